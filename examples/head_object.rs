@@ -1,4 +1,5 @@
-use aliyun_oss_client::{errors::OssError, file::AlignBuilder, Client, Method};
+use aliyun_oss_client::{errors::OssError, file::File, object::Conditions, Client};
+use chrono::{TimeZone, Utc};
 use dotenv::dotenv;
 
 #[tokio::main]
@@ -7,18 +8,14 @@ pub async fn main() -> Result<(), OssError> {
 
     let client = Client::from_env().unwrap();
 
-    let (url, resource) = client.get_object_base("9AB932LY.jpeg").get_url_resource([]);
+    let conditions =
+        Conditions::new().if_unmodified_since(Utc.with_ymd_and_hms(2022, 1, 1, 18, 1, 1).unwrap());
 
-    let headers = vec![(
-        "If-Unmodified-Since".parse().unwrap(),
-        "Sat, 01 Jan 2022 18:01:01 GMT".parse().unwrap(),
-    )];
-
-    let builder = client.builder_with_header(Method::HEAD, url, resource, headers)?;
-
-    let response = builder.send().await?;
-
-    println!("status: {:?}", response.status());
+    match client.head_object("9AB932LY.jpeg", conditions).await {
+        Ok(headers) => println!("headers: {:?}", headers),
+        Err(OssError::PreconditionFailed(e)) => println!("object is modified: {}", e),
+        Err(e) => return Err(e),
+    }
 
     Ok(())
 }
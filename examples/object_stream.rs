@@ -23,9 +23,7 @@ async fn main() {
 
     pin_mut!(stream);
 
-    let second_list = stream.next().await;
-    let third_list = stream.next().await;
-
-    println!("second_list: {:?}", second_list);
-    println!("third_list: {:?}", third_list);
+    while let Some(list) = stream.next().await {
+        println!("next_list: {:?}", list);
+    }
 }
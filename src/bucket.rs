@@ -10,21 +10,44 @@ use crate::errors::{OssError, OssResult};
 #[cfg(feature = "blocking")]
 use crate::file::blocking::AlignBuilder as BlockingAlignBuilder;
 use crate::file::AlignBuilder;
-use crate::object::{Object, ObjectList};
+use crate::object::{
+    Object, ObjectList, ServerSideEncryption, StorageClass, ACL_PRIVATE, ACL_PUBLIC_READ,
+    ACL_PUBLIC_READ_WRITE,
+};
 use crate::types::{
     CanonicalizedResource, InvalidEndPoint, Query, QueryKey, QueryValue, BUCKET_INFO,
 };
 use crate::{BucketName, EndPoint};
 
+use async_stream::try_stream;
 use chrono::{DateTime, NaiveDateTime, Utc};
-use http::Method;
+use futures::{pin_mut, StreamExt};
+use futures_core::stream::Stream;
+use http::{header::InvalidHeaderValue, HeaderName, HeaderValue, Method};
 use oss_derive::oss_gen_rc;
+use quick_xml::{events::Event, Reader};
+use reqwest::Url;
 use std::error::Error;
 use std::fmt;
 #[cfg(feature = "blocking")]
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::Arc;
 
+const ENCRYPTION: &str = "encryption";
+const STAT: &str = "stat";
+const ACL: &str = "acl";
+const LOCATION: &str = "location";
+const GRANT: &[u8] = b"Grant";
+const LOCATION_CONSTRAINT: &[u8] = b"LocationConstraint";
+const MARKER: &str = "marker";
+const OSS_RESOURCE_GROUP_ID: &str = "x-oss-resource-group-id";
+const OSS_ACL: &str = "x-oss-acl";
+
+/// 查询 bucket 列表时按资源组过滤，该条件会以 `x-oss-resource-group-id` header 的形式发送
+pub const RESOURCE_GROUP_ID: &str = "resource-group-id";
+const NO_SUCH_ENCRYPTION_RULE: &str = "NoSuchServerSideEncryptionRule";
+
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct ListBuckets<PointerSel: PointerFamily = ArcPointer> {
@@ -37,6 +60,7 @@ pub struct ListBuckets<PointerSel: PointerFamily = ArcPointer> {
     display_name: Option<String>,
     pub buckets: Vec<Bucket<PointerSel>>,
     client: PointerSel::PointerType,
+    search_query: Query,
 }
 
 impl<T: PointerFamily> fmt::Debug for ListBuckets<T> {
@@ -61,6 +85,126 @@ impl ListBuckets<ArcPointer> {
     }
 }
 
+impl<T: PointerFamily> ListBuckets<T> {
+    pub fn prefix(&self) -> Option<&String> {
+        self.prefix.as_ref()
+    }
+
+    pub fn marker(&self) -> Option<&String> {
+        self.marker.as_ref()
+    }
+
+    pub fn max_keys(&self) -> Option<&String> {
+        self.max_keys.as_ref()
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    pub fn next_marker(&self) -> Option<&String> {
+        self.next_marker.as_ref()
+    }
+
+    #[inline]
+    pub fn set_search_query(&mut self, search_query: Query) {
+        self.search_query = search_query;
+    }
+
+    /// # 下一页的查询条件
+    ///
+    /// 如果有下一页，返回 Some(Query)
+    /// 如果没有下一页，则返回 None
+    pub fn next_query(&self) -> Option<Query> {
+        if !self.is_truncated {
+            return None;
+        }
+
+        let mut search_query = self.search_query.clone();
+        search_query.insert(MARKER, self.next_marker.clone()?);
+        Some(search_query)
+    }
+}
+
+impl ListBuckets {
+    /// 获取下一页的 bucket 列表，没有下一页时返回 [`OssError::WithoutMore`]
+    pub async fn get_next_list(&self) -> OssResult<Self> {
+        match self.next_query() {
+            None => Err(OssError::WithoutMore),
+            Some(query) => {
+                ClientArc::clone(&self.client)
+                    .get_bucket_list_query(query)
+                    .await
+            }
+        }
+    }
+
+    /// # 将 bucket 列表转化为 stream, 返回第二页，第三页... 的内容
+    /// 依次请求下一页，直到没有更多内容时结束
+    pub fn into_stream(self) -> impl Stream<Item = OssResult<Self>> {
+        try_stream! {
+            let mut query = self.next_query();
+            let client = self.client;
+            while let Some(search_query) = query {
+                let list = ClientArc::clone(&client).get_bucket_list_query(search_query).await?;
+                query = list.next_query();
+                yield list;
+            }
+        }
+    }
+
+    /// # 将 bucket 列表展开为 bucket 的 stream
+    /// 先返回当前页的 bucket，再依次返回后续每一页的 bucket
+    pub fn into_bucket_stream(self) -> impl Stream<Item = OssResult<Bucket>> {
+        try_stream! {
+            let mut page = self;
+            loop {
+                for bucket in std::mem::take(&mut page.buckets) {
+                    yield bucket;
+                }
+                if page.next_query().is_none() {
+                    break;
+                }
+                page = page.get_next_list().await?;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ListBuckets<RcPointer> {
+    /// 获取下一页的 bucket 列表，没有下一页时返回 [`OssError::WithoutMore`]
+    pub fn get_next_list(&self) -> OssResult<Self> {
+        match self.next_query() {
+            None => Err(OssError::WithoutMore),
+            Some(query) => ClientRc::clone(&self.client).get_bucket_list_query(query),
+        }
+    }
+
+    /// # 将 bucket 列表展开为 bucket 的迭代器
+    /// 先返回当前页的 bucket，再依次返回后续每一页的 bucket，请求出错后迭代结束
+    pub fn into_bucket_iter(mut self) -> impl Iterator<Item = OssResult<Bucket<RcPointer>>> {
+        let mut buckets = std::mem::take(&mut self.buckets).into_iter();
+        let mut page = Some(self);
+
+        std::iter::from_fn(move || loop {
+            if let Some(bucket) = buckets.next() {
+                return Some(Ok(bucket));
+            }
+
+            let current = page.take()?;
+            current.next_query()?;
+            match current.get_next_list() {
+                Ok(mut next) => {
+                    buckets = std::mem::take(&mut next.buckets).into_iter();
+                    page = Some(next);
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+}
+
 #[oss_gen_rc]
 impl Default for ListBuckets<ArcPointer> {
     fn default() -> Self {
@@ -74,6 +218,7 @@ impl Default for ListBuckets<ArcPointer> {
             display_name: None,
             buckets: Vec::default(),
             client: Arc::default(),
+            search_query: Query::default(),
         }
     }
 }
@@ -247,6 +392,22 @@ impl Bucket {
 
         Ok(list)
     }
+
+    /// # 获取 bucket 的默认加密规则
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_encryption(&self) -> OssResult<Option<ServerSideEncryption>> {
+        get_encryption(self, self.base.get_sub_resource(ENCRYPTION)).await
+    }
+
+    /// # 设置 bucket 的默认加密规则
+    pub async fn put_bucket_encryption(&self, rule: ServerSideEncryption) -> OssResult<()> {
+        put_encryption(self, self.base.get_sub_resource(ENCRYPTION), rule).await
+    }
+
+    /// # 删除 bucket 的默认加密规则
+    pub async fn delete_bucket_encryption(&self) -> OssResult<()> {
+        delete_encryption(self, self.base.get_sub_resource(ENCRYPTION)).await
+    }
 }
 
 #[cfg(feature = "blocking")]
@@ -283,6 +444,22 @@ impl Bucket<RcPointer> {
 
         Ok(list)
     }
+
+    /// # 获取 bucket 的默认加密规则
+    /// 未设置时返回 `None`
+    pub fn get_bucket_encryption(&self) -> OssResult<Option<ServerSideEncryption>> {
+        blocking::get_encryption(self, self.base.get_sub_resource(ENCRYPTION))
+    }
+
+    /// # 设置 bucket 的默认加密规则
+    pub fn put_bucket_encryption(&self, rule: ServerSideEncryption) -> OssResult<()> {
+        blocking::put_encryption(self, self.base.get_sub_resource(ENCRYPTION), rule)
+    }
+
+    /// # 删除 bucket 的默认加密规则
+    pub fn delete_bucket_encryption(&self) -> OssResult<()> {
+        blocking::delete_encryption(self, self.base.get_sub_resource(ENCRYPTION))
+    }
 }
 
 impl<T: PointerFamily> RefineBucketList<Bucket<T>> for ListBuckets<T> {
@@ -355,6 +532,16 @@ impl<T: PointerFamily> RefineBucketList<Bucket<T>> for ListBuckets<T> {
 
 impl ClientArc {
     pub async fn get_bucket_list(self) -> OssResult<ListBuckets> {
+        self.get_bucket_list_query(Query::new()).await
+    }
+
+    /// # 按条件查询 bucket 列表
+    /// 支持 `prefix`、`marker`、`max-keys` 以及 [`RESOURCE_GROUP_ID`] 等查询条件
+    pub async fn get_bucket_list_query<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        self,
+        query: Q,
+    ) -> OssResult<ListBuckets> {
+        let query = Query::from_iter(query);
         let client_arc = Arc::new(self);
 
         let init_bucket = || {
@@ -365,32 +552,51 @@ impl ClientArc {
 
         let mut bucket_list = ListBuckets::<ArcPointer>::default();
         let res: Result<_, OssError> = client_arc
-            .base_bucket_list(&mut bucket_list, init_bucket)
+            .base_bucket_list_query(&query, &mut bucket_list, init_bucket)
             .await;
         res?;
 
         bucket_list.set_client(client_arc.clone());
+        bucket_list.set_search_query(query);
 
         Ok(bucket_list)
     }
 
+    /// # 按条件查询所有 bucket
+    /// 会自动翻页，直到列举完为止
+    pub fn bucket_stream<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        self,
+        query: Q,
+    ) -> impl Stream<Item = OssResult<Bucket>> {
+        let query = Query::from_iter(query);
+        try_stream! {
+            let stream = self.get_bucket_list_query(query).await?.into_bucket_stream();
+            pin_mut!(stream);
+            while let Some(bucket) = stream.next().await {
+                yield bucket?;
+            }
+        }
+    }
+
+    /// 可将带查询条件的 bucket 列表导出到外部 struct
     #[inline]
-    pub async fn base_bucket_list<List, Item, F, E>(
+    pub async fn base_bucket_list_query<List, Item, F, E>(
         &self,
+        query: &Query,
         list: &mut List,
         init_bucket: F,
     ) -> Result<(), E>
     where
         List: RefineBucketList<Item>,
         Item: RefineBucket,
-        E: From<BuilderError> + From<List::Error>,
+        E: From<BuilderError> + From<List::Error> + From<InvalidHeaderValue>,
         F: FnMut() -> Item,
     {
-        let url = self.get_endpoint_url();
+        let (url, headers) = list_buckets_request(self.get_endpoint_url(), query)?;
 
         let canonicalized = CanonicalizedResource::default();
 
-        let response = self.builder(Method::GET, url, canonicalized)?;
+        let response = self.builder_with_header(Method::GET, url, canonicalized, headers)?;
         let content = response.send_adjust_error().await?;
 
         list.decode(
@@ -401,6 +607,25 @@ impl ClientArc {
         Ok(())
     }
 
+    /// 可将 bucket 列表导出到外部 struct，即不带查询条件的 [`base_bucket_list_query`]
+    ///
+    /// [`base_bucket_list_query`]: Self::base_bucket_list_query
+    #[inline]
+    pub async fn base_bucket_list<List, Item, F, E>(
+        &self,
+        list: &mut List,
+        init_bucket: F,
+    ) -> Result<(), E>
+    where
+        List: RefineBucketList<Item>,
+        Item: RefineBucket,
+        E: From<BuilderError> + From<List::Error> + From<InvalidHeaderValue>,
+        F: FnMut() -> Item,
+    {
+        self.base_bucket_list_query(&Query::new(), list, init_bucket)
+            .await
+    }
+
     pub async fn get_bucket_info(self) -> OssResult<Bucket> {
         let name = self.get_bucket_name();
 
@@ -437,11 +662,122 @@ impl ClientArc {
 
         Ok(())
     }
+
+    /// # 创建 bucket
+    /// 同名 bucket 已存在时返回 [`OssError::BucketAlreadyExists`]
+    pub async fn put_bucket<N: Into<BucketName>>(
+        &self,
+        name: N,
+        config: CreateBucketConfiguration,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = named_bucket(name.into(), self.get_endpoint());
+
+        self.builder_with_header(Method::PUT, url, canonicalized, config.to_headers()?)?
+            .body(config.to_xml())
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 删除 bucket
+    /// bucket 中仍有文件或未完成的分片上传时返回 [`OssError::BucketNotEmpty`]
+    pub async fn delete_bucket<N: Into<BucketName>>(&self, name: N) -> OssResult<()> {
+        let (url, canonicalized) = named_bucket(name.into(), self.get_endpoint());
+
+        self.builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 获取默认 bucket 的访问权限（ACL）
+    pub async fn get_bucket_acl(&self) -> OssResult<Grant> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(ACL);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        Grant::from_xml(&content.text().await?)
+    }
+
+    /// # 设置默认 bucket 的访问权限（ACL）
+    pub async fn put_bucket_acl(&self, acl: Grant) -> OssResult<()> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(ACL);
+        let headers = vec![(HeaderName::from_static(OSS_ACL), acl.as_ref().parse()?)];
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 获取默认 bucket 所在的地域
+    pub async fn get_bucket_location(&self) -> OssResult<BucketLocation> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(LOCATION);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        BucketLocation::from_xml(&content.text().await?)
+    }
+
+    /// # 按默认 bucket 实际所在的地域设置可用区
+    /// 适用于不确定 bucket 所在地域的情况，返回设置后的可用区
+    pub async fn discover_endpoint(&mut self) -> OssResult<EndPoint> {
+        let endpoint = self.get_bucket_location().await?.endpoint()?;
+        self.set_endpoint(endpoint.clone());
+        Ok(endpoint)
+    }
+
+    /// # 获取默认 bucket 的存储容量及文件数量
+    pub async fn get_bucket_stat(&self) -> OssResult<BucketStat> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(STAT);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        BucketStat::from_xml(&content.text().await?)
+    }
+
+    /// # 获取默认 bucket 的默认加密规则
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_encryption(&self) -> OssResult<Option<ServerSideEncryption>> {
+        get_encryption(self, self.get_bucket_sub_resource(ENCRYPTION)).await
+    }
+
+    /// # 设置默认 bucket 的默认加密规则
+    pub async fn put_bucket_encryption(&self, rule: ServerSideEncryption) -> OssResult<()> {
+        put_encryption(self, self.get_bucket_sub_resource(ENCRYPTION), rule).await
+    }
+
+    /// # 删除默认 bucket 的默认加密规则
+    pub async fn delete_bucket_encryption(&self) -> OssResult<()> {
+        delete_encryption(self, self.get_bucket_sub_resource(ENCRYPTION)).await
+    }
 }
 
 #[cfg(feature = "blocking")]
 impl ClientRc {
     pub fn get_bucket_list(self) -> OssResult<ListBuckets<RcPointer>> {
+        self.get_bucket_list_query(Query::new())
+    }
+
+    /// # 按条件查询 bucket 列表
+    /// 支持 `prefix`、`marker`、`max-keys` 以及 [`RESOURCE_GROUP_ID`] 等查询条件
+    pub fn get_bucket_list_query<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        self,
+        query: Q,
+    ) -> OssResult<ListBuckets<RcPointer>> {
+        let query = Query::from_iter(query);
         let client_arc = Rc::new(self);
 
         let init_bucket = || {
@@ -451,30 +787,43 @@ impl ClientRc {
         };
 
         let mut bucket_list = ListBuckets::<RcPointer>::default();
-        let res: Result<_, OssError> = client_arc.base_bucket_list(&mut bucket_list, init_bucket);
+        let res: Result<_, OssError> =
+            client_arc.base_bucket_list_query(&query, &mut bucket_list, init_bucket);
         res?;
         bucket_list.set_client(client_arc.clone());
+        bucket_list.set_search_query(query);
 
         Ok(bucket_list)
     }
 
+    /// # 按条件查询所有 bucket
+    /// 会自动翻页，直到列举完为止
+    pub fn bucket_iter<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        self,
+        query: Q,
+    ) -> OssResult<impl Iterator<Item = OssResult<Bucket<RcPointer>>>> {
+        Ok(self.get_bucket_list_query(query)?.into_bucket_iter())
+    }
+
+    /// 可将带查询条件的 bucket 列表导出到外部 struct
     #[inline]
-    pub fn base_bucket_list<List, Item, F, E>(
+    pub fn base_bucket_list_query<List, Item, F, E>(
         &self,
+        query: &Query,
         list: &mut List,
         init_bucket: F,
     ) -> Result<(), E>
     where
         List: RefineBucketList<Item>,
         Item: RefineBucket,
-        E: From<BuilderError> + From<List::Error>,
+        E: From<BuilderError> + From<List::Error> + From<InvalidHeaderValue>,
         F: FnMut() -> Item,
     {
-        let url = self.get_endpoint_url();
+        let (url, headers) = list_buckets_request(self.get_endpoint_url(), query)?;
 
         let canonicalized = CanonicalizedResource::default();
 
-        let response = self.builder(Method::GET, url, canonicalized)?;
+        let response = self.builder_with_header(Method::GET, url, canonicalized, headers)?;
         let content = response.send_adjust_error()?;
 
         list.decode(&content.text().map_err(BuilderError::from)?, init_bucket)?;
@@ -482,6 +831,24 @@ impl ClientRc {
         Ok(())
     }
 
+    /// 可将 bucket 列表导出到外部 struct，即不带查询条件的 [`base_bucket_list_query`]
+    ///
+    /// [`base_bucket_list_query`]: Self::base_bucket_list_query
+    #[inline]
+    pub fn base_bucket_list<List, Item, F, E>(
+        &self,
+        list: &mut List,
+        init_bucket: F,
+    ) -> Result<(), E>
+    where
+        List: RefineBucketList<Item>,
+        Item: RefineBucket,
+        E: From<BuilderError> + From<List::Error> + From<InvalidHeaderValue>,
+        F: FnMut() -> Item,
+    {
+        self.base_bucket_list_query(&Query::new(), list, init_bucket)
+    }
+
     pub fn get_bucket_info(self) -> OssResult<Bucket<RcPointer>> {
         let name = self.get_bucket_name();
 
@@ -518,6 +885,220 @@ impl ClientRc {
 
         Ok(())
     }
+
+    /// # 创建 bucket
+    /// 同名 bucket 已存在时返回 [`OssError::BucketAlreadyExists`]
+    pub fn put_bucket<N: Into<BucketName>>(
+        &self,
+        name: N,
+        config: CreateBucketConfiguration,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = named_bucket(name.into(), self.get_endpoint());
+
+        self.builder_with_header(Method::PUT, url, canonicalized, config.to_headers()?)?
+            .body(config.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    /// # 删除 bucket
+    /// bucket 中仍有文件或未完成的分片上传时返回 [`OssError::BucketNotEmpty`]
+    pub fn delete_bucket<N: Into<BucketName>>(&self, name: N) -> OssResult<()> {
+        let (url, canonicalized) = named_bucket(name.into(), self.get_endpoint());
+
+        self.builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    /// # 获取默认 bucket 的访问权限（ACL）
+    pub fn get_bucket_acl(&self) -> OssResult<Grant> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(ACL);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Grant::from_xml(&content.text()?)
+    }
+
+    /// # 设置默认 bucket 的访问权限（ACL）
+    pub fn put_bucket_acl(&self, acl: Grant) -> OssResult<()> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(ACL);
+        let headers = vec![(HeaderName::from_static(OSS_ACL), acl.as_ref().parse()?)];
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    /// # 获取默认 bucket 所在的地域
+    pub fn get_bucket_location(&self) -> OssResult<BucketLocation> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(LOCATION);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?;
+
+        BucketLocation::from_xml(&content.text()?)
+    }
+
+    /// # 按默认 bucket 实际所在的地域设置可用区
+    /// 适用于不确定 bucket 所在地域的情况，返回设置后的可用区
+    pub fn discover_endpoint(&mut self) -> OssResult<EndPoint> {
+        let endpoint = self.get_bucket_location()?.endpoint()?;
+        self.set_endpoint(endpoint.clone());
+        Ok(endpoint)
+    }
+
+    /// # 获取默认 bucket 的存储容量及文件数量
+    pub fn get_bucket_stat(&self) -> OssResult<BucketStat> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(STAT);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?;
+
+        BucketStat::from_xml(&content.text()?)
+    }
+
+    /// # 获取默认 bucket 的默认加密规则
+    /// 未设置时返回 `None`
+    pub fn get_bucket_encryption(&self) -> OssResult<Option<ServerSideEncryption>> {
+        blocking::get_encryption(self, self.get_bucket_sub_resource(ENCRYPTION))
+    }
+
+    /// # 设置默认 bucket 的默认加密规则
+    pub fn put_bucket_encryption(&self, rule: ServerSideEncryption) -> OssResult<()> {
+        blocking::put_encryption(self, self.get_bucket_sub_resource(ENCRYPTION), rule)
+    }
+
+    /// # 删除默认 bucket 的默认加密规则
+    pub fn delete_bucket_encryption(&self) -> OssResult<()> {
+        blocking::delete_encryption(self, self.get_bucket_sub_resource(ENCRYPTION))
+    }
+}
+
+async fn get_encryption<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Option<ServerSideEncryption>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => ServerSideEncryption::from_xml(&response.text().await?).map(Some),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_ENCRYPTION_RULE => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn put_encryption<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    rule: ServerSideEncryption,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(rule.to_xml()?)
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_encryption<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::NO_SUCH_ENCRYPTION_RULE;
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        object::ServerSideEncryption, types::CanonicalizedResource,
+    };
+
+    pub(super) fn get_encryption<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Option<ServerSideEncryption>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => ServerSideEncryption::from_xml(&response.text()?).map(Some),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_ENCRYPTION_RULE => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn put_encryption<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        rule: ServerSideEncryption,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(rule.to_xml()?)
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_encryption<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+/// 将查询条件附加到 ListBuckets 接口的地址上，资源组 ID 则转为 header
+fn list_buckets_request(
+    mut url: Url,
+    query: &Query,
+) -> Result<(Url, Vec<(HeaderName, HeaderValue)>), InvalidHeaderValue> {
+    let mut list: Vec<_> = query.clone().into_iter().collect();
+    list.sort_by(|(k1, _), (k2, _)| k1.as_ref().cmp(k2.as_ref()));
+
+    let mut headers = Vec::new();
+    let mut pairs = Vec::with_capacity(list.len());
+    for (key, value) in list.iter() {
+        if key.as_ref() == RESOURCE_GROUP_ID {
+            headers.push((
+                HeaderName::from_static(OSS_RESOURCE_GROUP_ID),
+                value.as_ref().parse()?,
+            ));
+        } else {
+            pairs.push((key.as_ref(), value.as_ref()));
+        }
+    }
+
+    if !pairs.is_empty() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+
+    Ok((url, headers))
 }
 
 impl<T: PointerFamily> PartialEq<Bucket<T>> for Bucket<T> {
@@ -544,7 +1125,8 @@ impl<T: PointerFamily> PartialEq<BucketBase> for Bucket<T> {
     }
 }
 
-#[derive(Default)]
+/// # Bucket 的访问权限（ACL）
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub enum Grant {
     #[default]
     Private,
@@ -552,13 +1134,241 @@ pub enum Grant {
     PublicReadWrite,
 }
 
-#[derive(Clone, Debug, Default)]
+impl AsRef<str> for Grant {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Private => ACL_PRIVATE,
+            Self::PublicRead => ACL_PUBLIC_READ,
+            Self::PublicReadWrite => ACL_PUBLIC_READ_WRITE,
+        }
+    }
+}
+
+impl fmt::Display for Grant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for Grant {
+    type Err = InvalidBucketValue;
+    /// ```
+    /// # use aliyun_oss_client::bucket::Grant;
+    /// assert_eq!("public-read".parse::<Grant>().unwrap(), Grant::PublicRead);
+    /// assert!("default".parse::<Grant>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ACL_PRIVATE => Ok(Self::Private),
+            ACL_PUBLIC_READ => Ok(Self::PublicRead),
+            ACL_PUBLIC_READ_WRITE => Ok(Self::PublicReadWrite),
+            _ => Err(InvalidBucketValue),
+        }
+    }
+}
+
+impl Grant {
+    /// 解析 GetBucketAcl 接口返回的 AccessControlPolicy xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() == GRANT => {
+                    return Ok(reader.read_text(e.to_end().name())?.parse()?);
+                }
+                Event::Eof => return Err(InvalidBucketValue.into()),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+}
+
+/// # Bucket 所在的地域
+/// 如 `oss-cn-hangzhou`，可通过 [`BucketLocation::location`] 转为 [`Location`]，
+/// 或通过 [`BucketLocation::endpoint`] 转为 [`EndPoint`]，
+/// 保存原始字符串以支持 lib 暂不支持的地域
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketLocation(String);
+
+impl AsRef<str> for BucketLocation {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for BucketLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl BucketLocation {
+    /// 转为 lib 支持的地域，暂不支持的地域会返回错误
+    pub fn location(&self) -> Result<Location, InvalidEndPoint> {
+        self.0.parse()
+    }
+
+    /// 转为对应的可用区，lib 暂不支持的地域会返回错误
+    /// ```
+    /// # use aliyun_oss_client::bucket::BucketLocation;
+    /// # use aliyun_oss_client::EndPoint;
+    /// let location: BucketLocation = "oss-cn-qingdao".parse().unwrap();
+    /// assert_eq!(location.endpoint().unwrap(), EndPoint::CnQingdao);
+    /// let location: BucketLocation = "oss-cn-chengdu".parse().unwrap();
+    /// assert!(location.endpoint().is_err());
+    /// ```
+    pub fn endpoint(&self) -> Result<EndPoint, InvalidEndPoint> {
+        self.location().map(EndPoint::from)
+    }
+
+    /// 解析 GetBucketLocation 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() == LOCATION_CONSTRAINT => {
+                    return Ok(reader.read_text(e.to_end().name())?.parse()?);
+                }
+                Event::Eof => return Err(InvalidBucketValue.into()),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+}
+
+impl FromStr for BucketLocation {
+    type Err = InvalidBucketValue;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(InvalidBucketValue);
+        }
+        Ok(Self(s.to_owned()))
+    }
+}
+
+/// # 数据容灾类型
+/// `LRS` 为本地冗余存储，`ZRS` 为同城冗余存储
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum DataRedundancyType {
     #[default]
     LRS,
     ZRS,
 }
 
+const REDUNDANCY_LRS: &str = "LRS";
+const REDUNDANCY_ZRS: &str = "ZRS";
+
+impl AsRef<str> for DataRedundancyType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::LRS => REDUNDANCY_LRS,
+            Self::ZRS => REDUNDANCY_ZRS,
+        }
+    }
+}
+
+impl fmt::Display for DataRedundancyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for DataRedundancyType {
+    type Err = InvalidBucketValue;
+    /// ```
+    /// # use aliyun_oss_client::bucket::DataRedundancyType;
+    /// assert_eq!("ZRS".parse::<DataRedundancyType>().unwrap(), DataRedundancyType::ZRS);
+    /// assert!("foo".parse::<DataRedundancyType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            REDUNDANCY_LRS => Ok(Self::LRS),
+            REDUNDANCY_ZRS => Ok(Self::ZRS),
+            _ => Err(InvalidBucketValue),
+        }
+    }
+}
+
+/// # 创建 bucket 时的可选参数
+/// 未设置的项使用 OSS 的默认值
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateBucketConfiguration {
+    pub acl: Option<Grant>,
+    pub storage_class: Option<StorageClass>,
+    pub data_redundancy_type: Option<DataRedundancyType>,
+    /// 所属资源组 ID，未设置时属于默认资源组
+    pub resource_group_id: Option<String>,
+}
+
+impl CreateBucketConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn acl(mut self, acl: Grant) -> Self {
+        self.acl = Some(acl);
+        self
+    }
+
+    pub fn storage_class(mut self, storage_class: StorageClass) -> Self {
+        self.storage_class = Some(storage_class);
+        self
+    }
+
+    pub fn data_redundancy_type(mut self, data_redundancy_type: DataRedundancyType) -> Self {
+        self.data_redundancy_type = Some(data_redundancy_type);
+        self
+    }
+
+    pub fn resource_group_id<G: Into<String>>(mut self, id: G) -> Self {
+        self.resource_group_id = Some(id.into());
+        self
+    }
+
+    /// 转化为 PutBucket 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        let mut xml = String::from("<CreateBucketConfiguration>");
+        if let Some(storage_class) = &self.storage_class {
+            xml.push_str(&format!("<StorageClass>{}</StorageClass>", storage_class));
+        }
+        if let Some(redundancy) = &self.data_redundancy_type {
+            xml.push_str(&format!(
+                "<DataRedundancyType>{}</DataRedundancyType>",
+                redundancy
+            ));
+        }
+        xml.push_str("</CreateBucketConfiguration>");
+        xml
+    }
+
+    /// 转化为 PutBucket 接口的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let mut headers = Vec::new();
+        if let Some(acl) = &self.acl {
+            headers.push((HeaderName::from_static(OSS_ACL), acl.as_ref().parse()?));
+        }
+        if let Some(id) = &self.resource_group_id {
+            headers.push((HeaderName::from_static(OSS_RESOURCE_GROUP_ID), id.parse()?));
+        }
+        Ok(headers)
+    }
+}
+
+/// 获取指定 bucket 的接口地址及签名参数
+fn named_bucket(name: BucketName, endpoint: &EndPoint) -> (Url, CanonicalizedResource) {
+    let base = BucketBase::new(name, endpoint.to_owned());
+    let canonicalized = CanonicalizedResource::from(format!("/{}/", base.name()));
+    (base.to_url(), canonicalized)
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct BucketListObjectParms<'a> {
     pub list_type: u8,
@@ -596,7 +1406,9 @@ pub struct BucketListObject<'a> {
     pub restore_info: Option<&'a str>,
 }
 
-#[derive(Clone, Debug)]
+/// # lib 支持的地域
+/// 与 [`EndPoint`] 一一对应，字符串形式为 GetBucketLocation 接口返回的 `LocationConstraint`
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Location {
     CnHangzhou,
     CnShanghai,
@@ -610,15 +1422,93 @@ pub enum Location {
     ApSouthEast1,
 }
 
-#[derive(Clone, Debug)]
+const LOCATION_HANGZHOU: &str = "oss-cn-hangzhou";
+const LOCATION_SHANGHAI: &str = "oss-cn-shanghai";
+const LOCATION_QINGDAO: &str = "oss-cn-qingdao";
+const LOCATION_BEIJING: &str = "oss-cn-beijing";
+const LOCATION_ZHANGJIAKOU: &str = "oss-cn-zhangjiakou";
+const LOCATION_HONGKONG: &str = "oss-cn-hongkong";
+const LOCATION_SHENZHEN: &str = "oss-cn-shenzhen";
+const LOCATION_US_WEST1: &str = "oss-us-west-1";
+const LOCATION_US_EAST1: &str = "oss-us-east-1";
+const LOCATION_AP_SOUTH_EAST1: &str = "oss-ap-southeast-1";
+
+impl AsRef<str> for Location {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::CnHangzhou => LOCATION_HANGZHOU,
+            Self::CnShanghai => LOCATION_SHANGHAI,
+            Self::CnQingdao => LOCATION_QINGDAO,
+            Self::CnBeijing => LOCATION_BEIJING,
+            Self::CnZhangjiakou => LOCATION_ZHANGJIAKOU,
+            Self::CnHongkong => LOCATION_HONGKONG,
+            Self::CnShenzhen => LOCATION_SHENZHEN,
+            Self::UsWest1 => LOCATION_US_WEST1,
+            Self::UsEast1 => LOCATION_US_EAST1,
+            Self::ApSouthEast1 => LOCATION_AP_SOUTH_EAST1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for Location {
+    type Err = InvalidEndPoint;
+    /// ```
+    /// # use aliyun_oss_client::bucket::Location;
+    /// assert_eq!("oss-us-west-1".parse::<Location>().unwrap(), Location::UsWest1);
+    /// assert!("oss-cn-chengdu".parse::<Location>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            LOCATION_HANGZHOU => Ok(Self::CnHangzhou),
+            LOCATION_SHANGHAI => Ok(Self::CnShanghai),
+            LOCATION_QINGDAO => Ok(Self::CnQingdao),
+            LOCATION_BEIJING => Ok(Self::CnBeijing),
+            LOCATION_ZHANGJIAKOU => Ok(Self::CnZhangjiakou),
+            LOCATION_HONGKONG => Ok(Self::CnHongkong),
+            LOCATION_SHENZHEN => Ok(Self::CnShenzhen),
+            LOCATION_US_WEST1 => Ok(Self::UsWest1),
+            LOCATION_US_EAST1 => Ok(Self::UsEast1),
+            LOCATION_AP_SOUTH_EAST1 => Ok(Self::ApSouthEast1),
+            _ => Err(InvalidEndPoint),
+        }
+    }
+}
+
+impl From<Location> for EndPoint {
+    fn from(location: Location) -> Self {
+        match location {
+            Location::CnHangzhou => Self::CnHangzhou,
+            Location::CnShanghai => Self::CnShanghai,
+            Location::CnQingdao => Self::CnQingdao,
+            Location::CnBeijing => Self::CnBeijing,
+            Location::CnZhangjiakou => Self::CnZhangjiakou,
+            Location::CnHongkong => Self::CnHongkong,
+            Location::CnShenzhen => Self::CnShenzhen,
+            Location::UsWest1 => Self::UsWest1,
+            Location::UsEast1 => Self::UsEast1,
+            Location::ApSouthEast1 => Self::ApSouthEast1,
+        }
+    }
+}
+
+/// # Bucket 的存储容量及文件数量
+/// 数据并非实时更新，可能存在一小时以上的延时
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct BucketStat {
     pub storage: u64,
-    pub object_count: u32,
-    pub multipart_upload_count: u32,
-    pub live_channel_count: u32,
-    pub last_modified_time: u16,
+    pub object_count: u64,
+    pub multipart_upload_count: u64,
+    pub live_channel_count: u64,
+    /// 统计信息的获取时间，Unix 时间戳（秒）
+    pub last_modified_time: i64,
     pub standard_storage: u64,
-    pub standard_object_count: u32,
+    pub standard_object_count: u64,
     pub infrequent_access_storage: u64,
     pub infrequent_access_real_storage: u64,
     pub infrequent_access_object_count: u64,
@@ -630,6 +1520,72 @@ pub struct BucketStat {
     pub cold_archive_object_count: u64,
 }
 
+const BUCKET_STAT: &[u8] = b"BucketStat";
+const STORAGE: &[u8] = b"Storage";
+const OBJECT_COUNT: &[u8] = b"ObjectCount";
+const MULTIPART_UPLOAD_COUNT: &[u8] = b"MultipartUploadCount";
+const LIVE_CHANNEL_COUNT: &[u8] = b"LiveChannelCount";
+const LAST_MODIFIED_TIME: &[u8] = b"LastModifiedTime";
+const STANDARD_STORAGE: &[u8] = b"StandardStorage";
+const STANDARD_OBJECT_COUNT: &[u8] = b"StandardObjectCount";
+const IA_STORAGE: &[u8] = b"InfrequentAccessStorage";
+const IA_REAL_STORAGE: &[u8] = b"InfrequentAccessRealStorage";
+const IA_OBJECT_COUNT: &[u8] = b"InfrequentAccessObjectCount";
+const ARCHIVE_STORAGE: &[u8] = b"ArchiveStorage";
+const ARCHIVE_REAL_STORAGE: &[u8] = b"ArchiveRealStorage";
+const ARCHIVE_OBJECT_COUNT: &[u8] = b"ArchiveObjectCount";
+const COLD_ARCHIVE_STORAGE: &[u8] = b"ColdArchiveStorage";
+const COLD_ARCHIVE_REAL_STORAGE: &[u8] = b"ColdArchiveRealStorage";
+const COLD_ARCHIVE_OBJECT_COUNT: &[u8] = b"ColdArchiveObjectCount";
+
+impl BucketStat {
+    /// 解析 GetBucketStat 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut stat = Self::default();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() != BUCKET_STAT => {
+                    let text = reader.read_text(e.to_end().name())?;
+                    stat.set_field(e.name().as_ref(), &text)?;
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(stat)
+    }
+
+    fn set_field(&mut self, field: &[u8], text: &str) -> OssResult<()> {
+        match field {
+            STORAGE => self.storage = text.parse()?,
+            OBJECT_COUNT => self.object_count = text.parse()?,
+            MULTIPART_UPLOAD_COUNT => self.multipart_upload_count = text.parse()?,
+            LIVE_CHANNEL_COUNT => self.live_channel_count = text.parse()?,
+            LAST_MODIFIED_TIME => self.last_modified_time = text.parse()?,
+            STANDARD_STORAGE => self.standard_storage = text.parse()?,
+            STANDARD_OBJECT_COUNT => self.standard_object_count = text.parse()?,
+            IA_STORAGE => self.infrequent_access_storage = text.parse()?,
+            IA_REAL_STORAGE => self.infrequent_access_real_storage = text.parse()?,
+            IA_OBJECT_COUNT => self.infrequent_access_object_count = text.parse()?,
+            ARCHIVE_STORAGE => self.archive_storage = text.parse()?,
+            ARCHIVE_REAL_STORAGE => self.archive_real_storage = text.parse()?,
+            ARCHIVE_OBJECT_COUNT => self.archive_object_count = text.parse()?,
+            COLD_ARCHIVE_STORAGE => self.cold_archive_storage = text.parse()?,
+            COLD_ARCHIVE_REAL_STORAGE => self.cold_archive_real_storage = text.parse()?,
+            COLD_ARCHIVE_OBJECT_COUNT => self.cold_archive_object_count = text.parse()?,
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -644,4 +1600,34 @@ mod tests {
 
         assert!(list.buckets.len() == 0);
     }
+
+    #[test]
+    fn test_bucket_location_endpoint() {
+        use super::{BucketLocation, Location};
+        use crate::EndPoint;
+
+        let cases = [
+            ("oss-cn-hangzhou", EndPoint::CnHangzhou),
+            ("oss-cn-shanghai", EndPoint::CnShanghai),
+            ("oss-cn-qingdao", EndPoint::CnQingdao),
+            ("oss-cn-beijing", EndPoint::CnBeijing),
+            ("oss-cn-zhangjiakou", EndPoint::CnZhangjiakou),
+            ("oss-cn-hongkong", EndPoint::CnHongkong),
+            ("oss-cn-shenzhen", EndPoint::CnShenzhen),
+            ("oss-us-west-1", EndPoint::UsWest1),
+            ("oss-us-east-1", EndPoint::UsEast1),
+            ("oss-ap-southeast-1", EndPoint::ApSouthEast1),
+        ];
+        for (constraint, endpoint) in cases {
+            let bucket_location: BucketLocation = constraint.parse().unwrap();
+            assert_eq!(bucket_location.endpoint().unwrap(), endpoint);
+            assert_eq!(bucket_location.location().unwrap().to_string(), constraint);
+        }
+        let location: Location = "oss-us-east-1".parse().unwrap();
+        assert_eq!(location, Location::UsEast1);
+
+        let unknown: BucketLocation = "oss-ap-southeast-5".parse().unwrap();
+        assert!(unknown.endpoint().is_err());
+        assert_eq!(unknown.as_ref(), "oss-ap-southeast-5");
+    }
 }
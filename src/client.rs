@@ -6,7 +6,8 @@ use crate::builder::Middleware;
 use crate::builder::{ArcPointer, BuilderError, ClientWithMiddleware, RequestBuilder};
 use crate::config::{BucketBase, Config, InvalidConfig, ObjectBase, ObjectPath};
 use crate::file::AlignBuilder;
-use crate::types::{BucketName, CanonicalizedResource, EndPoint, KeyId, KeySecret};
+use crate::integrity::{Integrity, CONTENT_MD5};
+use crate::types::{BucketName, CanonicalizedResource, ContentMd5, EndPoint, KeyId, KeySecret};
 
 use chrono::{DateTime, Utc};
 use http::{
@@ -21,6 +22,9 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Duration;
 
+pub(crate) const OSS_REQUEST_PAYER: &str = "x-oss-request-payer";
+pub(crate) const PAYER_REQUESTER: &str = "requester";
+
 /// # 构造请求的客户端结构体
 /// Clone 特征不是必须的
 #[non_exhaustive]
@@ -34,6 +38,8 @@ where
     endpoint: EndPoint,
     bucket: BucketName,
     timeout: Option<Duration>,
+    integrity: Integrity,
+    request_payer: bool,
 }
 
 impl<M: Default + Clone> Client<M> {
@@ -100,6 +106,8 @@ impl<M: Default + Clone> Client<M> {
             endpoint,
             bucket,
             timeout: None,
+            integrity: Integrity::default(),
+            request_payer: false,
         }
     }
 
@@ -116,6 +124,11 @@ impl<M: Default + Clone> Client<M> {
         self.get_bucket_base().to_url()
     }
 
+    /// 获取默认 bucket 子资源（如 `?encryption`）的接口地址及签名参数
+    pub(crate) fn get_bucket_sub_resource(&self, sub: &str) -> (Url, CanonicalizedResource) {
+        self.get_bucket_base().get_sub_resource(sub)
+    }
+
     pub(crate) fn get_endpoint(&self) -> &EndPoint {
         &self.endpoint
     }
@@ -123,10 +136,33 @@ impl<M: Default + Clone> Client<M> {
         self.endpoint.to_url()
     }
 
+    /// 设置默认可用区
+    pub fn set_endpoint(&mut self, endpoint: EndPoint) {
+        self.endpoint = endpoint;
+    }
+
     /// 设置 timeout
     pub fn timeout(&mut self, timeout: Duration) {
         self.timeout = Some(timeout);
     }
+
+    /// 设置数据完整性校验
+    pub fn integrity(&mut self, integrity: Integrity) {
+        self.integrity = integrity;
+    }
+
+    /// 以请求者身份付费，访问开启了请求者付费的 bucket 时需要设置
+    ///
+    /// 设置后该 client 发出的所有请求都会带上 `x-oss-request-payer: requester` header。
+    /// [`Bucket`]、[`ObjectList`] 持有的是创建时 client 的副本，需在获取它们之前设置；
+    /// 只需为个别文件请求付费时，可使用 [`Conditions::request_payer`]
+    ///
+    /// [`Bucket`]: crate::bucket::Bucket
+    /// [`ObjectList`]: crate::object::ObjectList
+    /// [`Conditions::request_payer`]: crate::object::Conditions::request_payer
+    pub fn request_payer(&mut self, requester: bool) {
+        self.request_payer = requester;
+    }
 }
 
 #[cfg(not(test))]
@@ -219,7 +255,16 @@ impl AlignBuilder for Client<ClientWithMiddleware> {
         auth_builder.method(&method);
         auth_builder.date(now().into());
         auth_builder.canonicalized_resource(resource);
-        auth_builder.extend_headers(HeaderMap::from_iter(headers));
+
+        let mut headers = HeaderMap::from_iter(headers);
+        if self.request_payer {
+            headers.insert(OSS_REQUEST_PAYER, HeaderValue::from_static(PAYER_REQUESTER));
+        }
+        // Content-MD5 需参与签名
+        if let Some(md5) = headers.get(CONTENT_MD5).and_then(|md5| md5.to_str().ok()) {
+            auth_builder.content_md5(ContentMd5::new(md5.to_owned()));
+        }
+        auth_builder.extend_headers(headers);
 
         let mut builder = self
             .client_middleware
@@ -232,6 +277,11 @@ impl AlignBuilder for Client<ClientWithMiddleware> {
 
         Ok(builder)
     }
+
+    #[inline]
+    fn get_integrity(&self) -> Integrity {
+        self.integrity
+    }
 }
 
 #[cfg(all(feature = "blocking", test))]
@@ -279,7 +329,16 @@ impl crate::file::blocking::AlignBuilder for Client<BlockingClientWithMiddleware
         auth_builder.method(&method);
         auth_builder.date(now().into());
         auth_builder.canonicalized_resource(resource);
-        auth_builder.extend_headers(HeaderMap::from_iter(headers));
+
+        let mut headers = HeaderMap::from_iter(headers);
+        if self.request_payer {
+            headers.insert(OSS_REQUEST_PAYER, HeaderValue::from_static(PAYER_REQUESTER));
+        }
+        // Content-MD5 需参与签名
+        if let Some(md5) = headers.get(CONTENT_MD5).and_then(|md5| md5.to_str().ok()) {
+            auth_builder.content_md5(ContentMd5::new(md5.to_owned()));
+        }
+        auth_builder.extend_headers(headers);
 
         let mut builder = self
             .client_middleware
@@ -292,4 +351,9 @@ impl crate::file::blocking::AlignBuilder for Client<BlockingClientWithMiddleware
 
         Ok(builder)
     }
+
+    #[inline]
+    fn get_integrity(&self) -> Integrity {
+        self.integrity
+    }
 }
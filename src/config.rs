@@ -232,6 +232,14 @@ impl BucketBase {
 
         (url, resource)
     }
+
+    /// 获取当前 bucket 子资源（如 `?encryption`）的接口地址及签名参数
+    pub(crate) fn get_sub_resource(&self, sub: &str) -> (Url, CanonicalizedResource) {
+        let mut url = self.to_url();
+        url.set_query(Some(sub));
+
+        (url, CanonicalizedResource::from_bucket(self, Some(sub)))
+    }
 }
 
 #[derive(Error, Debug)]
@@ -0,0 +1,459 @@
+use http::{
+    header::{
+        ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN,
+        ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_HEADERS,
+        ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+    },
+    HeaderMap, HeaderName, HeaderValue, Method,
+};
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket,
+    builder::BuilderError,
+    client::ClientArc,
+    errors::{OssError, OssResult},
+    file::AlignBuilder,
+    object::InvalidObjectValue,
+    types::CanonicalizedResource,
+};
+
+const CORS: &str = "cors";
+const NO_SUCH_CORS_CONFIGURATION: &str = "NoSuchCORSConfiguration";
+
+const CORS_CONFIGURATION: &[u8] = b"CORSConfiguration";
+const CORS_RULE: &[u8] = b"CORSRule";
+const ALLOWED_ORIGIN: &[u8] = b"AllowedOrigin";
+const ALLOWED_METHOD: &[u8] = b"AllowedMethod";
+const ALLOWED_HEADER: &[u8] = b"AllowedHeader";
+const EXPOSE_HEADER: &[u8] = b"ExposeHeader";
+const MAX_AGE_SECONDS: &[u8] = b"MaxAgeSeconds";
+
+/// # 跨域资源共享（CORS）规则
+/// 来源及 header 支持 `*` 通配符
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<Method>,
+    pub allowed_headers: Vec<String>,
+    pub expose_headers: Vec<String>,
+    /// 浏览器缓存预检结果的时间（秒）
+    pub max_age_seconds: Option<u32>,
+}
+
+impl CorsRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加允许的来源，可多次调用
+    pub fn origin<O: Into<String>>(mut self, origin: O) -> Self {
+        self.allowed_origins.push(origin.into());
+        self
+    }
+
+    /// 添加允许的请求方式，可多次调用
+    pub fn method(mut self, method: Method) -> Self {
+        self.allowed_methods.push(method);
+        self
+    }
+
+    /// 添加允许在预检请求中携带的 header，可多次调用
+    pub fn header<H: Into<String>>(mut self, header: H) -> Self {
+        self.allowed_headers.push(header.into());
+        self
+    }
+
+    /// 添加允许浏览器读取的响应 header，可多次调用
+    pub fn expose_header<H: Into<String>>(mut self, header: H) -> Self {
+        self.expose_headers.push(header.into());
+        self
+    }
+
+    pub fn max_age_seconds(mut self, seconds: u32) -> Self {
+        self.max_age_seconds = Some(seconds);
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = String::from("<CORSRule>");
+        for origin in self.allowed_origins.iter() {
+            xml.push_str(&format!(
+                "<AllowedOrigin>{}</AllowedOrigin>",
+                escape(origin)
+            ));
+        }
+        for method in self.allowed_methods.iter() {
+            xml.push_str(&format!("<AllowedMethod>{}</AllowedMethod>", method));
+        }
+        for header in self.allowed_headers.iter() {
+            xml.push_str(&format!(
+                "<AllowedHeader>{}</AllowedHeader>",
+                escape(header)
+            ));
+        }
+        for header in self.expose_headers.iter() {
+            xml.push_str(&format!("<ExposeHeader>{}</ExposeHeader>", escape(header)));
+        }
+        if let Some(seconds) = self.max_age_seconds {
+            xml.push_str(&format!("<MaxAgeSeconds>{}</MaxAgeSeconds>", seconds));
+        }
+        xml.push_str("</CORSRule>");
+        xml
+    }
+}
+
+/// 转化为 PutBucketCors 接口的请求体
+pub(crate) fn to_xml(rules: &[CorsRule]) -> String {
+    let mut xml = String::from("<CORSConfiguration>");
+    for rule in rules {
+        xml.push_str(&rule.to_xml());
+    }
+    xml.push_str("</CORSConfiguration>");
+    xml
+}
+
+/// 解析 GetBucketCors 接口返回的 xml
+pub(crate) fn from_xml(xml: &str) -> OssResult<Vec<CorsRule>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::with_capacity(xml.len());
+
+    let mut rules = Vec::new();
+    let mut rule: Option<CorsRule> = None;
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => match e.name().as_ref() {
+                CORS_CONFIGURATION => (),
+                CORS_RULE => rule = Some(CorsRule::default()),
+                name => {
+                    let raw = reader.read_text(e.to_end().name())?;
+                    let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                    if let Some(rule) = rule.as_mut() {
+                        match name {
+                            ALLOWED_ORIGIN => rule.allowed_origins.push(text.into_owned()),
+                            ALLOWED_METHOD => rule
+                                .allowed_methods
+                                .push(text.parse().map_err(|_| InvalidObjectValue)?),
+                            ALLOWED_HEADER => rule.allowed_headers.push(text.into_owned()),
+                            EXPOSE_HEADER => rule.expose_headers.push(text.into_owned()),
+                            MAX_AGE_SECONDS => rule.max_age_seconds = Some(text.parse()?),
+                            _ => (),
+                        }
+                    }
+                }
+            },
+            Event::End(e) if e.name().as_ref() == CORS_RULE => rules.extend(rule.take()),
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(rules)
+}
+
+/// # 预检请求的结果
+/// 请求不符合任何规则时，OSS 会返回 403 错误
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CorsPreflight {
+    pub allow_origin: Option<String>,
+    pub allow_methods: Vec<String>,
+    pub allow_headers: Vec<String>,
+    pub expose_headers: Vec<String>,
+    pub max_age: Option<u32>,
+}
+
+impl CorsPreflight {
+    /// 从预检请求的响应 header 中读取结果
+    pub(crate) fn from_headers(headers: &HeaderMap) -> OssResult<Self> {
+        let list = |name: HeaderName| -> OssResult<Vec<String>> {
+            match headers.get(name) {
+                Some(value) => Ok(value
+                    .to_str()?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_owned)
+                    .collect()),
+                None => Ok(Vec::new()),
+            }
+        };
+
+        let allow_origin = match headers.get(ACCESS_CONTROL_ALLOW_ORIGIN) {
+            Some(value) => Some(value.to_str()?.to_owned()),
+            None => None,
+        };
+        let max_age = match headers.get(ACCESS_CONTROL_MAX_AGE) {
+            Some(value) => Some(value.to_str()?.parse()?),
+            None => None,
+        };
+
+        Ok(Self {
+            allow_origin,
+            allow_methods: list(ACCESS_CONTROL_ALLOW_METHODS)?,
+            allow_headers: list(ACCESS_CONTROL_ALLOW_HEADERS)?,
+            expose_headers: list(ACCESS_CONTROL_EXPOSE_HEADERS)?,
+            max_age,
+        })
+    }
+}
+
+/// 预检请求的 header，`headers` 为空时不发送 `Access-Control-Request-Headers`
+pub(crate) fn preflight_headers(
+    origin: &str,
+    method: &Method,
+    headers: &[&str],
+) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+    let mut list = vec![
+        (ORIGIN, origin.parse().map_err(OssError::from)?),
+        (
+            ACCESS_CONTROL_REQUEST_METHOD,
+            method.as_str().parse().map_err(OssError::from)?,
+        ),
+    ];
+    if !headers.is_empty() {
+        list.push((
+            ACCESS_CONTROL_REQUEST_HEADERS,
+            headers.join(",").parse().map_err(OssError::from)?,
+        ));
+    }
+    Ok(list)
+}
+
+async fn get_cors<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Vec<CorsRule>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => from_xml(&response.text().await?),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_CORS_CONFIGURATION => Ok(Vec::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn put_cors<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    rules: &[CorsRule],
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(to_xml(rules))
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_cors<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的 CORS 规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_cors(&self) -> OssResult<Vec<CorsRule>> {
+        get_cors(self, self.get_bucket_sub_resource(CORS)).await
+    }
+
+    /// # 设置默认 bucket 的 CORS 规则
+    /// 会覆盖已有的全部规则
+    pub async fn put_bucket_cors(&self, rules: &[CorsRule]) -> OssResult<()> {
+        put_cors(self, self.get_bucket_sub_resource(CORS), rules).await
+    }
+
+    /// # 删除默认 bucket 的全部 CORS 规则
+    pub async fn delete_bucket_cors(&self) -> OssResult<()> {
+        delete_cors(self, self.get_bucket_sub_resource(CORS)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的 CORS 规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_cors(&self) -> OssResult<Vec<CorsRule>> {
+        get_cors(self, self.base.get_sub_resource(CORS)).await
+    }
+
+    /// # 设置 bucket 的 CORS 规则
+    /// 会覆盖已有的全部规则
+    pub async fn put_bucket_cors(&self, rules: &[CorsRule]) -> OssResult<()> {
+        put_cors(self, self.base.get_sub_resource(CORS), rules).await
+    }
+
+    /// # 删除 bucket 的全部 CORS 规则
+    pub async fn delete_bucket_cors(&self) -> OssResult<()> {
+        delete_cors(self, self.base.get_sub_resource(CORS)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::{from_xml, to_xml, CorsRule, NO_SUCH_CORS_CONFIGURATION};
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        types::CanonicalizedResource,
+    };
+
+    pub(super) fn get_cors<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Vec<CorsRule>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => from_xml(&response.text()?),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_CORS_CONFIGURATION => {
+                Ok(Vec::new())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn put_cors<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        rules: &[CorsRule],
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(to_xml(rules))
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_cors<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的 CORS 规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_cors(&self) -> OssResult<Vec<CorsRule>> {
+        blocking::get_cors(self, self.get_bucket_sub_resource(CORS))
+    }
+
+    /// # 设置默认 bucket 的 CORS 规则
+    /// 会覆盖已有的全部规则
+    pub fn put_bucket_cors(&self, rules: &[CorsRule]) -> OssResult<()> {
+        blocking::put_cors(self, self.get_bucket_sub_resource(CORS), rules)
+    }
+
+    /// # 删除默认 bucket 的全部 CORS 规则
+    pub fn delete_bucket_cors(&self) -> OssResult<()> {
+        blocking::delete_cors(self, self.get_bucket_sub_resource(CORS))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的 CORS 规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_cors(&self) -> OssResult<Vec<CorsRule>> {
+        blocking::get_cors(self, self.base.get_sub_resource(CORS))
+    }
+
+    /// # 设置 bucket 的 CORS 规则
+    /// 会覆盖已有的全部规则
+    pub fn put_bucket_cors(&self, rules: &[CorsRule]) -> OssResult<()> {
+        blocking::put_cors(self, self.base.get_sub_resource(CORS), rules)
+    }
+
+    /// # 删除 bucket 的全部 CORS 规则
+    pub fn delete_bucket_cors(&self) -> OssResult<()> {
+        blocking::delete_cors(self, self.base.get_sub_resource(CORS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, Method};
+
+    use super::{from_xml, to_xml, CorsPreflight, CorsRule};
+
+    #[test]
+    fn test_cors_xml() {
+        let rules = vec![
+            CorsRule::new()
+                .origin("https://example.com")
+                .method(Method::PUT)
+                .method(Method::GET)
+                .header("*")
+                .expose_header("ETag")
+                .max_age_seconds(100),
+            CorsRule::new().origin("*").method(Method::HEAD),
+        ];
+
+        let xml = to_xml(&rules);
+        assert_eq!(
+            xml,
+            "<CORSConfiguration>\
+            <CORSRule><AllowedOrigin>https://example.com</AllowedOrigin>\
+            <AllowedMethod>PUT</AllowedMethod><AllowedMethod>GET</AllowedMethod>\
+            <AllowedHeader>*</AllowedHeader><ExposeHeader>ETag</ExposeHeader>\
+            <MaxAgeSeconds>100</MaxAgeSeconds></CORSRule>\
+            <CORSRule><AllowedOrigin>*</AllowedOrigin><AllowedMethod>HEAD</AllowedMethod></CORSRule>\
+            </CORSConfiguration>"
+        );
+        assert_eq!(from_xml(&xml).unwrap(), rules);
+    }
+
+    #[test]
+    fn test_preflight_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "access-control-allow-origin",
+            "https://example.com".parse().unwrap(),
+        );
+        headers.insert("access-control-allow-methods", "PUT, GET".parse().unwrap());
+        headers.insert("access-control-max-age", "100".parse().unwrap());
+
+        let preflight = CorsPreflight::from_headers(&headers).unwrap();
+        assert_eq!(
+            preflight.allow_origin.as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(preflight.allow_methods, vec!["PUT", "GET"]);
+        assert!(preflight.allow_headers.is_empty());
+        assert_eq!(preflight.max_age, Some(100));
+    }
+}
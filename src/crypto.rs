@@ -0,0 +1,585 @@
+//! 兼容官方 SDK 的客户端加密格式，文件内容使用 AES-256-CTR 加密。
+//!
+//! 官方 SDK 还支持 AES/GCM/NoPadding，但 GCM 的认证标签附加在密文末尾，
+//! 无法按任意范围下载后单独解密，所以这里只实现了 CTR 模式，
+//! 下载 GCM 加密的文件会返回 [`CryptoError::UnsupportedAlgorithm`](crate::crypto::CryptoError::UnsupportedAlgorithm)。
+
+use std::sync::Arc;
+
+use aes::Aes256;
+use aes_kw::KekAes256;
+use base64::{decode, encode};
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use rand::{thread_rng, RngCore};
+use reqwest::Response;
+use rsa::{pkcs1::DecodeRsaPrivateKey, Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey};
+use thiserror::Error;
+
+use crate::{
+    config::ObjectPath,
+    errors::OssResult,
+    file::File,
+    object::{Conditions, PutOptions, UserMeta},
+    types::ContentRange,
+};
+
+type Aes256Ctr = ctr::Ctr128BE<Aes256>;
+
+const KEY: &str = "client-side-encryption-key";
+const START: &str = "client-side-encryption-start";
+const CEK_ALG: &str = "client-side-encryption-cek-alg";
+const WRAP_ALG: &str = "client-side-encryption-wrap-alg";
+const MATDESC: &str = "client-side-encryption-matdesc";
+const UNENCRYPTED_LENGTH: &str = "client-side-encryption-unencrypted-content-length";
+
+/// 文件内容的加密算法
+pub const AES_CTR: &str = "AES/CTR/NoPadding";
+
+/// [`RsaKeyProvider`] 包装数据密钥的算法
+pub const RSA_PKCS1: &str = "RSA/NONE/PKCS1Padding";
+
+/// [`AesKeyProvider`] 包装数据密钥的算法，即 RFC 3394 AES Key Wrap
+pub const AES_WRAP: &str = "AESWrap";
+
+/// # 数据密钥的包装方式
+/// 每个文件都会生成单独的数据密钥，由 KeyProvider 加密后保存在文件的元信息中，
+/// 可自行实现此 trait 接入 KMS 等密钥管理服务
+pub trait KeyProvider: Send + Sync {
+    /// 包装算法的名称，保存在 `x-oss-meta-client-side-encryption-wrap-alg` 中
+    fn wrap_algorithm(&self) -> &str;
+
+    /// 密钥的描述信息，保存在 `x-oss-meta-client-side-encryption-matdesc` 中
+    fn matdesc(&self) -> Option<String> {
+        None
+    }
+
+    /// 加密数据密钥
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError>;
+
+    /// 解密数据密钥
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError>;
+}
+
+/// # 使用本地 RSA 主密钥包装数据密钥
+/// 与官方 SDK 的 RSA 客户端加密兼容
+pub struct RsaKeyProvider {
+    public: RsaPublicKey,
+    private: Option<RsaPrivateKey>,
+    description: Option<String>,
+}
+
+impl RsaKeyProvider {
+    pub fn new(private: RsaPrivateKey) -> Self {
+        Self {
+            public: private.to_public_key(),
+            private: Some(private),
+            description: None,
+        }
+    }
+
+    /// 只有公钥时只能上传，无法解密下载的文件
+    pub fn from_public_key(public: RsaPublicKey) -> Self {
+        Self {
+            public,
+            private: None,
+            description: None,
+        }
+    }
+
+    /// 从 PKCS#1 PEM 格式的私钥创建
+    pub fn from_pkcs1_pem(pem: &str) -> Result<Self, CryptoError> {
+        Ok(Self::new(RsaPrivateKey::from_pkcs1_pem(pem)?))
+    }
+
+    /// 设置密钥的描述信息，官方 SDK 使用 json 格式
+    pub fn description<D: Into<String>>(mut self, description: D) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl KeyProvider for RsaKeyProvider {
+    fn wrap_algorithm(&self) -> &str {
+        RSA_PKCS1
+    }
+
+    fn matdesc(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        Ok(self
+            .public
+            .encrypt(&mut thread_rng(), Pkcs1v15Encrypt, data)?)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let private = self
+            .private
+            .as_ref()
+            .ok_or(CryptoError::MissingPrivateKey)?;
+
+        Ok(private.decrypt(Pkcs1v15Encrypt, data)?)
+    }
+}
+
+/// # 使用本地 AES 对称主密钥包装数据密钥
+/// 采用 RFC 3394 AES Key Wrap，主密钥为 32 字节，上传和下载使用同一个密钥
+pub struct AesKeyProvider {
+    kek: KekAes256,
+    description: Option<String>,
+}
+
+impl AesKeyProvider {
+    pub fn new(key: [u8; 32]) -> Self {
+        Self {
+            kek: KekAes256::from(key),
+            description: None,
+        }
+    }
+
+    /// 设置密钥的描述信息，官方 SDK 使用 json 格式
+    pub fn description<D: Into<String>>(mut self, description: D) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl KeyProvider for AesKeyProvider {
+    fn wrap_algorithm(&self) -> &str {
+        AES_WRAP
+    }
+
+    fn matdesc(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        Ok(self.kek.wrap_vec(data)?)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        Ok(self.kek.unwrap_vec(data)?)
+    }
+}
+
+/// 单个文件的数据密钥及初始计数器
+pub(crate) struct ContentCipher {
+    key: [u8; 32],
+    iv: [u8; 16],
+}
+
+impl ContentCipher {
+    pub(crate) fn generate() -> Self {
+        let mut key = [0u8; 32];
+        let mut iv = [0u8; 16];
+        thread_rng().fill_bytes(&mut key);
+        thread_rng().fill_bytes(&mut iv);
+
+        Self { key, iv }
+    }
+
+    /// # 加密或解密
+    /// CTR 模式下两者相同，`offset` 为 `data` 在整个文件中的位置，
+    /// 计数器会按 16 字节的块对齐到该位置，所以范围下载的内容也可以直接解密
+    pub(crate) fn apply(&self, data: &mut [u8], offset: u64) {
+        let mut cipher = Aes256Ctr::new(&self.key.into(), &self.iv.into());
+        cipher.seek(offset);
+        cipher.apply_keystream(data);
+    }
+
+    /// 将包装后的数据密钥等信息写入元信息
+    pub(crate) fn write_meta(
+        &self,
+        provider: &dyn KeyProvider,
+        meta: &mut UserMeta,
+        content_length: usize,
+    ) -> OssResult<()> {
+        meta.insert(KEY, encode(provider.encrypt(&self.key)?))?;
+        meta.insert(START, encode(provider.encrypt(&self.iv)?))?;
+        meta.insert(CEK_ALG, AES_CTR)?;
+        meta.insert(WRAP_ALG, provider.wrap_algorithm())?;
+        if let Some(matdesc) = provider.matdesc() {
+            meta.insert(MATDESC, matdesc)?;
+        }
+        meta.insert(UNENCRYPTED_LENGTH, content_length.to_string())?;
+        Ok(())
+    }
+
+    /// 从元信息中读取数据密钥，文件未使用客户端加密时返回 `None`
+    pub(crate) fn from_meta(
+        provider: &dyn KeyProvider,
+        meta: &UserMeta,
+    ) -> Result<Option<Self>, CryptoError> {
+        let key = match meta.get(KEY) {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        let iv = meta.get(START).ok_or(CryptoError::InvalidMeta(START))?;
+
+        let cek_alg = meta.get(CEK_ALG).unwrap_or(AES_CTR);
+        if cek_alg != AES_CTR {
+            return Err(CryptoError::UnsupportedAlgorithm(cek_alg.to_owned()));
+        }
+        let wrap_alg = meta
+            .get(WRAP_ALG)
+            .ok_or(CryptoError::InvalidMeta(WRAP_ALG))?;
+        if wrap_alg != provider.wrap_algorithm() {
+            return Err(CryptoError::UnsupportedAlgorithm(wrap_alg.to_owned()));
+        }
+
+        let key = provider
+            .decrypt(&decode(key)?)?
+            .try_into()
+            .map_err(|_| CryptoError::InvalidMeta(KEY))?;
+        let iv = provider
+            .decrypt(&decode(iv)?)?
+            .try_into()
+            .map_err(|_| CryptoError::InvalidMeta(START))?;
+
+        Ok(Some(Self { key, iv }))
+    }
+}
+
+/// 加密文件内容，并将密钥信息写入上传参数
+fn encrypt_content(
+    provider: &dyn KeyProvider,
+    content: &mut [u8],
+    options: &mut PutOptions,
+) -> OssResult<()> {
+    let cipher = ContentCipher::generate();
+    cipher.write_meta(provider, &mut options.meta, content.len())?;
+    cipher.apply(content, 0);
+    Ok(())
+}
+
+/// 根据文件的元信息解密文件内容，`start` 为请求范围的起始位置
+fn decrypt_content(
+    provider: &dyn KeyProvider,
+    meta: &UserMeta,
+    content: &mut [u8],
+    start: u32,
+) -> OssResult<()> {
+    if let Some(cipher) = ContentCipher::from_meta(provider, meta)? {
+        // 范围无效时 OSS 会返回整个文件
+        let whole = meta
+            .get(UNENCRYPTED_LENGTH)
+            .and_then(|len| len.parse::<usize>().ok())
+            == Some(content.len());
+        let offset = if whole { 0 } else { start };
+        cipher.apply(content, offset as u64);
+    }
+    Ok(())
+}
+
+/// # 客户端加密
+/// 包装实现了 [`File`] 的结构体，上传前在本地使用 AES-256-CTR 加密文件内容，下载后自动解密，
+/// 数据密钥由 [`KeyProvider`] 包装后保存在 `x-oss-meta-client-side-encryption-*` 元信息中，
+/// 与官方 SDK 的客户端加密兼容
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use aliyun_oss_client::crypto::{EncryptionClient, RsaKeyProvider};
+/// use aliyun_oss_client::object::PutOptions;
+/// # use dotenv::dotenv;
+/// # dotenv().ok();
+/// # let client = aliyun_oss_client::Client::from_env().unwrap();
+/// # let pem = std::fs::read_to_string("private.pem").unwrap();
+///
+/// let provider = RsaKeyProvider::from_pkcs1_pem(&pem).unwrap();
+/// let client = EncryptionClient::new(client, provider);
+///
+/// client
+///     .put_content(b"secret".to_vec(), "text/plain", "secret.txt", PutOptions::new())
+///     .await
+///     .unwrap();
+/// let content = client.get_object("secret.txt", ..).await.unwrap();
+/// assert_eq!(content, b"secret");
+/// # }
+/// ```
+///
+/// [`File`]: crate::file::File
+#[derive(Clone)]
+pub struct EncryptionClient<F> {
+    inner: F,
+    provider: Arc<dyn KeyProvider>,
+}
+
+impl<F> EncryptionClient<F> {
+    pub fn new<P: KeyProvider + 'static>(inner: F, provider: P) -> Self {
+        Self {
+            inner,
+            provider: Arc::new(provider),
+        }
+    }
+
+    /// 获取被包装的结构体，用于不需要加密的操作
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+}
+
+impl<F: File> EncryptionClient<F> {
+    /// # 加密后上传文件内容
+    pub async fn put_content<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        mut content: Vec<u8>,
+        content_type: &str,
+        path: OP,
+        mut options: PutOptions,
+    ) -> OssResult<Response> {
+        encrypt_content(self.provider.as_ref(), &mut content, &mut options)?;
+
+        self.inner
+            .put_content_with_options(content, content_type, path, options)
+            .await
+    }
+
+    /// # 下载并解密文件内容
+    /// 文件未使用客户端加密时返回原内容
+    pub async fn get_object<
+        R: Into<ContentRange> + Send + Sync,
+        OP: Into<ObjectPath> + Send + Sync,
+    >(
+        &self,
+        path: OP,
+        range: R,
+    ) -> OssResult<Vec<u8>> {
+        self.get_object_with_conditions(path, range, Conditions::default())
+            .await
+    }
+
+    /// # 按条件下载并解密文件内容
+    /// 参见 [`File::get_object_with_conditions`]
+    pub async fn get_object_with_conditions<
+        R: Into<ContentRange> + Send + Sync,
+        OP: Into<ObjectPath> + Send + Sync,
+    >(
+        &self,
+        path: OP,
+        range: R,
+        conditions: Conditions,
+    ) -> OssResult<Vec<u8>> {
+        let range = range.into();
+        let start = range.start();
+        let (mut content, meta) = self
+            .inner
+            .get_object_with_meta(path, range, conditions)
+            .await?;
+
+        decrypt_content(self.provider.as_ref(), &meta, &mut content, start)?;
+
+        Ok(content)
+    }
+}
+
+/// 客户端加密失败
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CryptoError {
+    #[error("rsa error: {0}")]
+    Rsa(#[from] rsa::Error),
+
+    #[error("pkcs1 error: {0}")]
+    Pkcs1(#[from] rsa::pkcs1::Error),
+
+    #[error("aes key wrap error: {0}")]
+    KeyWrap(#[from] aes_kw::Error),
+
+    #[error("base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("private key is required to decrypt the data key")]
+    MissingPrivateKey,
+
+    #[error("invalid client side encryption meta: {0}")]
+    InvalidMeta(&'static str),
+
+    #[error("unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::rc::Rc;
+
+    use reqwest::blocking::Response;
+
+    use super::{decrypt_content, encrypt_content, KeyProvider};
+    use crate::{
+        config::ObjectPath,
+        errors::OssResult,
+        file::blocking::File,
+        object::{Conditions, PutOptions},
+        types::ContentRange,
+    };
+
+    /// # 客户端加密（阻塞模式）
+    /// 参见 [`EncryptionClient`](super::EncryptionClient)
+    #[derive(Clone)]
+    pub struct EncryptionClient<F> {
+        inner: F,
+        provider: Rc<dyn KeyProvider>,
+    }
+
+    impl<F> EncryptionClient<F> {
+        pub fn new<P: KeyProvider + 'static>(inner: F, provider: P) -> Self {
+            Self {
+                inner,
+                provider: Rc::new(provider),
+            }
+        }
+
+        /// 获取被包装的结构体，用于不需要加密的操作
+        pub fn inner(&self) -> &F {
+            &self.inner
+        }
+    }
+
+    impl<F: File> EncryptionClient<F> {
+        /// # 加密后上传文件内容
+        pub fn put_content<OP: Into<ObjectPath>>(
+            &self,
+            mut content: Vec<u8>,
+            content_type: &str,
+            path: OP,
+            mut options: PutOptions,
+        ) -> OssResult<Response> {
+            encrypt_content(self.provider.as_ref(), &mut content, &mut options)?;
+
+            self.inner
+                .put_content_with_options(content, content_type, path, options)
+        }
+
+        /// # 下载并解密文件内容
+        /// 文件未使用客户端加密时返回原内容
+        pub fn get_object<R: Into<ContentRange>, OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            range: R,
+        ) -> OssResult<Vec<u8>> {
+            self.get_object_with_conditions(path, range, Conditions::default())
+        }
+
+        /// # 按条件下载并解密文件内容
+        /// 参见 [`File::get_object_with_conditions`]
+        pub fn get_object_with_conditions<R: Into<ContentRange>, OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            range: R,
+            conditions: Conditions,
+        ) -> OssResult<Vec<u8>> {
+            let range = range.into();
+            let start = range.start();
+            let (mut content, meta) = self.inner.get_object_with_meta(path, range, conditions)?;
+
+            decrypt_content(self.provider.as_ref(), &meta, &mut content, start)?;
+
+            Ok(content)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+    use rsa::RsaPrivateKey;
+
+    use super::{
+        decrypt_content, encrypt_content, AesKeyProvider, ContentCipher, RsaKeyProvider, AES_CTR,
+        AES_WRAP,
+    };
+    use crate::object::{PutOptions, UserMeta};
+
+    fn init_provider() -> RsaKeyProvider {
+        let private = RsaPrivateKey::new(&mut thread_rng(), 1024).unwrap();
+        RsaKeyProvider::new(private).description(r#"{"desc":"test"}"#)
+    }
+
+    #[test]
+    fn test_content_cipher() {
+        let provider = init_provider();
+
+        let plain: Vec<u8> = (0..100u8).collect();
+        let mut content = plain.clone();
+        let mut options = PutOptions::new();
+        encrypt_content(&provider, &mut content, &mut options).unwrap();
+        assert_ne!(content, plain);
+
+        let meta = &options.meta;
+        assert_eq!(meta.get("client-side-encryption-cek-alg"), Some(AES_CTR));
+        assert_eq!(
+            meta.get("client-side-encryption-wrap-alg"),
+            Some("RSA/NONE/PKCS1Padding")
+        );
+        assert_eq!(
+            meta.get("client-side-encryption-matdesc"),
+            Some(r#"{"desc":"test"}"#)
+        );
+        assert_eq!(
+            meta.get("client-side-encryption-unencrypted-content-length"),
+            Some("100")
+        );
+
+        let mut full = content.clone();
+        decrypt_content(&provider, meta, &mut full, 0).unwrap();
+        assert_eq!(full, plain);
+
+        // 范围无效时返回的整个文件
+        let mut full = content.clone();
+        decrypt_content(&provider, meta, &mut full, 200).unwrap();
+        assert_eq!(full, plain);
+
+        // 起始位置不在块边界上的范围下载
+        let mut part = content[21..60].to_vec();
+        decrypt_content(&provider, meta, &mut part, 21).unwrap();
+        assert_eq!(part, plain[21..60]);
+
+        // 未加密的文件原样返回
+        let mut raw = plain.clone();
+        decrypt_content(&provider, &UserMeta::new(), &mut raw, 0).unwrap();
+        assert_eq!(raw, plain);
+    }
+
+    #[test]
+    fn test_from_meta() {
+        let provider = init_provider();
+        let cipher = ContentCipher::generate();
+
+        let mut meta = UserMeta::new();
+        cipher.write_meta(&provider, &mut meta, 1).unwrap();
+
+        let other = init_provider();
+        assert!(ContentCipher::from_meta(&other, &meta).is_err());
+
+        let public = RsaKeyProvider::from_public_key(provider.public.clone());
+        assert!(ContentCipher::from_meta(&public, &meta).is_err());
+
+        meta.insert("client-side-encryption-cek-alg", "AES/GCM/NoPadding")
+            .unwrap();
+        assert!(ContentCipher::from_meta(&provider, &meta).is_err());
+    }
+
+    #[test]
+    fn test_aes_key_provider() {
+        let provider = AesKeyProvider::new([7u8; 32]);
+
+        let plain: Vec<u8> = (0..100u8).collect();
+        let mut content = plain.clone();
+        let mut options = PutOptions::new();
+        encrypt_content(&provider, &mut content, &mut options).unwrap();
+        assert_ne!(content, plain);
+
+        let meta = &options.meta;
+        assert_eq!(meta.get("client-side-encryption-wrap-alg"), Some(AES_WRAP));
+
+        let mut full = content.clone();
+        decrypt_content(&provider, meta, &mut full, 0).unwrap();
+        assert_eq!(full, plain);
+
+        let other = AesKeyProvider::new([8u8; 32]);
+        assert!(ContentCipher::from_meta(&other, meta).is_err());
+        assert!(ContentCipher::from_meta(&init_provider(), meta).is_err());
+    }
+}
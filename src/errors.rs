@@ -6,9 +6,17 @@ use crate::{
     bucket::InvalidBucketValue,
     builder::BuilderError,
     config::InvalidConfig,
+    integrity::IntegrityError,
+    logging::InvalidAccessLog,
+    object::{InvalidObjectValue, InvalidUserMeta},
+    policy::InvalidPolicy,
+    select::SelectError,
     types::{InvalidBucketName, InvalidEndPoint},
 };
 
+const BUCKET_ALREADY_EXISTS: &str = "BucketAlreadyExists";
+const BUCKET_NOT_EMPTY: &str = "BucketNotEmpty";
+
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum OssError {
@@ -53,10 +61,29 @@ pub enum OssError {
     // #[error("FromUtf8Error: {0}")]
     // FromUtf8Error(#[from] std::string::FromUtf8Error),
     #[error("aliyun response error: {0}")]
-    OssService(#[from] OssService),
+    OssService(OssService),
 
     #[error("{0}")]
-    BuilderError(#[from] BuilderError),
+    BuilderError(BuilderError),
+
+    /// 条件请求中，文件未发生变化（304）
+    ///
+    /// 此变体及以下三个变体由服务端错误单独转换而来，不再包含在
+    /// `OssError::BuilderError(BuilderError::OssService(..))` 中
+    #[error("object not modified")]
+    NotModified,
+
+    /// 条件请求中，前置条件不满足（412）
+    #[error("precondition failed: {0}")]
+    PreconditionFailed(OssService),
+
+    /// 创建 bucket 时，同名 bucket 已存在（409）
+    #[error("bucket already exists: {0}")]
+    BucketAlreadyExists(OssService),
+
+    /// 删除 bucket 时，bucket 中仍有文件或未完成的分片上传（409）
+    #[error("bucket not empty: {0}")]
+    BucketNotEmpty(OssService),
 
     #[error("{0}")]
     InvalidEndPoint(#[from] InvalidEndPoint),
@@ -67,9 +94,36 @@ pub enum OssError {
     #[error("{0}")]
     InvalidBucketName(#[from] InvalidBucketName),
 
+    #[error("{0}")]
+    InvalidObjectValue(#[from] InvalidObjectValue),
+
+    #[error("{0}")]
+    InvalidUserMeta(#[from] InvalidUserMeta),
+
     #[error("{0}")]
     InvalidConfig(#[from] InvalidConfig),
 
+    #[error("{0}")]
+    InvalidAccessLog(#[from] InvalidAccessLog),
+
+    #[error("{0}")]
+    InvalidPolicy(#[from] InvalidPolicy),
+
+    #[error("{0}")]
+    Select(#[from] SelectError),
+
+    #[error("{0}")]
+    Integrity(#[from] IntegrityError),
+
+    #[cfg(feature = "encryption")]
+    #[error("{0}")]
+    Crypto(#[from] crate::crypto::CryptoError),
+
+    /// 后台预取下一页的任务被取消
+    #[cfg(feature = "prefetch")]
+    #[error("prefetch task error: {0}")]
+    Prefetch(#[from] tokio::task::JoinError),
+
     /// 用于 Stream
     #[error("Without More Content")]
     WithoutMore,
@@ -79,16 +133,57 @@ impl OssError {
     /// 返回 oss 服务端的错误信息
     pub fn message(self) -> String {
         match self {
-            OssError::OssService(e) => e.message,
+            OssError::OssService(e)
+            | OssError::PreconditionFailed(e)
+            | OssError::BucketAlreadyExists(e)
+            | OssError::BucketNotEmpty(e) => e.message,
             _ => self.to_string(),
         }
     }
 }
 
+impl OssError {
+    /// 条件请求的 304、412 响应，以及 bucket 已存在、bucket 非空的响应转为单独的错误类型，
+    /// 其他响应原样返回
+    fn classify(service: OssService) -> Result<Self, OssService> {
+        match service.status {
+            StatusCode::NOT_MODIFIED => Ok(OssError::NotModified),
+            StatusCode::PRECONDITION_FAILED => Ok(OssError::PreconditionFailed(service)),
+            _ => match service.code.as_str() {
+                BUCKET_ALREADY_EXISTS => Ok(OssError::BucketAlreadyExists(service)),
+                BUCKET_NOT_EMPTY => Ok(OssError::BucketNotEmpty(service)),
+                _ => Err(service),
+            },
+        }
+    }
+}
+
+impl From<OssService> for OssError {
+    /// 见 [`OssError::NotModified`] 等变体，其他响应转为 `OssError::OssService`
+    fn from(service: OssService) -> Self {
+        OssError::classify(service).unwrap_or_else(OssError::OssService)
+    }
+}
+
+impl From<BuilderError> for OssError {
+    /// 见 [`OssError::NotModified`] 等变体，其他 OSS 服务端错误与之前的版本一样，
+    /// 仍为 `OssError::BuilderError(BuilderError::OssService(..))`
+    fn from(error: BuilderError) -> Self {
+        match error {
+            BuilderError::OssService(service) => {
+                OssError::classify(service).unwrap_or_else(|service| {
+                    OssError::BuilderError(BuilderError::OssService(service))
+                })
+            }
+            _ => OssError::BuilderError(error),
+        }
+    }
+}
+
 /// # 保存并返回 OSS 服务端返回是数据
 /// 当服务器返回的状态码不在 200<=x 且 x<300 范围时，则会返回此错误
 ///
-/// 如果解析 xml 格式错误，则会返回默认值，默认值的 status = 200
+/// 如果解析 xml 格式错误（如 HEAD 请求及 304 响应没有响应体），则会返回默认值，但保留响应的状态码
 #[derive(Debug, Error, PartialEq, Eq)]
 pub struct OssService {
     pub code: String,
@@ -122,29 +217,34 @@ impl fmt::Display for OssService {
 impl<'a> OssService {
     /// 解析 oss 的错误信息
     pub fn new(source: &'a str, status: &StatusCode) -> Self {
+        let default = || Self {
+            status: *status,
+            ..Self::default()
+        };
+
         let code0 = match source.find("<Code>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
         let code1 = match source.find("</Code>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
         let message0 = match source.find("<Message>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
         let message1 = match source.find("</Message>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
         let request_id0 = match source.find("<RequestId>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
         let request_id1 = match source.find("</RequestId>") {
             Some(offset) => offset,
-            None => return Self::default(),
+            None => return default(),
         };
 
         Self {
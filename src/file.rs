@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use http::{
-    header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE},
-    HeaderValue, Method,
+    header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE, ETAG, RANGE},
+    HeaderMap, HeaderValue, Method,
 };
 use reqwest::{Response, Url};
 
@@ -9,15 +9,47 @@ use crate::{
     bucket::Bucket,
     builder::{ArcPointer, BuilderError, RequestBuilder},
     config::{ObjectBase, ObjectPath},
+    cors::{preflight_headers, CorsPreflight},
     errors::{OssError, OssResult},
-    object::{Object, ObjectList},
+    integrity::{content_md5, crc64, Integrity, CONTENT_MD5},
+    object::{
+        complete_multipart_xml, decode_upload_id, multipart_crc64, Conditions, CopyDirective,
+        CopyOptions, Object, ObjectAcl, ObjectList, PutOptions, RestoreInfo, RestoreRequest,
+        UploadedPart, UserMeta, METADATA_DIRECTIVE,
+    },
+    select::{
+        decode_select_meta, SelectMetaRequest, SelectObjectMeta, SelectObjectResponse,
+        SelectRequest,
+    },
+    tagging::{delete_tagging, get_tagging, put_tagging, Tagging, TAGGING},
     types::{CanonicalizedResource, ContentRange},
-    Client,
+    Client, Query,
 };
 #[cfg(feature = "put_file")]
 use infer::Infer;
 
 use oss_derive::oss_file;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+const ACL: &str = "acl";
+const OBJECT_ACL: &str = "x-oss-object-acl";
+const SYMLINK: &str = "symlink";
+const SYMLINK_TARGET: &str = "x-oss-symlink-target";
+const RESTORE: &str = "restore";
+const OSS_RESTORE: &str = "x-oss-restore";
+const OSS_PROCESS: &str = "x-oss-process";
+const COPY_SOURCE: &str = "x-oss-copy-source";
+const UPLOADS: &str = "uploads";
+const UPLOAD_ID: &str = "uploadId";
+const PART_NUMBER: &str = "partNumber";
+const VERSION_ID: &str = "versionId";
+
+/// 软链接目标、复制源等 header 中的路径需进行 url 编码，除 `-_.~` 外的字符都需要转义
+const PATH_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
 
 /// # 文件相关功能
 ///
@@ -25,9 +57,13 @@ use oss_derive::oss_file;
 /// 在 [`Client`]，[`Bucket`], [`ObjectList`] 等结构体中均已实现，其中 Client 是在默认的 bucket 上操作文件，
 /// 而 Bucket, ObjectList 则是在当前的 bucket 上操作文件
 ///
+/// [`Object`] 上也可调用除 `get_url` 外的全部方法（包括 ACL 等），需把 Client 或 Bucket 作为最后一个参数传入，
+/// 如 `object.get_object_acl(&client)`，此时使用的是传入者所在的 bucket
+///
 /// [`Client`]: crate::client::Client
 /// [`Bucket`]: crate::bucket::Bucket
 /// [`ObjectList`]: crate::object::ObjectList
+/// [`Object`]: crate::object::Object
 #[oss_file(ASYNC)]
 #[async_trait]
 pub trait File: AlignBuilder {
@@ -119,23 +155,90 @@ pub trait File: AlignBuilder {
         content: Vec<u8>,
         content_type: &str,
         path: OP,
+    ) -> OssResult<Response> {
+        self.put_content_with_options(content, content_type, path, PutOptions::default())
+            .await
+    }
+
+    /// # 上传文件内容到 OSS，可附带自定义元信息、禁止覆盖等参数
+    async fn put_content_with_options<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        content: Vec<u8>,
+        content_type: &str,
+        path: OP,
+        options: PutOptions,
     ) -> OssResult<Response> {
         let (url, canonicalized) = self.get_url(path);
 
         let content_length = content.len().to_string();
-        let headers = vec![
+        let mut headers = vec![
             (
                 CONTENT_LENGTH,
                 HeaderValue::from_str(&content_length).map_err(OssError::from)?,
             ),
             (CONTENT_TYPE, content_type.parse().map_err(OssError::from)?),
         ];
+        headers.extend(options.to_headers()?);
+
+        let integrity = self.get_integrity();
+        if integrity.content_md5 {
+            headers.push((
+                HeaderName::from_static(CONTENT_MD5),
+                content_md5(&content).try_into().map_err(OssError::from)?,
+            ));
+        }
+        let crc = integrity.crc64.then(|| crc64(&content));
 
-        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+        let response = self
+            .builder_with_header(Method::PUT, url, canonicalized, headers)?
             .body(content)
             .send_adjust_error()
-            .await
-            .map_err(OssError::from)
+            .await?;
+
+        integrity.check_upload(crc, response.headers())?;
+        Ok(response)
+    }
+
+    /// # 获取文件的自定义元信息
+    async fn get_user_meta<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+    ) -> OssResult<UserMeta> {
+        let (url, canonicalized) = self.get_url(path);
+
+        let response = self
+            .builder(Method::HEAD, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(UserMeta::from_headers(response.headers()))
+    }
+
+    /// # 修改文件的自定义元信息
+    /// 通过将文件复制到自身实现，会替换掉原有的全部自定义元信息，
+    /// Content-Type、Cache-Control 等标准 header 以及服务端加密、存储类型保持不变，
+    /// 客户端加密的 `x-oss-meta-client-side-encryption-*` 元信息也会保留
+    ///
+    /// 复制时会校验 HEAD 得到的 ETag，期间文件被修改则返回 [`OssError::PreconditionFailed`]
+    async fn put_user_meta<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        meta: UserMeta,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = self.get_url(path);
+
+        let response = self
+            .builder(Method::HEAD, url.clone(), canonicalized.clone())?
+            .send_adjust_error()
+            .await?;
+
+        let headers = replace_meta_headers(response.headers(), &canonicalized, &meta)?;
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
     }
 
     /// # 获取 OSS 上的文件内容
@@ -144,30 +247,512 @@ pub trait File: AlignBuilder {
         path: OP,
         range: R,
     ) -> OssResult<Vec<u8>> {
+        self.get_object_with_conditions(path, range, Conditions::default())
+            .await
+    }
+
+    /// # 按条件获取 OSS 上的文件内容
+    /// 条件不满足时返回 [`OssError::NotModified`] 或 [`OssError::PreconditionFailed`]
+    async fn get_object_with_conditions<
+        R: Into<ContentRange> + Send + Sync,
+        OP: Into<ObjectPath> + Send + Sync,
+    >(
+        &self,
+        path: OP,
+        range: R,
+        conditions: Conditions,
+    ) -> OssResult<Vec<u8>> {
+        let (content, _) = self.get_object_with_meta(path, range, conditions).await?;
+        Ok(content)
+    }
+
+    /// # 按条件获取 OSS 上的文件内容，同时返回文件的自定义元信息
+    async fn get_object_with_meta<
+        R: Into<ContentRange> + Send + Sync,
+        OP: Into<ObjectPath> + Send + Sync,
+    >(
+        &self,
+        path: OP,
+        range: R,
+        conditions: Conditions,
+    ) -> OssResult<(Vec<u8>, UserMeta)> {
+        let (url, canonicalized) =
+            with_version_id(self.get_url(path), conditions.version_id.as_deref());
+
+        let mut headers = vec![(RANGE, range.into().into())];
+        headers.extend(conditions.to_headers()?);
+
+        let response = self
+            .builder_with_header(Method::GET, url, canonicalized, headers)?
+            .send_adjust_error()
+            .await?;
+        let (status, headers) = (response.status(), response.headers().clone());
+        let content = response.bytes().await?;
+
+        self.get_integrity()
+            .check_download(status, &headers, &content)?;
+
+        Ok((content.to_vec(), UserMeta::from_headers(&headers)))
+    }
+
+    /// # 获取文件的全部 header 信息，不返回文件内容
+    /// 条件不满足时返回 [`OssError::NotModified`] 或 [`OssError::PreconditionFailed`]
+    async fn head_object<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        conditions: Conditions,
+    ) -> OssResult<HeaderMap> {
+        let (url, canonicalized) =
+            with_version_id(self.get_url(path), conditions.version_id.as_deref());
+
+        let response = self
+            .builder_with_header(Method::HEAD, url, canonicalized, conditions.to_headers()?)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(response.headers().clone())
+    }
+
+    /// # 在当前 bucket 内复制文件
+    /// `path` 为目标文件路径，`source` 为源文件路径，条件作用于源文件
+    async fn copy_object<OP: Into<ObjectPath> + Send + Sync, SP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        source: SP,
+        conditions: Conditions,
+    ) -> OssResult<()> {
+        self.copy_object_with_options(path, source, CopyOptions::new().conditions(conditions))
+            .await
+    }
+
+    /// # 在当前 bucket 内复制文件，可附带源文件条件、服务端加密等参数
+    async fn copy_object_with_options<
+        OP: Into<ObjectPath> + Send + Sync,
+        SP: Into<ObjectPath> + Send + Sync,
+    >(
+        &self,
+        path: OP,
+        source: SP,
+        options: CopyOptions,
+    ) -> OssResult<()> {
         let (url, canonicalized) = self.get_url(path);
+        let (_, source) = self.get_url(source);
+
+        let mut headers = vec![(
+            HeaderName::from_static(COPY_SOURCE),
+            copy_source(&source, options.source_version_id.as_deref())?,
+        )];
+        headers.extend(options.to_headers()?);
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .send_adjust_error()
+            .await?;
 
-        let list: Vec<(_, HeaderValue)> = vec![("Range".parse().unwrap(), range.into().into())];
+        Ok(())
+    }
+
+    /// # 初始化分片上传，返回 upload id
+    /// 自定义元信息、服务端加密等参数需在此时设置，上传完成后作用于最终的文件
+    async fn init_multipart_upload<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        content_type: &str,
+        options: PutOptions,
+    ) -> OssResult<String> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(UPLOADS, "")]);
+
+        let mut headers = vec![(CONTENT_TYPE, content_type.parse().map_err(OssError::from)?)];
+        headers.extend(options.to_headers()?);
 
         let content = self
-            .builder_with_header(Method::GET, url, canonicalized, list)?
+            .builder_with_header(Method::POST, url, canonicalized, headers)?
             .send_adjust_error()
             .await?
             .text()
             .await?;
 
-        Ok(content.into_bytes())
+        decode_upload_id(&content)
+    }
+
+    /// # 上传一个分片
+    /// 分片编号从 1 开始，最大为 10000，开启数据完整性校验时会携带 Content-MD5，
+    /// 并比对分片的 CRC64，校验值记录在返回的 [`UploadedPart`] 中
+    async fn upload_part<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        upload_id: &str,
+        part_number: u32,
+        content: Vec<u8>,
+    ) -> OssResult<UploadedPart> {
+        let part = part_number.to_string();
+        let (url, canonicalized) = with_sub_resource(
+            self.get_url(path),
+            [(PART_NUMBER, part.as_str()), (UPLOAD_ID, upload_id)],
+        );
+
+        let size = content.len() as u64;
+        let mut headers = vec![(
+            CONTENT_LENGTH,
+            HeaderValue::from_str(&size.to_string()).map_err(OssError::from)?,
+        )];
+
+        let integrity = self.get_integrity();
+        if integrity.content_md5 {
+            headers.push((
+                HeaderName::from_static(CONTENT_MD5),
+                content_md5(&content).try_into().map_err(OssError::from)?,
+            ));
+        }
+        let crc = integrity.crc64.then(|| crc64(&content));
+
+        let response = self
+            .builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .body(content)
+            .send_adjust_error()
+            .await?;
+
+        integrity.check_upload(crc, response.headers())?;
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .ok_or_else(|| OssError::Input("get Etag error".to_string()))?
+            .to_str()
+            .map_err(OssError::from)?;
+
+        let part = UploadedPart::new(part_number, etag);
+        Ok(match crc {
+            Some(crc) => part.crc64(crc, size),
+            None => part,
+        })
+    }
+
+    /// # 完成分片上传，合并全部分片为最终的文件
+    /// 开启 CRC64 校验且全部分片都带有校验值时，会合并各分片的校验值并与最终文件的校验值比对
+    async fn complete_multipart_upload<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        upload_id: &str,
+        parts: &[UploadedPart],
+    ) -> OssResult<()> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(UPLOAD_ID, upload_id)]);
+
+        let response = self
+            .builder(Method::POST, url, canonicalized)?
+            .body(complete_multipart_xml(parts))
+            .send_adjust_error()
+            .await?;
+
+        self.get_integrity()
+            .check_upload(multipart_crc64(parts), response.headers())?;
+        Ok(())
+    }
+
+    /// # 取消分片上传，并删除已上传的分片
+    async fn abort_multipart_upload<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        upload_id: &str,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(UPLOAD_ID, upload_id)]);
+
+        self.builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
     }
 
     /// # 删除 OSS 上的文件
     async fn delete_object<OP: Into<ObjectPath> + Send + Sync>(&self, path: OP) -> OssResult<()> {
+        self.delete_object_with_conditions(path, Conditions::default())
+            .await
+    }
+
+    /// # 删除 OSS 上的文件，可指定版本
+    /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]，指定版本时永久删除该版本，
+    /// 版本号为删除标记时，删除的是该删除标记
+    async fn delete_object_with_conditions<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        conditions: Conditions,
+    ) -> OssResult<()> {
+        let (url, canonicalized) =
+            with_version_id(self.get_url(path), conditions.version_id.as_deref());
+
+        self.builder_with_header(
+            Method::DELETE,
+            url,
+            canonicalized,
+            conditions.to_payer_headers(),
+        )?
+        .send_adjust_error()
+        .await?;
+
+        Ok(())
+    }
+
+    /// # 获取 OSS 上文件的访问权限（ACL）
+    async fn get_object_acl<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+    ) -> OssResult<ObjectAcl> {
+        self.get_object_acl_with_conditions(path, Conditions::default())
+            .await
+    }
+
+    /// # 获取 OSS 上文件的访问权限（ACL），可指定版本
+    /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]
+    async fn get_object_acl_with_conditions<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        conditions: Conditions,
+    ) -> OssResult<ObjectAcl> {
+        let (url, canonicalized) = with_version_id(
+            with_sub_resource(self.get_url(path), [(ACL, "")]),
+            conditions.version_id.as_deref(),
+        );
+
+        let content = self
+            .builder_with_header(
+                Method::GET,
+                url,
+                canonicalized,
+                conditions.to_payer_headers(),
+            )?
+            .send_adjust_error()
+            .await?
+            .text()
+            .await?;
+
+        ObjectAcl::from_xml(&content)
+    }
+
+    /// # 设置 OSS 上文件的访问权限（ACL）
+    async fn put_object_acl<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        acl: ObjectAcl,
+    ) -> OssResult<()> {
+        self.put_object_acl_with_conditions(path, acl, Conditions::default())
+            .await
+    }
+
+    /// # 设置 OSS 上文件的访问权限（ACL），可指定版本
+    /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]
+    async fn put_object_acl_with_conditions<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        acl: ObjectAcl,
+        conditions: Conditions,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = with_version_id(
+            with_sub_resource(self.get_url(path), [(ACL, "")]),
+            conditions.version_id.as_deref(),
+        );
+
+        let mut headers = vec![(
+            HeaderName::from_static(OBJECT_ACL),
+            acl.as_ref().parse().map_err(OssError::from)?,
+        )];
+        headers.extend(conditions.to_payer_headers());
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 获取 OSS 上文件的标签
+    /// 只使用条件中的 [`Conditions::version_id`]
+    async fn get_object_tagging<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        conditions: Conditions,
+    ) -> OssResult<Tagging> {
+        get_tagging(
+            self,
+            with_version_id(
+                with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                conditions.version_id.as_deref(),
+            ),
+        )
+        .await
+    }
+
+    /// # 设置 OSS 上文件的标签
+    /// 会覆盖已有的全部标签，只使用条件中的 [`Conditions::version_id`]
+    async fn put_object_tagging<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        tagging: Tagging,
+        conditions: Conditions,
+    ) -> OssResult<()> {
+        put_tagging(
+            self,
+            with_version_id(
+                with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                conditions.version_id.as_deref(),
+            ),
+            tagging,
+        )
+        .await
+    }
+
+    /// # 删除 OSS 上文件的全部标签
+    /// 只使用条件中的 [`Conditions::version_id`]
+    async fn delete_object_tagging<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        conditions: Conditions,
+    ) -> OssResult<()> {
+        delete_tagging(
+            self,
+            with_version_id(
+                with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                conditions.version_id.as_deref(),
+            ),
+        )
+        .await
+    }
+
+    /// # 发送跨域预检请求（OPTIONS）
+    /// 返回 OSS 按 bucket 的 CORS 规则允许的请求方式、header 等，可用于验证 CORS 规则
+    async fn options_object<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        origin: &str,
+        method: Method,
+        headers: &[&str],
+    ) -> OssResult<CorsPreflight> {
         let (url, canonicalized) = self.get_url(path);
 
-        self.builder(Method::DELETE, url, canonicalized)?
+        let response = self
+            .builder_with_header(
+                Method::OPTIONS,
+                url,
+                canonicalized,
+                preflight_headers(origin, &method, headers)?,
+            )?
+            .send_adjust_error()
+            .await?;
+
+        CorsPreflight::from_headers(response.headers())
+    }
+
+    /// # 创建软链接
+    /// `path` 为软链接文件的路径，`target` 为软链接指向的目标文件路径
+    async fn put_symlink<OP: Into<ObjectPath> + Send + Sync, TP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        target: TP,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(SYMLINK, "")]);
+
+        let headers = vec![(
+            HeaderName::from_static(SYMLINK_TARGET),
+            encode_symlink_target(target)?,
+        )];
+
+        self.builder_with_header(Method::PUT, url, canonicalized, headers)?
             .send_adjust_error()
             .await?;
 
         Ok(())
     }
+
+    /// # 获取软链接指向的目标文件路径
+    async fn get_symlink<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+    ) -> OssResult<ObjectPath> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(SYMLINK, "")]);
+
+        let response = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        decode_symlink_target(response.headers().get(SYMLINK_TARGET))
+    }
+
+    /// # 解冻归档、冷归档类型的文件
+    /// 解冻需要一定时间，可通过 [`get_restore_info`] 查询解冻进度
+    ///
+    /// [`get_restore_info`]: crate::file::File::get_restore_info
+    async fn restore_object<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        request: RestoreRequest,
+    ) -> OssResult<()> {
+        let (url, canonicalized) = with_sub_resource(self.get_url(path), [(RESTORE, "")]);
+
+        self.builder(Method::POST, url, canonicalized)?
+            .body(request.to_xml())
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 查询文件的解冻状态
+    /// 未提交过解冻请求时返回 None
+    async fn get_restore_info<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+    ) -> OssResult<Option<RestoreInfo>> {
+        let (url, canonicalized) = self.get_url(path);
+
+        let response = self
+            .builder(Method::HEAD, url, canonicalized)?
+            .send_adjust_error()
+            .await?;
+
+        decode_restore_info(response.headers().get(OSS_RESTORE))
+    }
+
+    /// # 使用 SQL 语句查询 CSV、JSON 文件的内容
+    /// 查询结果可通过 [`into_stream`] 分批读取
+    ///
+    /// [`into_stream`]: crate::select::SelectObjectResponse::into_stream
+    async fn select_object<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        request: SelectRequest,
+    ) -> OssResult<SelectObjectResponse> {
+        let (url, canonicalized) =
+            with_sub_resource(self.get_url(path), [(OSS_PROCESS, request.process())]);
+
+        let response = self
+            .builder(Method::POST, url, canonicalized)?
+            .body(request.to_xml())
+            .send_adjust_error()
+            .await?;
+
+        Ok(SelectObjectResponse::new(response))
+    }
+
+    /// # 获取 CSV、JSON 文件的总行数、总列数等元信息
+    /// 首次调用时会扫描整个文件并保存结果，之后的 select 查询可按行或分片查询
+    async fn create_select_object_meta<OP: Into<ObjectPath> + Send + Sync>(
+        &self,
+        path: OP,
+        request: SelectMetaRequest,
+    ) -> OssResult<SelectObjectMeta> {
+        let (url, canonicalized) =
+            with_sub_resource(self.get_url(path), [(OSS_PROCESS, request.process())]);
+
+        let content = self
+            .builder(Method::POST, url, canonicalized)?
+            .body(request.to_xml())
+            .send_adjust_error()
+            .await?
+            .bytes()
+            .await?;
+
+        decode_select_meta(&content)
+    }
 }
 
 impl File for Client {
@@ -202,6 +787,161 @@ impl File for ObjectList<ArcPointer> {
     }
 }
 
+/// 给 object 的接口地址及签名参数附加子资源，如 `?acl`
+pub(crate) fn with_sub_resource<'a, Q: IntoIterator<Item = (&'a str, &'a str)>>(
+    (mut url, resource): (Url, CanonicalizedResource),
+    query: Q,
+) -> (Url, CanonicalizedResource) {
+    let query = Query::from_iter(query).to_url_query();
+    url.set_query(Some(&query));
+
+    (url, format!("{}?{}", resource, query).into())
+}
+
+/// # 给 object 的接口地址及签名参数附加 `versionId`
+/// 可与 `?acl` 等子资源同时使用，url 中的版本号需进行编码，签名参数中则保持原样，
+/// 未指定版本时原样返回
+pub(crate) fn with_version_id(
+    (mut url, resource): (Url, CanonicalizedResource),
+    version_id: Option<&str>,
+) -> (Url, CanonicalizedResource) {
+    let version_id = match version_id {
+        Some(version_id) => version_id,
+        None => return (url, resource),
+    };
+    let encoded = utf8_percent_encode(version_id, PATH_ENCODE_SET);
+    let query = match url.query() {
+        Some(query) if !query.is_empty() => format!("{}&{}={}", query, VERSION_ID, encoded),
+        _ => format!("{}={}", VERSION_ID, encoded),
+    };
+    url.set_query(Some(&query));
+
+    let separator = if resource.as_ref().contains('?') {
+        '&'
+    } else {
+        '?'
+    };
+
+    (
+        url,
+        format!("{}{}{}={}", resource, separator, VERSION_ID, version_id).into(),
+    )
+}
+
+/// 将软链接的目标路径转化为 header 值
+pub(crate) fn encode_symlink_target<TP: Into<ObjectPath>>(target: TP) -> OssResult<HeaderValue> {
+    let target = target.into();
+    let value = utf8_percent_encode(target.as_ref(), PATH_ENCODE_SET).to_string();
+
+    HeaderValue::from_str(&value).map_err(OssError::from)
+}
+
+/// 由 object 的 CanonicalizedResource（`/bucket/path`）生成复制源的 header 值，
+/// 指定版本时附加 `?versionId=`
+pub(crate) fn copy_source(
+    resource: &CanonicalizedResource,
+    version_id: Option<&str>,
+) -> OssResult<HeaderValue> {
+    let (bucket, path) = resource
+        .as_ref()
+        .trim_start_matches('/')
+        .split_once('/')
+        .ok_or_else(|| OssError::Input("invalid copy source".to_string()))?;
+
+    let mut value = format!("/{}/{}", bucket, utf8_percent_encode(path, PATH_ENCODE_SET));
+    if let Some(version_id) = version_id {
+        value.push_str(&format!("?{}={}", VERSION_ID, version_id));
+    }
+
+    HeaderValue::from_str(&value).map_err(OssError::from)
+}
+
+/// 复制到自身修改元信息时，需沿用的标准 header
+const REPLACE_META_RETAINED: [&str; 10] = [
+    "content-type",
+    "cache-control",
+    "content-disposition",
+    "content-encoding",
+    "content-language",
+    "expires",
+    "x-oss-server-side-encryption",
+    "x-oss-server-side-data-encryption",
+    "x-oss-server-side-encryption-key-id",
+    "x-oss-storage-class",
+];
+
+/// 客户端加密的元信息，替换自定义元信息时需原样保留，否则文件将无法解密
+const CLIENT_SIDE_ENCRYPTION_META: &str = "x-oss-meta-client-side-encryption-";
+
+/// # 生成复制到自身以替换自定义元信息的请求 header
+/// `head` 为 HEAD 请求返回的 header，其中的标准 header 原样保留，并以其中的 ETag 作为复制条件
+pub(crate) fn replace_meta_headers(
+    head: &HeaderMap,
+    resource: &CanonicalizedResource,
+    meta: &UserMeta,
+) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+    let etag = head
+        .get(ETAG)
+        .ok_or_else(|| OssError::Input("get Etag error".to_string()))?
+        .to_str()
+        .map_err(OssError::from)?;
+
+    let is_encryption_meta =
+        |name: &HeaderName| name.as_str().starts_with(CLIENT_SIDE_ENCRYPTION_META);
+
+    let mut headers: Vec<_> = meta
+        .to_headers()?
+        .into_iter()
+        .filter(|(name, _)| !is_encryption_meta(name))
+        .collect();
+    headers.push((
+        HeaderName::from_static(COPY_SOURCE),
+        copy_source(resource, None)?,
+    ));
+    headers.push((
+        HeaderName::from_static(METADATA_DIRECTIVE),
+        CopyDirective::Replace
+            .as_ref()
+            .parse()
+            .map_err(OssError::from)?,
+    ));
+    headers.extend(Conditions::new().if_match(etag).to_copy_headers()?);
+    for name in REPLACE_META_RETAINED {
+        if let Some(value) = head.get(name) {
+            headers.push((HeaderName::from_static(name), value.clone()));
+        }
+    }
+    headers.extend(
+        head.iter()
+            .filter(|(name, _)| is_encryption_meta(name))
+            .map(|(name, value)| (name.clone(), value.clone())),
+    );
+
+    Ok(headers)
+}
+
+/// 从 header 中解析软链接的目标路径
+pub(crate) fn decode_symlink_target(value: Option<&HeaderValue>) -> OssResult<ObjectPath> {
+    let value = value
+        .ok_or_else(|| OssError::Input("get symlink target error".to_string()))?
+        .to_str()
+        .map_err(OssError::from)?;
+
+    let target = percent_decode_str(value)
+        .decode_utf8()
+        .map_err(|_| OssError::Input("symlink target is not valid utf-8".to_string()))?;
+
+    Ok(ObjectPath::new(target.into_owned()))
+}
+
+/// 从 header 中解析文件的解冻状态
+pub(crate) fn decode_restore_info(value: Option<&HeaderValue>) -> OssResult<Option<RestoreInfo>> {
+    match value {
+        Some(value) => Ok(Some(value.to_str().map_err(OssError::from)?.parse()?)),
+        None => Ok(None),
+    }
+}
+
 /// # 对齐 [`Client`]，[`Bucket`], [`ObjectList`] 等结构体的 trait
 ///
 /// 用于他们方便的实现 [`File`] trait
@@ -228,6 +968,12 @@ pub trait AlignBuilder: Send + Sync {
         resource: CanonicalizedResource,
         headers: H,
     ) -> Result<RequestBuilder, BuilderError>;
+
+    /// 数据完整性校验设置，默认不开启
+    #[inline]
+    fn get_integrity(&self) -> Integrity {
+        Integrity::default()
+    }
 }
 
 impl AlignBuilder for Bucket {
@@ -242,6 +988,11 @@ impl AlignBuilder for Bucket {
         self.client()
             .builder_with_header(method, url, resource, headers)
     }
+
+    #[inline]
+    fn get_integrity(&self) -> Integrity {
+        self.client().get_integrity()
+    }
 }
 
 impl AlignBuilder for ObjectList<ArcPointer> {
@@ -256,6 +1007,11 @@ impl AlignBuilder for ObjectList<ArcPointer> {
         self.client()
             .builder_with_header(method, url, resource, headers)
     }
+
+    #[inline]
+    fn get_integrity(&self) -> Integrity {
+        self.client().get_integrity()
+    }
 }
 
 #[cfg(test)]
@@ -361,20 +1117,44 @@ pub mod blocking {
         bucket::Bucket,
         builder::{BuilderError, RcPointer},
         config::{ObjectBase, ObjectPath},
+        cors::{preflight_headers, CorsPreflight},
         errors::{OssError, OssResult},
-        object::{Object, ObjectList},
+        integrity::{content_md5, crc64, Integrity, CONTENT_MD5},
+        object::{
+            complete_multipart_xml, decode_upload_id, multipart_crc64, Conditions, CopyOptions,
+            Object, ObjectAcl, ObjectList, PutOptions, RestoreInfo, RestoreRequest, UploadedPart,
+            UserMeta,
+        },
+        select::{
+            blocking::SelectObjectResponse, decode_select_meta, SelectMetaRequest,
+            SelectObjectMeta, SelectRequest,
+        },
+        tagging::{
+            blocking::{delete_tagging, get_tagging, put_tagging},
+            Tagging, TAGGING,
+        },
         types::{CanonicalizedResource, ContentRange},
         ClientRc,
     };
+
+    use super::{
+        copy_source, decode_restore_info, decode_symlink_target, encode_symlink_target,
+        replace_meta_headers, with_sub_resource, with_version_id, ACL, COPY_SOURCE, OBJECT_ACL,
+        OSS_PROCESS, OSS_RESTORE, PART_NUMBER, RESTORE, SYMLINK, SYMLINK_TARGET, UPLOADS,
+        UPLOAD_ID,
+    };
     use http::{
-        header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE},
-        HeaderValue, Method,
+        header::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE, ETAG, RANGE},
+        HeaderMap, HeaderValue, Method,
     };
     #[cfg(feature = "put_file")]
     use infer::Infer;
     use oss_derive::oss_file;
     use reqwest::{blocking::Response, Url};
 
+    /// # 文件相关功能（同步版本）
+    ///
+    /// 与异步版本相同，`Object<RcPointer>` 上也可调用，如 `object.get_object_acl(&client)`
     #[oss_file]
     pub trait File: AlignBuilder {
         /// 根据文件路径获取最终的调用接口以及相关参数
@@ -461,24 +1241,78 @@ pub mod blocking {
             content: Vec<u8>,
             content_type: &str,
             path: OP,
+        ) -> OssResult<Response> {
+            self.put_content_with_options(content, content_type, path, PutOptions::default())
+        }
+
+        /// # 上传文件内容到 OSS，可附带自定义元信息、禁止覆盖等参数
+        fn put_content_with_options<OP: Into<ObjectPath>>(
+            &self,
+            content: Vec<u8>,
+            content_type: &str,
+            path: OP,
+            options: PutOptions,
         ) -> OssResult<Response> {
             let (url, canonicalized) = self.get_url(path);
 
             let content_length = content.len().to_string();
-            let headers = vec![
+            let mut headers = vec![
                 (
                     CONTENT_LENGTH,
                     HeaderValue::from_str(&content_length).map_err(OssError::from)?,
                 ),
                 (CONTENT_TYPE, content_type.parse().map_err(OssError::from)?),
             ];
+            headers.extend(options.to_headers()?);
+
+            let integrity = self.get_integrity();
+            if integrity.content_md5 {
+                headers.push((
+                    HeaderName::from_static(CONTENT_MD5),
+                    content_md5(&content).try_into().map_err(OssError::from)?,
+                ));
+            }
+            let crc = integrity.crc64.then(|| crc64(&content));
 
             let response = self
                 .builder_with_header(Method::PUT, url, canonicalized, headers)?
-                .body(content);
+                .body(content)
+                .send_adjust_error()?;
 
-            let content = response.send_adjust_error()?;
-            Ok(content)
+            integrity.check_upload(crc, response.headers())?;
+            Ok(response)
+        }
+
+        /// # 获取文件的自定义元信息
+        fn get_user_meta<OP: Into<ObjectPath>>(&self, path: OP) -> OssResult<UserMeta> {
+            let (url, canonicalized) = self.get_url(path);
+
+            let response = self
+                .builder(Method::HEAD, url, canonicalized)?
+                .send_adjust_error()?;
+
+            Ok(UserMeta::from_headers(response.headers()))
+        }
+
+        /// # 修改文件的自定义元信息
+        /// 通过将文件复制到自身实现，会替换掉原有的全部自定义元信息，
+        /// Content-Type、Cache-Control 等标准 header 以及服务端加密、存储类型保持不变，
+        /// 客户端加密的 `x-oss-meta-client-side-encryption-*` 元信息也会保留
+        ///
+        /// 复制时会校验 HEAD 得到的 ETag，期间文件被修改则返回 [`OssError::PreconditionFailed`]
+        fn put_user_meta<OP: Into<ObjectPath>>(&self, path: OP, meta: UserMeta) -> OssResult<()> {
+            let (url, canonicalized) = self.get_url(path);
+
+            let response = self
+                .builder(Method::HEAD, url.clone(), canonicalized.clone())?
+                .send_adjust_error()?;
+
+            let headers = replace_meta_headers(response.headers(), &canonicalized, &meta)?;
+
+            self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+                .send_adjust_error()?;
+
+            Ok(())
         }
 
         /// # 获取文件内容
@@ -487,26 +1321,464 @@ pub mod blocking {
             path: OP,
             range: R,
         ) -> OssResult<Vec<u8>> {
-            let (url, canonicalized) = self.get_url(path);
+            self.get_object_with_conditions(path, range, Conditions::default())
+        }
+
+        /// # 按条件获取 OSS 上的文件内容
+        /// 条件不满足时返回 [`OssError::NotModified`] 或 [`OssError::PreconditionFailed`]
+        fn get_object_with_conditions<R: Into<ContentRange>, OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            range: R,
+            conditions: Conditions,
+        ) -> OssResult<Vec<u8>> {
+            let (content, _) = self.get_object_with_meta(path, range, conditions)?;
+            Ok(content)
+        }
+
+        /// # 按条件获取 OSS 上的文件内容，同时返回文件的自定义元信息
+        fn get_object_with_meta<R: Into<ContentRange>, OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            range: R,
+            conditions: Conditions,
+        ) -> OssResult<(Vec<u8>, UserMeta)> {
+            let (url, canonicalized) =
+                with_version_id(self.get_url(path), conditions.version_id.as_deref());
 
-            let headers: Vec<(_, HeaderValue)> =
-                vec![("Range".parse().unwrap(), range.into().into())];
+            let mut headers = vec![(RANGE, range.into().into())];
+            headers.extend(conditions.to_headers()?);
 
-            Ok(self
+            let response = self
                 .builder_with_header(Method::GET, url, canonicalized, headers)?
+                .send_adjust_error()?;
+            let (status, headers) = (response.status(), response.headers().clone());
+            let content = response.bytes()?;
+
+            self.get_integrity()
+                .check_download(status, &headers, &content)?;
+
+            Ok((content.to_vec(), UserMeta::from_headers(&headers)))
+        }
+
+        /// # 获取文件的全部 header 信息，不返回文件内容
+        /// 条件不满足时返回 [`OssError::NotModified`] 或 [`OssError::PreconditionFailed`]
+        fn head_object<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            conditions: Conditions,
+        ) -> OssResult<HeaderMap> {
+            let (url, canonicalized) =
+                with_version_id(self.get_url(path), conditions.version_id.as_deref());
+
+            let response = self
+                .builder_with_header(Method::HEAD, url, canonicalized, conditions.to_headers()?)?
+                .send_adjust_error()?;
+
+            Ok(response.headers().clone())
+        }
+
+        /// # 在当前 bucket 内复制文件
+        /// `path` 为目标文件路径，`source` 为源文件路径，条件作用于源文件
+        fn copy_object<OP: Into<ObjectPath>, SP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            source: SP,
+            conditions: Conditions,
+        ) -> OssResult<()> {
+            self.copy_object_with_options(path, source, CopyOptions::new().conditions(conditions))
+        }
+
+        /// # 在当前 bucket 内复制文件，可附带源文件条件、服务端加密等参数
+        fn copy_object_with_options<OP: Into<ObjectPath>, SP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            source: SP,
+            options: CopyOptions,
+        ) -> OssResult<()> {
+            let (url, canonicalized) = self.get_url(path);
+            let (_, source) = self.get_url(source);
+
+            let mut headers = vec![(
+                HeaderName::from_static(COPY_SOURCE),
+                copy_source(&source, options.source_version_id.as_deref())?,
+            )];
+            headers.extend(options.to_headers()?);
+
+            self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+                .send_adjust_error()?;
+
+            Ok(())
+        }
+
+        /// # 初始化分片上传，返回 upload id
+        /// 自定义元信息、服务端加密等参数需在此时设置，上传完成后作用于最终的文件
+        fn init_multipart_upload<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            content_type: &str,
+            options: PutOptions,
+        ) -> OssResult<String> {
+            let (url, canonicalized) = with_sub_resource(self.get_url(path), [(UPLOADS, "")]);
+
+            let mut headers = vec![(CONTENT_TYPE, content_type.parse().map_err(OssError::from)?)];
+            headers.extend(options.to_headers()?);
+
+            let content = self
+                .builder_with_header(Method::POST, url, canonicalized, headers)?
                 .send_adjust_error()?
-                .text()?
-                .into_bytes())
+                .text()?;
+
+            decode_upload_id(&content)
+        }
+
+        /// # 上传一个分片
+        /// 分片编号从 1 开始，最大为 10000，开启数据完整性校验时会携带 Content-MD5，
+        /// 并比对分片的 CRC64，校验值记录在返回的 [`UploadedPart`] 中
+        fn upload_part<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            upload_id: &str,
+            part_number: u32,
+            content: Vec<u8>,
+        ) -> OssResult<UploadedPart> {
+            let part = part_number.to_string();
+            let (url, canonicalized) = with_sub_resource(
+                self.get_url(path),
+                [(PART_NUMBER, part.as_str()), (UPLOAD_ID, upload_id)],
+            );
+
+            let size = content.len() as u64;
+            let mut headers = vec![(
+                CONTENT_LENGTH,
+                HeaderValue::from_str(&size.to_string()).map_err(OssError::from)?,
+            )];
+
+            let integrity = self.get_integrity();
+            if integrity.content_md5 {
+                headers.push((
+                    HeaderName::from_static(CONTENT_MD5),
+                    content_md5(&content).try_into().map_err(OssError::from)?,
+                ));
+            }
+            let crc = integrity.crc64.then(|| crc64(&content));
+
+            let response = self
+                .builder_with_header(Method::PUT, url, canonicalized, headers)?
+                .body(content)
+                .send_adjust_error()?;
+
+            integrity.check_upload(crc, response.headers())?;
+
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .ok_or_else(|| OssError::Input("get Etag error".to_string()))?
+                .to_str()
+                .map_err(OssError::from)?;
+
+            let part = UploadedPart::new(part_number, etag);
+            Ok(match crc {
+                Some(crc) => part.crc64(crc, size),
+                None => part,
+            })
+        }
+
+        /// # 完成分片上传，合并全部分片为最终的文件
+        /// 开启 CRC64 校验且全部分片都带有校验值时，会合并各分片的校验值并与最终文件的校验值比对
+        fn complete_multipart_upload<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            upload_id: &str,
+            parts: &[UploadedPart],
+        ) -> OssResult<()> {
+            let (url, canonicalized) =
+                with_sub_resource(self.get_url(path), [(UPLOAD_ID, upload_id)]);
+
+            let response = self
+                .builder(Method::POST, url, canonicalized)?
+                .body(complete_multipart_xml(parts))
+                .send_adjust_error()?;
+
+            self.get_integrity()
+                .check_upload(multipart_crc64(parts), response.headers())?;
+            Ok(())
+        }
+
+        /// # 取消分片上传，并删除已上传的分片
+        fn abort_multipart_upload<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            upload_id: &str,
+        ) -> OssResult<()> {
+            let (url, canonicalized) =
+                with_sub_resource(self.get_url(path), [(UPLOAD_ID, upload_id)]);
+
+            self.builder(Method::DELETE, url, canonicalized)?
+                .send_adjust_error()?;
+
+            Ok(())
         }
 
         fn delete_object<OP: Into<ObjectPath>>(&self, path: OP) -> OssResult<()> {
+            self.delete_object_with_conditions(path, Conditions::default())
+        }
+
+        /// # 删除 OSS 上的文件，可指定版本
+        /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]，指定版本时永久删除该版本，
+        /// 版本号为删除标记时，删除的是该删除标记
+        fn delete_object_with_conditions<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            conditions: Conditions,
+        ) -> OssResult<()> {
+            let (url, canonicalized) =
+                with_version_id(self.get_url(path), conditions.version_id.as_deref());
+
+            self.builder_with_header(
+                Method::DELETE,
+                url,
+                canonicalized,
+                conditions.to_payer_headers(),
+            )?
+            .send_adjust_error()?;
+
+            Ok(())
+        }
+
+        /// # 获取 OSS 上文件的访问权限（ACL）
+        fn get_object_acl<OP: Into<ObjectPath>>(&self, path: OP) -> OssResult<ObjectAcl> {
+            self.get_object_acl_with_conditions(path, Conditions::default())
+        }
+
+        /// # 获取 OSS 上文件的访问权限（ACL），可指定版本
+        /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]
+        fn get_object_acl_with_conditions<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            conditions: Conditions,
+        ) -> OssResult<ObjectAcl> {
+            let (url, canonicalized) = with_version_id(
+                with_sub_resource(self.get_url(path), [(ACL, "")]),
+                conditions.version_id.as_deref(),
+            );
+
+            let content = self
+                .builder_with_header(
+                    Method::GET,
+                    url,
+                    canonicalized,
+                    conditions.to_payer_headers(),
+                )?
+                .send_adjust_error()?
+                .text()?;
+
+            ObjectAcl::from_xml(&content)
+        }
+
+        /// # 设置 OSS 上文件的访问权限（ACL）
+        fn put_object_acl<OP: Into<ObjectPath>>(&self, path: OP, acl: ObjectAcl) -> OssResult<()> {
+            self.put_object_acl_with_conditions(path, acl, Conditions::default())
+        }
+
+        /// # 设置 OSS 上文件的访问权限（ACL），可指定版本
+        /// 只使用条件中的 [`Conditions::version_id`] 和 [`Conditions::request_payer`]
+        fn put_object_acl_with_conditions<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            acl: ObjectAcl,
+            conditions: Conditions,
+        ) -> OssResult<()> {
+            let (url, canonicalized) = with_version_id(
+                with_sub_resource(self.get_url(path), [(ACL, "")]),
+                conditions.version_id.as_deref(),
+            );
+
+            let mut headers = vec![(
+                HeaderName::from_static(OBJECT_ACL),
+                acl.as_ref().parse().map_err(OssError::from)?,
+            )];
+            headers.extend(conditions.to_payer_headers());
+
+            self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+                .send_adjust_error()?;
+
+            Ok(())
+        }
+
+        /// # 获取 OSS 上文件的标签
+        /// 只使用条件中的 [`Conditions::version_id`]
+        fn get_object_tagging<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            conditions: Conditions,
+        ) -> OssResult<Tagging> {
+            get_tagging(
+                self,
+                with_version_id(
+                    with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                    conditions.version_id.as_deref(),
+                ),
+            )
+        }
+
+        /// # 设置 OSS 上文件的标签
+        /// 会覆盖已有的全部标签，只使用条件中的 [`Conditions::version_id`]
+        fn put_object_tagging<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            tagging: Tagging,
+            conditions: Conditions,
+        ) -> OssResult<()> {
+            put_tagging(
+                self,
+                with_version_id(
+                    with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                    conditions.version_id.as_deref(),
+                ),
+                tagging,
+            )
+        }
+
+        /// # 删除 OSS 上文件的全部标签
+        /// 只使用条件中的 [`Conditions::version_id`]
+        fn delete_object_tagging<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            conditions: Conditions,
+        ) -> OssResult<()> {
+            delete_tagging(
+                self,
+                with_version_id(
+                    with_sub_resource(self.get_url(path), [(TAGGING, "")]),
+                    conditions.version_id.as_deref(),
+                ),
+            )
+        }
+
+        /// # 发送跨域预检请求（OPTIONS）
+        /// 返回 OSS 按 bucket 的 CORS 规则允许的请求方式、header 等，可用于验证 CORS 规则
+        fn options_object<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            origin: &str,
+            method: Method,
+            headers: &[&str],
+        ) -> OssResult<CorsPreflight> {
             let (url, canonicalized) = self.get_url(path);
 
-            self.builder(Method::DELETE, url, canonicalized)?
+            let response = self
+                .builder_with_header(
+                    Method::OPTIONS,
+                    url,
+                    canonicalized,
+                    preflight_headers(origin, &method, headers)?,
+                )?
+                .send_adjust_error()?;
+
+            CorsPreflight::from_headers(response.headers())
+        }
+
+        /// # 创建软链接
+        /// `path` 为软链接文件的路径，`target` 为软链接指向的目标文件路径
+        fn put_symlink<OP: Into<ObjectPath>, TP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            target: TP,
+        ) -> OssResult<()> {
+            let (url, canonicalized) = with_sub_resource(self.get_url(path), [(SYMLINK, "")]);
+
+            let headers = vec![(
+                HeaderName::from_static(SYMLINK_TARGET),
+                encode_symlink_target(target)?,
+            )];
+
+            self.builder_with_header(Method::PUT, url, canonicalized, headers)?
+                .send_adjust_error()?;
+
+            Ok(())
+        }
+
+        /// # 获取软链接指向的目标文件路径
+        fn get_symlink<OP: Into<ObjectPath>>(&self, path: OP) -> OssResult<ObjectPath> {
+            let (url, canonicalized) = with_sub_resource(self.get_url(path), [(SYMLINK, "")]);
+
+            let response = self
+                .builder(Method::GET, url, canonicalized)?
+                .send_adjust_error()?;
+
+            decode_symlink_target(response.headers().get(SYMLINK_TARGET))
+        }
+
+        /// # 解冻归档、冷归档类型的文件
+        /// 解冻需要一定时间，可通过 [`get_restore_info`] 查询解冻进度
+        ///
+        /// [`get_restore_info`]: crate::file::blocking::File::get_restore_info
+        fn restore_object<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            request: RestoreRequest,
+        ) -> OssResult<()> {
+            let (url, canonicalized) = with_sub_resource(self.get_url(path), [(RESTORE, "")]);
+
+            self.builder(Method::POST, url, canonicalized)?
+                .body(request.to_xml())
                 .send_adjust_error()?;
 
             Ok(())
         }
+
+        /// # 查询文件的解冻状态
+        /// 未提交过解冻请求时返回 None
+        fn get_restore_info<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+        ) -> OssResult<Option<RestoreInfo>> {
+            let (url, canonicalized) = self.get_url(path);
+
+            let response = self
+                .builder(Method::HEAD, url, canonicalized)?
+                .send_adjust_error()?;
+
+            decode_restore_info(response.headers().get(OSS_RESTORE))
+        }
+
+        /// # 使用 SQL 语句查询 CSV、JSON 文件的内容
+        /// 返回值可迭代，每一项为一批记录
+        fn select_object<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            request: SelectRequest,
+        ) -> OssResult<SelectObjectResponse> {
+            let (url, canonicalized) =
+                with_sub_resource(self.get_url(path), [(OSS_PROCESS, request.process())]);
+
+            let response = self
+                .builder(Method::POST, url, canonicalized)?
+                .body(request.to_xml())
+                .send_adjust_error()?;
+
+            Ok(SelectObjectResponse::new(response))
+        }
+
+        /// # 获取 CSV、JSON 文件的总行数、总列数等元信息
+        /// 首次调用时会扫描整个文件并保存结果，之后的 select 查询可按行或分片查询
+        fn create_select_object_meta<OP: Into<ObjectPath>>(
+            &self,
+            path: OP,
+            request: SelectMetaRequest,
+        ) -> OssResult<SelectObjectMeta> {
+            let (url, canonicalized) =
+                with_sub_resource(self.get_url(path), [(OSS_PROCESS, request.process())]);
+
+            let content = self
+                .builder(Method::POST, url, canonicalized)?
+                .body(request.to_xml())
+                .send_adjust_error()?
+                .bytes()?;
+
+            decode_select_meta(&content)
+        }
     }
 
     impl File for ClientRc {
@@ -551,6 +1823,12 @@ pub mod blocking {
             resource: CanonicalizedResource,
             headers: H,
         ) -> Result<RequestBuilder, BuilderError>;
+
+        /// 数据完整性校验设置，默认不开启
+        #[inline]
+        fn get_integrity(&self) -> Integrity {
+            Integrity::default()
+        }
     }
 
     /// # 对齐 Client, Bucket, ObjectList 等结构体的 trait
@@ -567,6 +1845,10 @@ pub mod blocking {
             self.client()
                 .builder_with_header(method, url, resource, headers)
         }
+
+        fn get_integrity(&self) -> Integrity {
+            self.client().get_integrity()
+        }
     }
 
     impl AlignBuilder for ObjectList<RcPointer> {
@@ -580,6 +1862,10 @@ pub mod blocking {
             self.client()
                 .builder_with_header(method, url, resource, headers)
         }
+
+        fn get_integrity(&self) -> Integrity {
+            self.client().get_integrity()
+        }
     }
 
     #[cfg(test)]
@@ -0,0 +1,384 @@
+use base64::encode;
+use http::{header::CONTENT_RANGE, HeaderMap, StatusCode};
+use md5::{Digest, Md5};
+use thiserror::Error;
+
+use crate::types::ContentMd5;
+
+/// OSS 返回的文件 CRC64 校验值所在的 header
+pub const OSS_HASH_CRC64: &str = "x-oss-hash-crc64ecma";
+
+/// 请求中携带 Content-MD5 的 header，需参与签名
+pub const CONTENT_MD5: &str = "content-md5";
+
+/// CRC-64/ECMA-182 的多项式（反转形式）
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const TABLE: [u64; 256] = make_table();
+
+const fn make_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLY
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// # CRC64 校验值计算器
+/// 与 OSS 使用的 CRC-64/ECMA 算法一致，支持分段计算
+///
+/// ```
+/// use aliyun_oss_client::integrity::Crc64;
+///
+/// let mut crc = Crc64::new();
+/// crc.update(b"1234");
+/// crc.update(b"56789");
+/// assert_eq!(crc.finish(), 0x995DC9BBDF1939FA);
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crc64(u64);
+
+impl Crc64 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以已有的校验值为起点继续计算
+    pub fn from_value(crc: u64) -> Self {
+        Self(crc)
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = !self.0;
+        for byte in data {
+            crc = TABLE[((crc ^ *byte as u64) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.0 = !crc;
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+
+    /// # 合并两段数据的校验值
+    /// `crc1`、`crc2` 分别为前后两段数据的校验值，`len2` 为后一段数据的长度，
+    /// 用于分片上传时由各分片的校验值得到整个文件的校验值
+    ///
+    /// ```
+    /// use aliyun_oss_client::integrity::{crc64, Crc64};
+    ///
+    /// let crc1 = crc64(b"1234");
+    /// let crc2 = crc64(b"56789");
+    /// assert_eq!(Crc64::combine(crc1, crc2, 5), crc64(b"123456789"));
+    /// ```
+    pub fn combine(mut crc1: u64, crc2: u64, mut len2: u64) -> u64 {
+        if len2 == 0 {
+            return crc1;
+        }
+
+        // odd 为追加一个 0 比特的运算矩阵
+        let mut odd = [0u64; 64];
+        odd[0] = POLY;
+        let mut row = 1u64;
+        for item in odd.iter_mut().skip(1) {
+            *item = row;
+            row <<= 1;
+        }
+
+        let mut even = [0u64; 64];
+        // 追加 2 个 0 比特
+        gf2_matrix_square(&mut even, &odd);
+        // 追加 4 个 0 比特
+        gf2_matrix_square(&mut odd, &even);
+
+        // 每轮将矩阵平方，第一轮即为追加 1 个 0 字节
+        loop {
+            gf2_matrix_square(&mut even, &odd);
+            if len2 & 1 == 1 {
+                crc1 = gf2_matrix_times(&even, crc1);
+            }
+            len2 >>= 1;
+            if len2 == 0 {
+                break;
+            }
+
+            gf2_matrix_square(&mut odd, &even);
+            if len2 & 1 == 1 {
+                crc1 = gf2_matrix_times(&odd, crc1);
+            }
+            len2 >>= 1;
+            if len2 == 0 {
+                break;
+            }
+        }
+
+        crc1 ^ crc2
+    }
+}
+
+fn gf2_matrix_times(mat: &[u64; 64], mut vec: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while vec != 0 {
+        if vec & 1 == 1 {
+            sum ^= mat[i];
+        }
+        vec >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
+    for (n, item) in square.iter_mut().enumerate() {
+        *item = gf2_matrix_times(mat, mat[n]);
+    }
+}
+
+/// 计算数据的 CRC64 校验值
+pub fn crc64(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.finish()
+}
+
+/// 计算数据的 Content-MD5（MD5 摘要的 base64 编码）
+///
+/// ```
+/// use aliyun_oss_client::integrity::content_md5;
+///
+/// assert_eq!(content_md5(b"0123456789").as_ref(), "eB5eJF1ptWaXm4bijSPyxw==");
+/// ```
+pub fn content_md5(data: &[u8]) -> ContentMd5 {
+    ContentMd5::new(encode(Md5::digest(data)))
+}
+
+/// # 数据完整性校验设置
+/// 默认不开启，开启后上传（包括分片上传）和下载时会在客户端计算校验值并与 OSS 返回的值进行比对
+///
+/// ```
+/// use aliyun_oss_client::{integrity::Integrity, Client};
+///
+/// let mut client = Client::new(
+///     "foo1".into(),
+///     "foo2".into(),
+///     "qingdao".into(),
+///     "foo4".into(),
+/// );
+/// client.integrity(Integrity::new().crc64().content_md5());
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Integrity {
+    /// 上传、下载时校验 CRC64
+    pub crc64: bool,
+    /// 上传时携带 Content-MD5，由 OSS 进行校验
+    pub content_md5: bool,
+}
+
+impl Integrity {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 开启 CRC64 校验
+    pub fn crc64(mut self) -> Self {
+        self.crc64 = true;
+        self
+    }
+
+    /// 开启 Content-MD5 校验
+    pub fn content_md5(mut self) -> Self {
+        self.content_md5 = true;
+        self
+    }
+
+    /// 比对上传内容的校验值与 OSS 返回的校验值
+    pub(crate) fn check_upload(
+        &self,
+        client_crc: Option<u64>,
+        headers: &HeaderMap,
+    ) -> Result<(), IntegrityError> {
+        match client_crc {
+            Some(crc) if self.crc64 => check_crc64(crc, headers),
+            _ => Ok(()),
+        }
+    }
+
+    /// # 校验下载的内容
+    /// 范围下载时，OSS 返回的是整个文件的校验值，
+    /// 所以只有在范围覆盖整个文件时才比对 CRC64，否则只检查内容长度是否与范围一致
+    pub(crate) fn check_download(
+        &self,
+        status: StatusCode,
+        headers: &HeaderMap,
+        content: &[u8],
+    ) -> Result<(), IntegrityError> {
+        if !self.crc64 {
+            return Ok(());
+        }
+
+        if status == StatusCode::PARTIAL_CONTENT {
+            let range = match headers
+                .get(CONTENT_RANGE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_content_range)
+            {
+                Some(range) => range,
+                None => return Ok(()),
+            };
+
+            let expected = range.1 - range.0 + 1;
+            if expected != content.len() as u64 {
+                return Err(IntegrityError::LengthMismatch {
+                    expected,
+                    actual: content.len() as u64,
+                });
+            }
+            if range.0 != 0 || expected != range.2 {
+                return Ok(());
+            }
+        }
+
+        check_crc64(crc64(content), headers)
+    }
+}
+
+/// 解析 `bytes 0-9/100` 格式的 Content-Range，返回（起始位置，结束位置，文件总长度）
+fn parse_content_range(value: &str) -> Option<(u64, u64, u64)> {
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, end) = range.split_once('-')?;
+    let (start, end, total) = (start.parse().ok()?, end.parse().ok()?, total.parse().ok()?);
+
+    if start > end {
+        return None;
+    }
+    Some((start, end, total))
+}
+
+/// 没有返回校验值时跳过比对
+fn check_crc64(client: u64, headers: &HeaderMap) -> Result<(), IntegrityError> {
+    let server = match headers
+        .get(OSS_HASH_CRC64)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    let server: u64 = server
+        .parse()
+        .map_err(|_| IntegrityError::InvalidCrc64(server.to_owned()))?;
+
+    if client != server {
+        return Err(IntegrityError::Crc64Mismatch { client, server });
+    }
+    Ok(())
+}
+
+/// 数据完整性校验失败
+#[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntegrityError {
+    #[error("crc64 mismatch, client: {client}, server: {server}")]
+    Crc64Mismatch { client: u64, server: u64 },
+
+    #[error("content length mismatch, expected: {expected}, actual: {actual}")]
+    LengthMismatch { expected: u64, actual: u64 },
+
+    #[error("invalid crc64 header value: {0}")]
+    InvalidCrc64(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{header::CONTENT_RANGE, HeaderMap, StatusCode};
+
+    use super::{crc64, Crc64, Integrity, IntegrityError, OSS_HASH_CRC64};
+
+    #[test]
+    fn test_crc64() {
+        assert_eq!(crc64(b""), 0);
+        assert_eq!(crc64(b"123456789"), 0x995DC9BBDF1939FA);
+
+        let mut crc = Crc64::from_value(crc64(b"1234"));
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), 0x995DC9BBDF1939FA);
+
+        let data = vec![7u8; 1000];
+        let crc1 = crc64(&data[..333]);
+        let crc2 = crc64(&data[333..]);
+        assert_eq!(Crc64::combine(crc1, crc2, 667), crc64(&data));
+        assert_eq!(Crc64::combine(crc1, 0, 0), crc1);
+    }
+
+    #[test]
+    fn test_check_upload() {
+        let mut headers = HeaderMap::new();
+        headers.insert(OSS_HASH_CRC64, crc64(b"abc").to_string().parse().unwrap());
+
+        let integrity = Integrity::new().crc64();
+        assert!(integrity
+            .check_upload(Some(crc64(b"abc")), &headers)
+            .is_ok());
+        assert_eq!(
+            integrity.check_upload(Some(1), &headers),
+            Err(IntegrityError::Crc64Mismatch {
+                client: 1,
+                server: crc64(b"abc")
+            })
+        );
+
+        headers.insert(OSS_HASH_CRC64, "abc".parse().unwrap());
+        assert_eq!(
+            integrity.check_upload(Some(1), &headers),
+            Err(IntegrityError::InvalidCrc64("abc".to_string()))
+        );
+        assert!(Integrity::new().check_upload(Some(1), &headers).is_ok());
+        assert!(integrity.check_upload(Some(1), &HeaderMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_check_download() {
+        let integrity = Integrity::new().crc64();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(OSS_HASH_CRC64, crc64(b"abc").to_string().parse().unwrap());
+        assert!(integrity
+            .check_download(StatusCode::OK, &headers, b"abc")
+            .is_ok());
+        assert!(matches!(
+            integrity.check_download(StatusCode::OK, &headers, b"abd"),
+            Err(IntegrityError::Crc64Mismatch { .. })
+        ));
+
+        headers.insert(CONTENT_RANGE, "bytes 1-2/3".parse().unwrap());
+        assert!(integrity
+            .check_download(StatusCode::PARTIAL_CONTENT, &headers, b"xx")
+            .is_ok());
+        assert_eq!(
+            integrity.check_download(StatusCode::PARTIAL_CONTENT, &headers, b"x"),
+            Err(IntegrityError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        headers.insert(CONTENT_RANGE, "bytes 0-2/3".parse().unwrap());
+        assert!(matches!(
+            integrity.check_download(StatusCode::PARTIAL_CONTENT, &headers, b"abd"),
+            Err(IntegrityError::Crc64Mismatch { .. })
+        ));
+    }
+}
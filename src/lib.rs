@@ -235,6 +235,71 @@ pub mod object;
 #[cfg(feature = "core")]
 pub mod file;
 
+/// # 使用 SQL 语句查询 CSV、JSON 文件
+/// 包含 SelectObject 接口的请求参数以及响应的解析
+#[cfg(feature = "core")]
+pub mod select;
+
+/// # 版本控制
+/// 包含 bucket 版本控制的开启、暂停，以及文件所有版本的列举
+#[cfg(feature = "core")]
+pub mod versioning;
+
+/// # 生命周期规则
+/// 包含过期删除、存储类型转换、清理分片上传等规则的设置
+#[cfg(feature = "core")]
+pub mod lifecycle;
+
+/// # 跨域资源共享（CORS）
+/// 包含 bucket CORS 规则的设置，以及发送预检请求验证规则
+#[cfg(feature = "core")]
+pub mod cors;
+
+/// # 防盗链
+/// 包含 bucket Referer 白名单、黑名单的设置
+#[cfg(feature = "core")]
+pub mod referer;
+
+/// # 静态网站托管
+/// 包含默认首页、404 页以及路由规则的设置
+#[cfg(feature = "core")]
+pub mod website;
+
+/// # 访问日志
+/// 包含 bucket 访问日志转存的设置，以及日志文件的解析
+#[cfg(feature = "core")]
+pub mod logging;
+
+/// # 授权策略
+/// 包含 bucket 授权策略的构建与设置
+#[cfg(feature = "core")]
+pub mod policy;
+
+/// # 标签
+/// 包含 bucket 及文件标签的设置
+#[cfg(feature = "core")]
+pub mod tagging;
+
+/// # 请求者付费
+/// 包含 bucket 请求费用支付方的设置
+#[cfg(feature = "core")]
+pub mod payment;
+
+/// # 跨区域复制
+/// 包含 bucket 数据同步规则、可同步地域及同步进度
+#[cfg(feature = "core")]
+pub mod replication;
+
+/// # 数据完整性校验
+/// 包含 CRC64、Content-MD5 的计算以及与 OSS 返回值的比对
+#[cfg(feature = "core")]
+pub mod integrity;
+
+/// # 客户端加密
+/// 上传前在本地加密文件内容，下载后自动解密
+#[cfg(feature = "encryption")]
+pub mod crypto;
+
 #[cfg(feature = "core")]
 pub mod config;
 
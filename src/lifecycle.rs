@@ -0,0 +1,864 @@
+use chrono::NaiveDate;
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket,
+    builder::BuilderError,
+    client::ClientArc,
+    errors::{OssError, OssResult},
+    file::AlignBuilder,
+    object::{InvalidObjectValue, StorageClass},
+    types::CanonicalizedResource,
+};
+
+const LIFECYCLE: &str = "lifecycle";
+const NO_SUCH_LIFECYCLE: &str = "NoSuchLifecycle";
+
+/// 生命周期规则中的日期格式，只精确到天
+const DATE_FORMAT: &str = "%Y-%m-%dT00:00:00.000Z";
+
+const LIFECYCLE_CONFIGURATION: &[u8] = b"LifecycleConfiguration";
+const RULE: &[u8] = b"Rule";
+const FILTER: &[u8] = b"Filter";
+const NOT: &[u8] = b"Not";
+const OBJECT_SIZE_GREATER_THAN: &[u8] = b"ObjectSizeGreaterThan";
+const OBJECT_SIZE_LESS_THAN: &[u8] = b"ObjectSizeLessThan";
+const ID: &[u8] = b"ID";
+const PREFIX: &[u8] = b"Prefix";
+const STATUS: &[u8] = b"Status";
+const TAG: &[u8] = b"Tag";
+const KEY: &[u8] = b"Key";
+const VALUE: &[u8] = b"Value";
+const EXPIRATION: &[u8] = b"Expiration";
+const TRANSITION: &[u8] = b"Transition";
+const ABORT_MULTIPART_UPLOAD: &[u8] = b"AbortMultipartUpload";
+const NONCURRENT_VERSION_EXPIRATION: &[u8] = b"NoncurrentVersionExpiration";
+const NONCURRENT_VERSION_TRANSITION: &[u8] = b"NoncurrentVersionTransition";
+const DAYS: &[u8] = b"Days";
+const CREATED_BEFORE_DATE: &[u8] = b"CreatedBeforeDate";
+const EXPIRED_OBJECT_DELETE_MARKER: &[u8] = b"ExpiredObjectDeleteMarker";
+const NONCURRENT_DAYS: &[u8] = b"NoncurrentDays";
+const STORAGE_CLASS: &[u8] = b"StorageClass";
+const IS_ACCESS_TIME: &[u8] = b"IsAccessTime";
+const RETURN_TO_STD_WHEN_VISIT: &[u8] = b"ReturnToStdWhenVisit";
+
+const STATUS_ENABLED: &str = "Enabled";
+const STATUS_DISABLED: &str = "Disabled";
+
+/// # 规则的生效时间
+/// 按文件最后修改时间（或最后访问时间）之后的天数，或者指定日期之前修改的文件
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LifecycleTime {
+    Days(u32),
+    CreatedBeforeDate(NaiveDate),
+}
+
+impl LifecycleTime {
+    fn to_xml(&self) -> String {
+        match self {
+            Self::Days(days) => format!("<Days>{}</Days>", days),
+            Self::CreatedBeforeDate(date) => format!(
+                "<CreatedBeforeDate>{}</CreatedBeforeDate>",
+                date.format(DATE_FORMAT)
+            ),
+        }
+    }
+}
+
+/// # 文件过期删除的时间
+/// `ExpiredObjectDeleteMarker` 用于开启了版本控制的 bucket，自动移除过期的删除标记
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expiration {
+    Days(u32),
+    CreatedBeforeDate(NaiveDate),
+    ExpiredObjectDeleteMarker,
+}
+
+impl From<LifecycleTime> for Expiration {
+    fn from(time: LifecycleTime) -> Self {
+        match time {
+            LifecycleTime::Days(days) => Self::Days(days),
+            LifecycleTime::CreatedBeforeDate(date) => Self::CreatedBeforeDate(date),
+        }
+    }
+}
+
+impl Expiration {
+    fn to_xml(&self) -> String {
+        let inner = match self {
+            Self::Days(days) => LifecycleTime::Days(*days).to_xml(),
+            Self::CreatedBeforeDate(date) => LifecycleTime::CreatedBeforeDate(*date).to_xml(),
+            Self::ExpiredObjectDeleteMarker => {
+                "<ExpiredObjectDeleteMarker>true</ExpiredObjectDeleteMarker>".to_owned()
+            }
+        };
+        format!("<Expiration>{}</Expiration>", inner)
+    }
+}
+
+/// # 转换文件的存储类型
+/// `is_access_time` 为 `true` 时按最后访问时间计算天数，需先开启 bucket 的访问跟踪；
+/// 此时 `return_to_std_when_visit` 表示文件被访问后是否转回标准存储
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+    pub time: LifecycleTime,
+    pub storage_class: StorageClass,
+    pub is_access_time: bool,
+    pub return_to_std_when_visit: bool,
+}
+
+impl Transition {
+    pub fn new(time: LifecycleTime, storage_class: StorageClass) -> Self {
+        Self {
+            time,
+            storage_class,
+            is_access_time: false,
+            return_to_std_when_visit: false,
+        }
+    }
+
+    /// 按最后访问时间转换存储类型
+    pub fn access_time(mut self, return_to_std_when_visit: bool) -> Self {
+        self.is_access_time = true;
+        self.return_to_std_when_visit = return_to_std_when_visit;
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<Transition>{}{}</Transition>",
+            self.time.to_xml(),
+            storage_xml(
+                &self.storage_class,
+                self.is_access_time,
+                self.return_to_std_when_visit
+            )
+        )
+    }
+}
+
+/// # 转换文件历史版本的存储类型
+/// 天数从文件变为历史版本时开始计算
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoncurrentVersionTransition {
+    pub noncurrent_days: u32,
+    pub storage_class: StorageClass,
+    pub is_access_time: bool,
+    pub return_to_std_when_visit: bool,
+}
+
+impl NoncurrentVersionTransition {
+    pub fn new(noncurrent_days: u32, storage_class: StorageClass) -> Self {
+        Self {
+            noncurrent_days,
+            storage_class,
+            is_access_time: false,
+            return_to_std_when_visit: false,
+        }
+    }
+
+    /// 按最后访问时间转换存储类型
+    pub fn access_time(mut self, return_to_std_when_visit: bool) -> Self {
+        self.is_access_time = true;
+        self.return_to_std_when_visit = return_to_std_when_visit;
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<NoncurrentVersionTransition><NoncurrentDays>{}</NoncurrentDays>{}</NoncurrentVersionTransition>",
+            self.noncurrent_days,
+            storage_xml(
+                &self.storage_class,
+                self.is_access_time,
+                self.return_to_std_when_visit
+            )
+        )
+    }
+}
+
+fn storage_xml(storage_class: &StorageClass, is_access_time: bool, return_to_std: bool) -> String {
+    let mut xml = format!("<StorageClass>{}</StorageClass>", storage_class);
+    if is_access_time {
+        xml.push_str(&format!(
+            "<IsAccessTime>true</IsAccessTime><ReturnToStdWhenVisit>{}</ReturnToStdWhenVisit>",
+            return_to_std
+        ));
+    }
+    xml
+}
+
+/// # 规则中排除的文件
+/// 排除匹配该前缀的文件，设置了标签时只排除同时带有该标签的文件
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LifecycleNot {
+    pub prefix: String,
+    pub tag: Option<(String, String)>,
+}
+
+impl LifecycleNot {
+    pub fn new<P: Into<String>>(prefix: P) -> Self {
+        Self {
+            prefix: prefix.into(),
+            tag: None,
+        }
+    }
+
+    pub fn tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tag = Some((key.into(), value.into()));
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!("<Not><Prefix>{}</Prefix>", escape(&self.prefix));
+        if let Some((key, value)) = &self.tag {
+            xml.push_str(&tag_xml(key, value));
+        }
+        xml.push_str("</Not>");
+        xml
+    }
+}
+
+/// # 规则的过滤条件
+/// 在前缀及标签的基础上，排除部分文件或按文件大小进一步筛选
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LifecycleFilter {
+    pub not: Option<LifecycleNot>,
+    /// 只匹配大于该字节数的文件
+    pub object_size_greater_than: Option<u64>,
+    /// 只匹配小于该字节数的文件
+    pub object_size_less_than: Option<u64>,
+}
+
+impl LifecycleFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn not(mut self, not: LifecycleNot) -> Self {
+        self.not = Some(not);
+        self
+    }
+
+    pub fn object_size_greater_than(mut self, size: u64) -> Self {
+        self.object_size_greater_than = Some(size);
+        self
+    }
+
+    pub fn object_size_less_than(mut self, size: u64) -> Self {
+        self.object_size_less_than = Some(size);
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = String::from("<Filter>");
+        if let Some(size) = self.object_size_greater_than {
+            xml.push_str(&format!(
+                "<ObjectSizeGreaterThan>{}</ObjectSizeGreaterThan>",
+                size
+            ));
+        }
+        if let Some(size) = self.object_size_less_than {
+            xml.push_str(&format!(
+                "<ObjectSizeLessThan>{}</ObjectSizeLessThan>",
+                size
+            ));
+        }
+        if let Some(not) = &self.not {
+            xml.push_str(&not.to_xml());
+        }
+        xml.push_str("</Filter>");
+        xml
+    }
+}
+
+fn tag_xml(key: &str, value: &str) -> String {
+    format!(
+        "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+        escape(key),
+        escape(value)
+    )
+}
+
+/// # 生命周期规则
+/// 按前缀及标签匹配文件，前缀为空时匹配整个 bucket
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LifecycleRule {
+    pub id: Option<String>,
+    pub prefix: String,
+    pub tags: Vec<(String, String)>,
+    pub filter: Option<LifecycleFilter>,
+    pub enabled: bool,
+    pub expiration: Option<Expiration>,
+    pub transitions: Vec<Transition>,
+    /// 删除未完成的分片上传
+    pub abort_multipart_upload: Option<LifecycleTime>,
+    /// 历史版本过期删除的天数
+    pub noncurrent_version_expiration: Option<u32>,
+    pub noncurrent_version_transitions: Vec<NoncurrentVersionTransition>,
+}
+
+impl LifecycleRule {
+    /// 创建一条已启用的规则
+    pub fn new<P: Into<String>>(prefix: P) -> Self {
+        Self {
+            prefix: prefix.into(),
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn id<I: Into<String>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// 只匹配带有该标签的文件，可多次调用
+    pub fn tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn filter(mut self, filter: LifecycleFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// 规则暂不生效
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    pub fn expiration(mut self, expiration: Expiration) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// 添加存储类型的转换，可多次调用
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    pub fn abort_multipart_upload(mut self, time: LifecycleTime) -> Self {
+        self.abort_multipart_upload = Some(time);
+        self
+    }
+
+    pub fn noncurrent_version_expiration(mut self, noncurrent_days: u32) -> Self {
+        self.noncurrent_version_expiration = Some(noncurrent_days);
+        self
+    }
+
+    /// 添加历史版本存储类型的转换，可多次调用
+    pub fn noncurrent_version_transition(
+        mut self,
+        transition: NoncurrentVersionTransition,
+    ) -> Self {
+        self.noncurrent_version_transitions.push(transition);
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = String::from("<Rule>");
+        if let Some(id) = &self.id {
+            xml.push_str(&format!("<ID>{}</ID>", escape(id)));
+        }
+        xml.push_str(&format!("<Prefix>{}</Prefix>", escape(&self.prefix)));
+        for (key, value) in self.tags.iter() {
+            xml.push_str(&tag_xml(key, value));
+        }
+        xml.push_str(&format!(
+            "<Status>{}</Status>",
+            if self.enabled {
+                STATUS_ENABLED
+            } else {
+                STATUS_DISABLED
+            }
+        ));
+        if let Some(filter) = &self.filter {
+            xml.push_str(&filter.to_xml());
+        }
+        if let Some(expiration) = &self.expiration {
+            xml.push_str(&expiration.to_xml());
+        }
+        for transition in self.transitions.iter() {
+            xml.push_str(&transition.to_xml());
+        }
+        if let Some(time) = &self.abort_multipart_upload {
+            xml.push_str(&format!(
+                "<AbortMultipartUpload>{}</AbortMultipartUpload>",
+                time.to_xml()
+            ));
+        }
+        if let Some(days) = self.noncurrent_version_expiration {
+            xml.push_str(&format!(
+                "<NoncurrentVersionExpiration><NoncurrentDays>{}</NoncurrentDays></NoncurrentVersionExpiration>",
+                days
+            ));
+        }
+        for transition in self.noncurrent_version_transitions.iter() {
+            xml.push_str(&transition.to_xml());
+        }
+        xml.push_str("</Rule>");
+        xml
+    }
+}
+
+/// 转化为 PutBucketLifecycle 接口的请求体
+pub(crate) fn to_xml(rules: &[LifecycleRule]) -> String {
+    let mut xml = String::from("<LifecycleConfiguration>");
+    for rule in rules {
+        xml.push_str(&rule.to_xml());
+    }
+    xml.push_str("</LifecycleConfiguration>");
+    xml
+}
+
+/// 解析 xml 时暂存 `Expiration`、`Transition` 等节点的子节点
+#[derive(Default)]
+struct Fields {
+    days: Option<u32>,
+    date: Option<NaiveDate>,
+    noncurrent_days: Option<u32>,
+    delete_marker: bool,
+    storage_class: Option<StorageClass>,
+    is_access_time: bool,
+    return_to_std: bool,
+    key: String,
+    value: String,
+}
+
+impl Fields {
+    fn time(&self) -> OssResult<LifecycleTime> {
+        match (self.days, self.date) {
+            (Some(days), _) => Ok(LifecycleTime::Days(days)),
+            (None, Some(date)) => Ok(LifecycleTime::CreatedBeforeDate(date)),
+            (None, None) => Err(InvalidObjectValue.into()),
+        }
+    }
+
+    fn storage_class(&mut self) -> OssResult<StorageClass> {
+        self.storage_class
+            .take()
+            .ok_or_else(|| InvalidObjectValue.into())
+    }
+}
+
+fn parse_date(text: &str) -> OssResult<NaiveDate> {
+    let date = text.get(..10).ok_or(InvalidObjectValue)?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(OssError::from)
+}
+
+/// 解析 GetBucketLifecycle 接口返回的 xml
+pub(crate) fn from_xml(xml: &str) -> OssResult<Vec<LifecycleRule>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::with_capacity(xml.len());
+
+    let mut rules = Vec::new();
+    let mut rule: Option<LifecycleRule> = None;
+    let mut fields = Fields::default();
+    // Filter 中的 Prefix、Tag 属于排除条件
+    let mut filter: Option<LifecycleFilter> = None;
+    let mut not: Option<LifecycleNot> = None;
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => match e.name().as_ref() {
+                LIFECYCLE_CONFIGURATION => (),
+                RULE => rule = Some(LifecycleRule::default()),
+                FILTER => filter = Some(LifecycleFilter::default()),
+                NOT => not = Some(LifecycleNot::default()),
+                TAG
+                | EXPIRATION
+                | TRANSITION
+                | ABORT_MULTIPART_UPLOAD
+                | NONCURRENT_VERSION_EXPIRATION
+                | NONCURRENT_VERSION_TRANSITION => fields = Fields::default(),
+                name => {
+                    let raw = reader.read_text(e.to_end().name())?;
+                    let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                    if let Some(rule) = rule.as_mut() {
+                        match name {
+                            ID => rule.id = Some(text.into_owned()),
+                            PREFIX => match not.as_mut() {
+                                Some(not) => not.prefix = text.into_owned(),
+                                None => rule.prefix = text.into_owned(),
+                            },
+                            OBJECT_SIZE_GREATER_THAN => {
+                                if let Some(filter) = filter.as_mut() {
+                                    filter.object_size_greater_than = Some(text.parse()?);
+                                }
+                            }
+                            OBJECT_SIZE_LESS_THAN => {
+                                if let Some(filter) = filter.as_mut() {
+                                    filter.object_size_less_than = Some(text.parse()?);
+                                }
+                            }
+                            STATUS => rule.enabled = text == STATUS_ENABLED,
+                            KEY => fields.key = text.into_owned(),
+                            VALUE => fields.value = text.into_owned(),
+                            DAYS => fields.days = Some(text.parse()?),
+                            CREATED_BEFORE_DATE => fields.date = Some(parse_date(&text)?),
+                            NONCURRENT_DAYS => fields.noncurrent_days = Some(text.parse()?),
+                            EXPIRED_OBJECT_DELETE_MARKER => fields.delete_marker = text == "true",
+                            STORAGE_CLASS => fields.storage_class = Some(text.parse()?),
+                            IS_ACCESS_TIME => fields.is_access_time = text == "true",
+                            RETURN_TO_STD_WHEN_VISIT => fields.return_to_std = text == "true",
+                            _ => (),
+                        }
+                    }
+                }
+            },
+            Event::End(e) => match (rule.as_mut(), e.name().as_ref()) {
+                (Some(rule), TAG) => {
+                    let tag = (
+                        std::mem::take(&mut fields.key),
+                        std::mem::take(&mut fields.value),
+                    );
+                    match not.as_mut() {
+                        Some(not) => not.tag = Some(tag),
+                        None => rule.tags.push(tag),
+                    }
+                }
+                (Some(_), NOT) => {
+                    if let Some(filter) = filter.as_mut() {
+                        filter.not = not.take();
+                    }
+                }
+                (Some(rule), FILTER) => rule.filter = filter.take(),
+                (Some(rule), EXPIRATION) => {
+                    rule.expiration = Some(if fields.delete_marker {
+                        Expiration::ExpiredObjectDeleteMarker
+                    } else {
+                        fields.time()?.into()
+                    })
+                }
+                (Some(rule), TRANSITION) => rule.transitions.push(Transition {
+                    time: fields.time()?,
+                    storage_class: fields.storage_class()?,
+                    is_access_time: fields.is_access_time,
+                    return_to_std_when_visit: fields.return_to_std,
+                }),
+                (Some(rule), ABORT_MULTIPART_UPLOAD) => {
+                    rule.abort_multipart_upload = Some(fields.time()?)
+                }
+                (Some(rule), NONCURRENT_VERSION_EXPIRATION) => {
+                    rule.noncurrent_version_expiration =
+                        Some(fields.noncurrent_days.ok_or(InvalidObjectValue)?)
+                }
+                (Some(rule), NONCURRENT_VERSION_TRANSITION) => rule
+                    .noncurrent_version_transitions
+                    .push(NoncurrentVersionTransition {
+                        noncurrent_days: fields.noncurrent_days.ok_or(InvalidObjectValue)?,
+                        storage_class: fields.storage_class()?,
+                        is_access_time: fields.is_access_time,
+                        return_to_std_when_visit: fields.return_to_std,
+                    }),
+                (Some(_), RULE) => rules.extend(rule.take()),
+                _ => (),
+            },
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(rules)
+}
+
+async fn get_lifecycle<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Vec<LifecycleRule>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => from_xml(&response.text().await?),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_LIFECYCLE => Ok(Vec::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn put_lifecycle<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    rules: &[LifecycleRule],
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(to_xml(rules))
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_lifecycle<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的生命周期规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_lifecycle(&self) -> OssResult<Vec<LifecycleRule>> {
+        get_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE)).await
+    }
+
+    /// # 设置默认 bucket 的生命周期规则
+    /// 会覆盖已有的全部规则
+    pub async fn put_bucket_lifecycle(&self, rules: &[LifecycleRule]) -> OssResult<()> {
+        put_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE), rules).await
+    }
+
+    /// # 删除默认 bucket 的全部生命周期规则
+    pub async fn delete_bucket_lifecycle(&self) -> OssResult<()> {
+        delete_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的生命周期规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_lifecycle(&self) -> OssResult<Vec<LifecycleRule>> {
+        get_lifecycle(self, self.base.get_sub_resource(LIFECYCLE)).await
+    }
+
+    /// # 设置 bucket 的生命周期规则
+    /// 会覆盖已有的全部规则
+    pub async fn put_bucket_lifecycle(&self, rules: &[LifecycleRule]) -> OssResult<()> {
+        put_lifecycle(self, self.base.get_sub_resource(LIFECYCLE), rules).await
+    }
+
+    /// # 删除 bucket 的全部生命周期规则
+    pub async fn delete_bucket_lifecycle(&self) -> OssResult<()> {
+        delete_lifecycle(self, self.base.get_sub_resource(LIFECYCLE)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::{from_xml, to_xml, LifecycleRule, NO_SUCH_LIFECYCLE};
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        types::CanonicalizedResource,
+    };
+
+    pub(super) fn get_lifecycle<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Vec<LifecycleRule>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => from_xml(&response.text()?),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_LIFECYCLE => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn put_lifecycle<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        rules: &[LifecycleRule],
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(to_xml(rules))
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_lifecycle<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的生命周期规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_lifecycle(&self) -> OssResult<Vec<LifecycleRule>> {
+        blocking::get_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE))
+    }
+
+    /// # 设置默认 bucket 的生命周期规则
+    /// 会覆盖已有的全部规则
+    pub fn put_bucket_lifecycle(&self, rules: &[LifecycleRule]) -> OssResult<()> {
+        blocking::put_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE), rules)
+    }
+
+    /// # 删除默认 bucket 的全部生命周期规则
+    pub fn delete_bucket_lifecycle(&self) -> OssResult<()> {
+        blocking::delete_lifecycle(self, self.get_bucket_sub_resource(LIFECYCLE))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的生命周期规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_lifecycle(&self) -> OssResult<Vec<LifecycleRule>> {
+        blocking::get_lifecycle(self, self.base.get_sub_resource(LIFECYCLE))
+    }
+
+    /// # 设置 bucket 的生命周期规则
+    /// 会覆盖已有的全部规则
+    pub fn put_bucket_lifecycle(&self, rules: &[LifecycleRule]) -> OssResult<()> {
+        blocking::put_lifecycle(self, self.base.get_sub_resource(LIFECYCLE), rules)
+    }
+
+    /// # 删除 bucket 的全部生命周期规则
+    pub fn delete_bucket_lifecycle(&self) -> OssResult<()> {
+        blocking::delete_lifecycle(self, self.base.get_sub_resource(LIFECYCLE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{
+        from_xml, to_xml, Expiration, LifecycleFilter, LifecycleNot, LifecycleRule, LifecycleTime,
+        NoncurrentVersionTransition, Transition,
+    };
+    use crate::object::StorageClass;
+
+    #[test]
+    fn test_lifecycle_xml() {
+        let rules = vec![
+            LifecycleRule::new("tmp/")
+                .id("expire-tmp")
+                .tag("type", "a&b")
+                .expiration(Expiration::Days(7))
+                .abort_multipart_upload(LifecycleTime::Days(1)),
+            LifecycleRule::new("log/")
+                .disabled()
+                .transition(
+                    Transition::new(LifecycleTime::Days(30), StorageClass::IA).access_time(true),
+                )
+                .transition(Transition::new(
+                    LifecycleTime::CreatedBeforeDate(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()),
+                    StorageClass::Archive,
+                ))
+                .expiration(Expiration::ExpiredObjectDeleteMarker)
+                .noncurrent_version_expiration(60)
+                .noncurrent_version_transition(NoncurrentVersionTransition::new(
+                    10,
+                    StorageClass::ColdArchive,
+                )),
+        ];
+
+        let xml = to_xml(&rules);
+        assert_eq!(
+            xml,
+            "<LifecycleConfiguration>\
+            <Rule><ID>expire-tmp</ID><Prefix>tmp/</Prefix><Tag><Key>type</Key><Value>a&amp;b</Value></Tag>\
+            <Status>Enabled</Status><Expiration><Days>7</Days></Expiration>\
+            <AbortMultipartUpload><Days>1</Days></AbortMultipartUpload></Rule>\
+            <Rule><Prefix>log/</Prefix><Status>Disabled</Status>\
+            <Expiration><ExpiredObjectDeleteMarker>true</ExpiredObjectDeleteMarker></Expiration>\
+            <Transition><Days>30</Days><StorageClass>IA</StorageClass><IsAccessTime>true</IsAccessTime><ReturnToStdWhenVisit>true</ReturnToStdWhenVisit></Transition>\
+            <Transition><CreatedBeforeDate>2023-01-02T00:00:00.000Z</CreatedBeforeDate><StorageClass>Archive</StorageClass></Transition>\
+            <NoncurrentVersionExpiration><NoncurrentDays>60</NoncurrentDays></NoncurrentVersionExpiration>\
+            <NoncurrentVersionTransition><NoncurrentDays>10</NoncurrentDays><StorageClass>ColdArchive</StorageClass></NoncurrentVersionTransition>\
+            </Rule></LifecycleConfiguration>"
+        );
+
+        assert_eq!(from_xml(&xml).unwrap(), rules);
+    }
+
+    #[test]
+    fn test_lifecycle_from_xml_with_filter() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LifecycleConfiguration>
+          <Rule>
+            <ID>rule</ID>
+            <Prefix>log/</Prefix>
+            <Status>Enabled</Status>
+            <Filter>
+              <Not>
+                <Prefix>log/keep/</Prefix>
+                <Tag><Key>keep</Key><Value>true</Value></Tag>
+              </Not>
+            </Filter>
+            <Expiration>
+              <CreatedBeforeDate>2021-10-01T00:00:00.000Z</CreatedBeforeDate>
+            </Expiration>
+          </Rule>
+        </LifecycleConfiguration>"#;
+
+        let rules = from_xml(xml).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id.as_deref(), Some("rule"));
+        assert_eq!(rules[0].prefix, "log/");
+        assert!(rules[0].tags.is_empty());
+        assert_eq!(
+            rules[0].filter,
+            Some(LifecycleFilter::new().not(LifecycleNot::new("log/keep/").tag("keep", "true")))
+        );
+        assert_eq!(
+            rules[0].expiration,
+            Some(Expiration::CreatedBeforeDate(
+                NaiveDate::from_ymd_opt(2021, 10, 1).unwrap()
+            ))
+        );
+
+        let rules = vec![LifecycleRule::new("log/")
+            .tag("type", "log")
+            .filter(
+                LifecycleFilter::new()
+                    .object_size_greater_than(500)
+                    .object_size_less_than(64000)
+                    .not(LifecycleNot::new("log/keep/").tag("keep", "a&b")),
+            )
+            .expiration(Expiration::Days(30))];
+
+        let xml = to_xml(&rules);
+        assert_eq!(
+            xml,
+            "<LifecycleConfiguration><Rule><Prefix>log/</Prefix>\
+            <Tag><Key>type</Key><Value>log</Value></Tag><Status>Enabled</Status>\
+            <Filter><ObjectSizeGreaterThan>500</ObjectSizeGreaterThan><ObjectSizeLessThan>64000</ObjectSizeLessThan>\
+            <Not><Prefix>log/keep/</Prefix><Tag><Key>keep</Key><Value>a&amp;b</Value></Tag></Not></Filter>\
+            <Expiration><Days>30</Days></Expiration></Rule></LifecycleConfiguration>"
+        );
+        assert_eq!(from_xml(&xml).unwrap(), rules);
+    }
+}
@@ -0,0 +1,480 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use chrono::{DateTime, FixedOffset};
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket,
+    client::ClientArc,
+    errors::OssResult,
+    file::AlignBuilder,
+    types::{BucketName, CanonicalizedResource},
+};
+
+const LOGGING: &str = "logging";
+
+const LOGGING_ENABLED: &[u8] = b"LoggingEnabled";
+const TARGET_BUCKET: &[u8] = b"TargetBucket";
+const TARGET_PREFIX: &[u8] = b"TargetPrefix";
+
+/// 访问日志中表示空值的占位符
+const EMPTY_VALUE: &str = "-";
+/// 访问日志中时间的格式，如 `03/Jan/2019:16:51:56 +0800`
+const LOG_TIME_FORMAT: &str = "%d/%b/%Y:%H:%M:%S %z";
+
+/// # 访问日志转存设置
+/// 日志文件以 `<TargetPrefix><SourceBucket>YYYY-mm-DD-HH-MM-SS-UniqueString` 命名，
+/// 保存在 `target_bucket` 中
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoggingConfiguration {
+    /// 存放日志的 bucket，需与源 bucket 位于同一地域
+    pub target_bucket: BucketName,
+    pub target_prefix: String,
+}
+
+impl LoggingConfiguration {
+    pub fn new(target_bucket: BucketName) -> Self {
+        Self {
+            target_bucket,
+            target_prefix: String::new(),
+        }
+    }
+
+    pub fn target_prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.target_prefix = prefix.into();
+        self
+    }
+
+    /// 转化为 PutBucketLogging 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        format!(
+            "<BucketLoggingStatus><LoggingEnabled><TargetBucket>{}</TargetBucket>\
+            <TargetPrefix>{}</TargetPrefix></LoggingEnabled></BucketLoggingStatus>",
+            self.target_bucket,
+            escape(&self.target_prefix)
+        )
+    }
+
+    /// 解析 GetBucketLogging 接口返回的 xml，未开启时返回 `None`
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Option<Self>> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut enabled = false;
+        let mut target_bucket = None;
+        let mut target_prefix = String::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    LOGGING_ENABLED => enabled = true,
+                    name @ (TARGET_BUCKET | TARGET_PREFIX) => {
+                        let raw = reader.read_text(e.to_end().name())?;
+                        let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                        if name == TARGET_BUCKET {
+                            target_bucket = Some(BucketName::new(text.into_owned())?);
+                        } else {
+                            target_prefix = text.into_owned();
+                        }
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(match target_bucket {
+            Some(target_bucket) if enabled => Some(Self {
+                target_bucket,
+                target_prefix,
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// # 访问日志中的一条记录
+/// 值为 `-` 的字段解析为 `None`，旧格式日志中缺少的末尾字段同样为 `None`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AccessLogRecord {
+    pub remote_ip: String,
+    pub time: DateTime<FixedOffset>,
+    /// 请求行，如 `GET /example.jpg HTTP/1.1`
+    pub request_uri: String,
+    pub http_status: u16,
+    pub sent_bytes: u64,
+    /// 请求耗时，单位为毫秒
+    pub request_time: u64,
+    pub referer: Option<String>,
+    pub user_agent: Option<String>,
+    pub host: String,
+    pub request_id: String,
+    pub logging_flag: bool,
+    /// 请求者的阿里云账号 ID，匿名访问时为 `None`
+    pub requester_id: Option<String>,
+    /// 请求的类型，如 `GetObject`
+    pub operation: String,
+    pub bucket: String,
+    /// 请求的 object，已经过 URL 编码
+    pub key: Option<String>,
+    pub object_size: Option<u64>,
+    /// OSS 处理请求的耗时，单位为毫秒
+    pub server_cost_time: Option<u64>,
+    pub error_code: Option<String>,
+    pub request_length: Option<u64>,
+    /// bucket 拥有者的账号 ID
+    pub user_id: Option<String>,
+    /// object 大小的变化量，单位为字节
+    pub delta_data_size: Option<i64>,
+    pub sync_request: Option<String>,
+    pub storage_class: Option<String>,
+    pub target_storage_class: Option<String>,
+    pub transmission_acceleration_access_point: Option<String>,
+    pub access_key_id: Option<String>,
+}
+
+/// 把日志行拆分为字段，`"..."` 和 `[...]` 中的空格不作为分隔符
+fn split_fields(line: &str) -> Result<Vec<&str>, InvalidAccessLog> {
+    let mut fields = Vec::new();
+    let mut rest = line.trim();
+
+    while !rest.is_empty() {
+        let (field, remain) = match rest.as_bytes()[0] {
+            b'"' => {
+                let end = rest[1..].find('"').ok_or(InvalidAccessLog)? + 1;
+                (&rest[1..end], &rest[end + 1..])
+            }
+            b'[' => {
+                let end = rest.find(']').ok_or(InvalidAccessLog)?;
+                (&rest[1..end], &rest[end + 1..])
+            }
+            _ => match rest.find(' ') {
+                Some(end) => (&rest[..end], &rest[end..]),
+                None => (rest, ""),
+            },
+        };
+        fields.push(field);
+        rest = remain.trim_start();
+    }
+
+    Ok(fields)
+}
+
+impl FromStr for AccessLogRecord {
+    type Err = InvalidAccessLog;
+
+    /// ```
+    /// # use aliyun_oss_client::logging::AccessLogRecord;
+    /// let line = r#"192.168.0.1 - - [03/Jan/2019:16:51:56 +0800] "GET /example.jpg HTTP/1.1" 200 368 10 "-" "curl/7.65.3" "examplebucket.oss-cn-hangzhou.aliyuncs.com" "5C3BD0AC6ACAEB3D8E1E****" "true" "-" "GetObject" "examplebucket" "example.jpg" 368 9 "-" 203 "16725xxxxxx" - "-" "standard" "-" "-" "-""#;
+    /// let record: AccessLogRecord = line.parse().unwrap();
+    /// assert_eq!(record.http_status, 200);
+    /// assert_eq!(record.key.as_deref(), Some("example.jpg"));
+    /// assert!(record.requester_id.is_none());
+    /// ```
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields = split_fields(line)?;
+        // 最早的日志格式包含 18 个字段
+        if fields.len() < 18 {
+            return Err(InvalidAccessLog);
+        }
+
+        let text = |index: usize| -> Option<String> {
+            fields
+                .get(index)
+                .filter(|value| **value != EMPTY_VALUE && !value.is_empty())
+                .map(|value| value.to_string())
+        };
+        fn number<T: FromStr>(value: Option<String>) -> Result<Option<T>, InvalidAccessLog> {
+            value
+                .map(|value| value.parse().map_err(|_| InvalidAccessLog))
+                .transpose()
+        }
+        let required = |index: usize| text(index).ok_or(InvalidAccessLog);
+
+        Ok(Self {
+            remote_ip: fields[0].to_string(),
+            time: DateTime::parse_from_str(fields[3], LOG_TIME_FORMAT)
+                .map_err(|_| InvalidAccessLog)?,
+            request_uri: fields[4].to_string(),
+            http_status: fields[5].parse().map_err(|_| InvalidAccessLog)?,
+            sent_bytes: number(text(6))?.unwrap_or_default(),
+            request_time: number(text(7))?.unwrap_or_default(),
+            referer: text(8),
+            user_agent: text(9),
+            host: required(10)?,
+            request_id: required(11)?,
+            logging_flag: fields[12] == "true",
+            requester_id: text(13),
+            operation: required(14)?,
+            bucket: text(15).unwrap_or_default(),
+            key: text(16),
+            object_size: number(text(17))?,
+            server_cost_time: number(text(18))?,
+            error_code: text(19),
+            request_length: number(text(20))?,
+            user_id: text(21),
+            delta_data_size: number(text(22))?,
+            sync_request: text(23),
+            storage_class: text(24),
+            target_storage_class: text(25),
+            transmission_acceleration_access_point: text(26),
+            access_key_id: text(27),
+        })
+    }
+}
+
+/// # 解析访问日志文件的内容
+/// 每行一条记录，忽略空行
+pub fn parse_access_log(content: &str) -> Result<Vec<AccessLogRecord>, InvalidAccessLog> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct InvalidAccessLog;
+
+impl fmt::Display for InvalidAccessLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "faild parse to access log record")
+    }
+}
+
+impl Error for InvalidAccessLog {}
+
+async fn get_logging<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Option<LoggingConfiguration>> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    LoggingConfiguration::from_xml(&content)
+}
+
+async fn put_logging<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    config: LoggingConfiguration,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(config.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_logging<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的访问日志转存设置
+    /// 未开启时返回 `None`
+    pub async fn get_bucket_logging(&self) -> OssResult<Option<LoggingConfiguration>> {
+        get_logging(self, self.get_bucket_sub_resource(LOGGING)).await
+    }
+
+    /// # 开启默认 bucket 的访问日志转存
+    pub async fn put_bucket_logging(&self, config: LoggingConfiguration) -> OssResult<()> {
+        put_logging(self, self.get_bucket_sub_resource(LOGGING), config).await
+    }
+
+    /// # 关闭默认 bucket 的访问日志转存
+    pub async fn delete_bucket_logging(&self) -> OssResult<()> {
+        delete_logging(self, self.get_bucket_sub_resource(LOGGING)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的访问日志转存设置
+    /// 未开启时返回 `None`
+    pub async fn get_bucket_logging(&self) -> OssResult<Option<LoggingConfiguration>> {
+        get_logging(self, self.base.get_sub_resource(LOGGING)).await
+    }
+
+    /// # 开启 bucket 的访问日志转存
+    pub async fn put_bucket_logging(&self, config: LoggingConfiguration) -> OssResult<()> {
+        put_logging(self, self.base.get_sub_resource(LOGGING), config).await
+    }
+
+    /// # 关闭 bucket 的访问日志转存
+    pub async fn delete_bucket_logging(&self) -> OssResult<()> {
+        delete_logging(self, self.base.get_sub_resource(LOGGING)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::LoggingConfiguration;
+    use crate::{errors::OssResult, file::blocking::AlignBuilder, types::CanonicalizedResource};
+
+    pub(super) fn get_logging<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Option<LoggingConfiguration>> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        LoggingConfiguration::from_xml(&content)
+    }
+
+    pub(super) fn put_logging<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        config: LoggingConfiguration,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(config.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_logging<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的访问日志转存设置
+    /// 未开启时返回 `None`
+    pub fn get_bucket_logging(&self) -> OssResult<Option<LoggingConfiguration>> {
+        blocking::get_logging(self, self.get_bucket_sub_resource(LOGGING))
+    }
+
+    /// # 开启默认 bucket 的访问日志转存
+    pub fn put_bucket_logging(&self, config: LoggingConfiguration) -> OssResult<()> {
+        blocking::put_logging(self, self.get_bucket_sub_resource(LOGGING), config)
+    }
+
+    /// # 关闭默认 bucket 的访问日志转存
+    pub fn delete_bucket_logging(&self) -> OssResult<()> {
+        blocking::delete_logging(self, self.get_bucket_sub_resource(LOGGING))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的访问日志转存设置
+    /// 未开启时返回 `None`
+    pub fn get_bucket_logging(&self) -> OssResult<Option<LoggingConfiguration>> {
+        blocking::get_logging(self, self.base.get_sub_resource(LOGGING))
+    }
+
+    /// # 开启 bucket 的访问日志转存
+    pub fn put_bucket_logging(&self, config: LoggingConfiguration) -> OssResult<()> {
+        blocking::put_logging(self, self.base.get_sub_resource(LOGGING), config)
+    }
+
+    /// # 关闭 bucket 的访问日志转存
+    pub fn delete_bucket_logging(&self) -> OssResult<()> {
+        blocking::delete_logging(self, self.base.get_sub_resource(LOGGING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_access_log, AccessLogRecord, LoggingConfiguration};
+
+    #[test]
+    fn test_logging_xml() {
+        let config = LoggingConfiguration::new("log-bucket".parse().unwrap()).target_prefix("a&b/");
+
+        let xml = config.to_xml();
+        assert_eq!(
+            xml,
+            "<BucketLoggingStatus><LoggingEnabled><TargetBucket>log-bucket</TargetBucket>\
+            <TargetPrefix>a&amp;b/</TargetPrefix></LoggingEnabled></BucketLoggingStatus>"
+        );
+        assert_eq!(LoggingConfiguration::from_xml(&xml).unwrap(), Some(config));
+
+        let disabled = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <BucketLoggingStatus xmlns="http://doc.oss-cn-hangzhou.aliyuncs.com"/>"#;
+        assert_eq!(LoggingConfiguration::from_xml(disabled).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_access_log() {
+        let content = r#"192.168.0.1 - - [03/Jan/2019:16:51:56 +0800] "GET /example.jpg?x=1 HTTP/1.1" 200 368 10 "http://www.aliyun.com/product/oss" "curl/7.65.3 (x86_64)" "examplebucket.oss-cn-hangzhou.aliyuncs.com" "5C3BD0AC6ACAEB3D8E1E****" "true" "16725xxxxxx" "GetObject" "examplebucket" "example.jpg" 368 9 "-" 203 "16725xxxxxx" -15 "-" "standard" "-" "-" "LTAI4FrfJPUSoKm4JHb5****"
+
+10.0.0.1 - - [04/Jan/2019:08:00:00 +0000] "PUT /dir/a%20b.txt HTTP/1.1" 403 0 2 "-" "-" "examplebucket.oss-cn-hangzhou.aliyuncs.com" "5C3BD0AC6ACAEB3D8E1F****" "false" "-" "PutObject" "examplebucket" "dir%2Fa%20b.txt" - 1 "AccessDenied""#;
+
+        let records = parse_access_log(content).unwrap();
+        assert_eq!(records.len(), 2);
+
+        let get = &records[0];
+        assert_eq!(get.remote_ip, "192.168.0.1");
+        assert_eq!(get.time.to_rfc3339(), "2019-01-03T16:51:56+08:00");
+        assert_eq!(get.request_uri, "GET /example.jpg?x=1 HTTP/1.1");
+        assert_eq!(get.sent_bytes, 368);
+        assert_eq!(get.user_agent.as_deref(), Some("curl/7.65.3 (x86_64)"));
+        assert!(get.logging_flag);
+        assert_eq!(get.operation, "GetObject");
+        assert_eq!(get.object_size, Some(368));
+        assert_eq!(get.error_code, None);
+        assert_eq!(get.delta_data_size, Some(-15));
+        assert_eq!(get.storage_class.as_deref(), Some("standard"));
+        assert_eq!(
+            get.access_key_id.as_deref(),
+            Some("LTAI4FrfJPUSoKm4JHb5****")
+        );
+
+        let put = &records[1];
+        assert_eq!(put.http_status, 403);
+        assert!(!put.logging_flag);
+        assert_eq!(put.requester_id, None);
+        assert_eq!(put.key.as_deref(), Some("dir%2Fa%20b.txt"));
+        assert_eq!(put.object_size, None);
+        assert_eq!(put.error_code.as_deref(), Some("AccessDenied"));
+        assert_eq!(put.request_length, None);
+
+        assert!("foo bar".parse::<AccessLogRecord>().is_err());
+        assert!(parse_access_log(r#"1.1.1.1 - - [03/Jan/2019:16:51:56 +0800] "GET"#).is_err());
+    }
+}
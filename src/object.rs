@@ -1,30 +1,42 @@
 #[cfg(feature = "blocking")]
 use crate::builder::RcPointer;
 use crate::builder::{ArcPointer, BuilderError, PointerFamily};
-use crate::client::ClientArc;
 #[cfg(feature = "blocking")]
 use crate::client::ClientRc;
+use crate::client::{ClientArc, OSS_REQUEST_PAYER, PAYER_REQUESTER};
 use crate::config::{BucketBase, ObjectBase, ObjectPath};
 use crate::decode::{RefineObject, RefineObjectList};
 use crate::errors::{OssError, OssResult};
 #[cfg(feature = "blocking")]
 use crate::file::blocking::AlignBuilder as BlockingAlignBuilder;
 use crate::file::AlignBuilder;
+use crate::integrity::Crc64;
 use crate::types::{
-    CanonicalizedResource, Query, QueryKey, QueryValue, UrlQuery, CONTINUATION_TOKEN,
+    CanonicalizedResource, Date, Query, QueryKey, QueryValue, UrlQuery, CONTINUATION_TOKEN,
 };
 use crate::{BucketName, Client};
 use async_stream::try_stream;
 use chrono::{DateTime, NaiveDateTime, Utc};
+use futures::{pin_mut, StreamExt};
 use futures_core::stream::Stream;
-use http::Method;
+use http::{
+    header::{IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_UNMODIFIED_SINCE},
+    HeaderMap, HeaderName, HeaderValue, Method,
+};
 use oss_derive::oss_gen_rc;
+use quick_xml::{events::Event, Reader};
 
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::error::Error;
 use std::fmt;
 #[cfg(feature = "blocking")]
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::vec::IntoIter;
+#[cfg(feature = "prefetch")]
+use tokio::task::JoinHandle;
 
 /// # 存放对象列表的结构体
 /// TODO impl core::ops::Index
@@ -39,6 +51,7 @@ pub struct ObjectList<PointerSel: PointerFamily = ArcPointer> {
     next_continuation_token: Option<String>,
     client: PointerSel::PointerType,
     search_query: Query,
+    common_prefixes: Vec<String>,
 }
 
 impl<T: PointerFamily> fmt::Debug for ObjectList<T> {
@@ -66,6 +79,7 @@ impl Default for ObjectList<ArcPointer> {
             next_continuation_token: None,
             client: Arc::new(ClientArc::default()),
             search_query: Query::default(),
+            common_prefixes: Vec::new(),
         }
     }
 }
@@ -90,6 +104,7 @@ impl<T: PointerFamily> ObjectList<T> {
             next_continuation_token,
             client,
             search_query: Query::from_iter(search_query),
+            common_prefixes: Vec::new(),
         }
     }
 
@@ -133,6 +148,12 @@ impl<T: PointerFamily> ObjectList<T> {
         }
     }
 
+    /// # 使用 `delimiter` 查询时返回的“子目录”列表
+    /// 即 xml 中的 `CommonPrefixes`
+    pub fn common_prefixes(&self) -> &Vec<String> {
+        &self.common_prefixes
+    }
+
     /// 将 object 列表转化为迭代器
     pub fn object_iter(self) -> IntoIter<Object<T>> {
         self.object_list.into_iter()
@@ -185,7 +206,7 @@ impl ObjectList {
 
     /// # 将 object_list 转化为 stream, 返回第二页，第三页... 的内容
     ///
-    /// *不够完善，最后一次迭代返回的是 `Some(Err(OssError::WithoutMore))`，而不是 `None`*
+    /// 依次请求下一页，直到没有更多内容时结束
     ///
     /// ## 用法
     ///
@@ -208,16 +229,135 @@ impl ObjectList {
     /// let stream = object_list.into_stream();
     /// pin_mut!(stream);
     ///
-    /// let second_list = stream.next().await;
-    /// let third_list = stream.next().await;
-    /// println!("second_list: {:?}", second_list);
-    /// println!("third_list: {:?}", third_list);
+    /// while let Some(list) = stream.next().await {
+    ///     println!("list: {:?}", list);
+    /// }
     /// # }
     /// ```
     pub fn into_stream(self) -> impl Stream<Item = OssResult<Self>> {
+        self.without_objects().pages().skip(1)
+    }
+
+    /// # 与 [`into_stream`] 相同，但在调用方处理当前页时，提前在后台请求下一页
+    /// 需开启 `prefetch` feature 并在 tokio 运行时中使用，stream 被提前丢弃时，已发出的请求结果会被忽略
+    ///
+    /// [`into_stream`]: ObjectList::into_stream
+    #[cfg(feature = "prefetch")]
+    pub fn into_prefetch_stream(self) -> impl Stream<Item = OssResult<Self>> {
+        self.without_objects().prefetch_pages().skip(1)
+    }
+
+    /// # 将 object_list 展开为 object 的 stream
+    /// 先返回当前页的 object，再依次返回后续每一页的 object，直到没有更多内容时结束
+    ///
+    /// ```no_run
+    /// # use dotenv::dotenv;
+    /// # use aliyun_oss_client::Client;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # dotenv().ok();
+    /// use futures::{pin_mut, StreamExt};
+    /// # let client = Client::from_env().unwrap();
+    /// # let query = [("max-keys".into(), 100u8.into())];
+    /// # let object_list = client.get_object_list(query).await.unwrap();
+    /// let stream = object_list.into_object_stream();
+    /// pin_mut!(stream);
+    ///
+    /// while let Some(object) = stream.next().await {
+    ///     println!("object: {:?}", object.unwrap().path());
+    /// }
+    /// # }
+    /// ```
+    pub fn into_object_stream(self) -> impl Stream<Item = OssResult<Object>> {
+        Self::flatten(self.pages())
+    }
+
+    /// # 与 [`into_object_stream`] 相同，但会提前在后台请求下一页
+    /// 参见 [`into_prefetch_stream`]
+    ///
+    /// [`into_object_stream`]: ObjectList::into_object_stream
+    /// [`into_prefetch_stream`]: ObjectList::into_prefetch_stream
+    #[cfg(feature = "prefetch")]
+    pub fn into_prefetch_object_stream(self) -> impl Stream<Item = OssResult<Object>> {
+        Self::flatten(self.prefetch_pages())
+    }
+
+    /// 依次返回每一页中的 object
+    fn flatten<S: Stream<Item = OssResult<Self>>>(
+        pages: S,
+    ) -> impl Stream<Item = OssResult<Object>> {
+        try_stream! {
+            pin_mut!(pages);
+
+            while let Some(page) = pages.next().await {
+                for object in page?.object_list {
+                    yield object;
+                }
+            }
+        }
+    }
+
+    /// 从当前页开始依次返回每一页
+    fn pages(self) -> impl Stream<Item = OssResult<Self>> {
+        try_stream! {
+            let mut current = self;
+            loop {
+                let cursor = current.next_query().map(|_| current.without_objects());
+                yield current;
+                match cursor {
+                    Some(cursor) => current = cursor.get_next_list().await?,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// 从当前页开始依次返回每一页，返回当前页前先在后台请求下一页
+    #[cfg(feature = "prefetch")]
+    fn prefetch_pages(self) -> impl Stream<Item = OssResult<Self>> {
         try_stream! {
-            let result = self.get_next_list().await?;
-            yield result;
+            let mut current = self;
+            loop {
+                let next = current.prefetch_next_list();
+                yield current;
+                match next {
+                    Some(handle) => {
+                        // 后台任务 panic 时继续向上传递，被取消时（如运行时关闭）返回错误
+                        current = match handle.await {
+                            Ok(list) => list?,
+                            Err(e) => match e.try_into_panic() {
+                                Ok(panic) => std::panic::resume_unwind(panic),
+                                Err(e) => Err(OssError::from(e))?,
+                            },
+                        };
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// 在后台请求下一页，没有下一页时返回 `None`
+    #[cfg(feature = "prefetch")]
+    fn prefetch_next_list(&self) -> Option<JoinHandle<OssResult<Self>>> {
+        self.next_query()?;
+        let cursor = self.without_objects();
+
+        Some(tokio::spawn(async move { cursor.get_next_list().await }))
+    }
+
+    /// 复制除 object 列表外的内容，用于请求下一页
+    fn without_objects(&self) -> Self {
+        Self {
+            bucket: self.bucket.clone(),
+            prefix: self.prefix.clone(),
+            max_keys: self.max_keys,
+            key_count: self.key_count,
+            object_list: Vec::new(),
+            next_continuation_token: self.next_continuation_token.clone(),
+            client: self.client(),
+            search_query: self.search_query.clone(),
+            common_prefixes: Vec::new(),
         }
     }
 }
@@ -286,6 +426,8 @@ pub struct Object<PointerSel: PointerFamily = ArcPointer> {
     _type: String,
     size: u64,
     storage_class: String,
+    restore_info: Option<RestoreInfo>,
+    owner: Option<Owner>,
 }
 
 impl<T: PointerFamily> Default for Object<T> {
@@ -297,6 +439,8 @@ impl<T: PointerFamily> Default for Object<T> {
             _type: String::default(),
             size: 0,
             storage_class: String::default(),
+            restore_info: None,
+            owner: None,
         }
     }
 }
@@ -320,6 +464,8 @@ impl<T: PointerFamily> Object<T> {
             _type,
             size,
             storage_class,
+            restore_info: None,
+            owner: None,
         }
     }
 
@@ -363,6 +509,18 @@ impl<T: PointerFamily> Object<T> {
         self._type = _type;
     }
 
+    /// 获取 object 的类型，无法识别时返回 Err
+    #[inline]
+    pub fn object_type(&self) -> Result<ObjectType, InvalidObjectValue> {
+        self._type.parse()
+    }
+
+    /// 是否是软链接文件
+    #[inline]
+    pub fn is_symlink(&self) -> bool {
+        matches!(self.object_type(), Ok(ObjectType::Symlink))
+    }
+
     #[inline]
     pub fn size(&self) -> u64 {
         self.size
@@ -383,6 +541,28 @@ impl<T: PointerFamily> Object<T> {
         self.storage_class = storage_class;
     }
 
+    /// 归档类型 object 的解冻状态，未提交过解冻请求或状态无法识别时为 None
+    #[inline]
+    pub fn restore_info(&self) -> Option<&RestoreInfo> {
+        self.restore_info.as_ref()
+    }
+
+    #[inline]
+    pub fn set_restore_info(&mut self, restore_info: Option<RestoreInfo>) {
+        self.restore_info = restore_info;
+    }
+
+    /// 文件的拥有者，查询时设置了 `fetch-owner=true` 才会有值
+    #[inline]
+    pub fn owner(&self) -> Option<&Owner> {
+        self.owner.as_ref()
+    }
+
+    #[inline]
+    pub fn set_owner(&mut self, owner: Option<Owner>) {
+        self.owner = owner;
+    }
+
     /// 获取一部分数据
     pub fn pieces(self) -> (ObjectBase<T>, DateTime<Utc>, String, String, u64, String) {
         (
@@ -404,6 +584,14 @@ impl<T: PointerFamily> Object<T> {
     }
 }
 
+/// # 文件的拥有者
+/// 列举文件时需设置 `fetch-owner=true` 才会返回
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Owner {
+    pub id: String,
+    pub display_name: String,
+}
+
 /// Object 结构体的构建器
 pub struct ObjectBuilder<T: PointerFamily = ArcPointer> {
     object: Object<T>,
@@ -421,6 +609,8 @@ impl<T: PointerFamily> ObjectBuilder<T> {
                 _type: String::default(),
                 size: 0,
                 storage_class: String::default(),
+                restore_info: None,
+                owner: None,
             },
         }
     }
@@ -450,6 +640,16 @@ impl<T: PointerFamily> ObjectBuilder<T> {
         self
     }
 
+    pub fn restore_info(mut self, restore_info: RestoreInfo) -> Self {
+        self.object.restore_info = Some(restore_info);
+        self
+    }
+
+    pub fn owner(mut self, owner: Owner) -> Self {
+        self.object.owner = Some(owner);
+        self
+    }
+
     pub fn build(self) -> Object<T> {
         self.object
     }
@@ -493,6 +693,22 @@ impl<T: PointerFamily + Sized> RefineObject for Object<T> {
         self.storage_class = value.to_string();
         Ok(())
     }
+
+    /// 无法识别的解冻状态记为 None，不影响整页列表的解析
+    #[inline]
+    fn set_restore_info(&mut self, value: &str) -> Result<(), Self::Error> {
+        self.restore_info = value.parse().ok();
+        Ok(())
+    }
+
+    #[inline]
+    fn set_owner(&mut self, id: &str, display_name: &str) -> Result<(), Self::Error> {
+        self.owner = Some(Owner {
+            id: id.to_owned(),
+            display_name: display_name.to_owned(),
+        });
+        Ok(())
+    }
 }
 
 impl<T: PointerFamily> RefineObjectList<Object<T>> for ObjectList<T> {
@@ -532,6 +748,22 @@ impl<T: PointerFamily> RefineObjectList<Object<T>> for ObjectList<T> {
         self.object_list = list;
         Ok(())
     }
+
+    #[inline]
+    fn set_common_prefix(&mut self, list: &[Cow<'_, str>]) -> Result<(), Self::Error> {
+        self.common_prefixes = list.iter().map(|prefix| prefix.to_string()).collect();
+        Ok(())
+    }
+}
+
+const DIR_DELIMITER: &str = "/";
+
+/// 补全目录前缀结尾的 `/`
+fn dir_prefix(mut prefix: String) -> String {
+    if !prefix.is_empty() && !prefix.ends_with(DIR_DELIMITER) {
+        prefix.push_str(DIR_DELIMITER);
+    }
+    prefix
 }
 
 impl Client {
@@ -575,6 +807,41 @@ impl Client {
         Ok(list)
     }
 
+    /// # 浏览默认 bucket 中的“目录”
+    /// 以 `/` 作为分隔符查询 `prefix` 下的内容，会自动翻页直到列举完，
+    /// 分别返回其中的文件以及子目录，目录本身（以 `/` 结尾的空文件）不包含在文件列表中
+    ///
+    /// `prefix` 为空时查询根目录，不以 `/` 结尾时会自动补上
+    pub async fn list_dir<P: Into<String>>(
+        self,
+        prefix: P,
+    ) -> OssResult<(Vec<Object>, Vec<String>)> {
+        let prefix = dir_prefix(prefix.into());
+        let query = [
+            (QueryKey::Prefix, prefix.clone().into()),
+            (QueryKey::Delimiter, DIR_DELIMITER.into()),
+        ];
+
+        let mut list = self.get_object_list(query).await?;
+        let mut files = Vec::new();
+        let mut dirs = Vec::new();
+        loop {
+            files.extend(
+                list.object_list
+                    .drain(..)
+                    .filter(|o| o.path_string() != prefix),
+            );
+            dirs.append(&mut list.common_prefixes);
+
+            if list.next_query().is_none() {
+                break;
+            }
+            list = list.get_next_list().await?;
+        }
+
+        Ok((files, dirs))
+    }
+
     /// # 可将 object 列表导出到外部类型（不仅仅是 struct）
     /// 可以参考下面示例，或者项目中的 `examples/custom.rs`
     /// ## 示例
@@ -732,6 +999,41 @@ impl ClientRc {
         Ok(list)
     }
 
+    /// # 浏览默认 bucket 中的“目录”
+    /// 与 [`Client::list_dir`] 相同
+    pub fn list_dir<P: Into<String>>(
+        self,
+        prefix: P,
+    ) -> OssResult<(Vec<Object<RcPointer>>, Vec<String>)> {
+        let prefix = dir_prefix(prefix.into());
+        let query = [
+            (QueryKey::Prefix, prefix.clone().into()),
+            (QueryKey::Delimiter, DIR_DELIMITER.into()),
+        ];
+
+        let mut list = self.get_object_list(query)?;
+        let mut files = Vec::new();
+        let mut dirs = Vec::new();
+        loop {
+            files.extend(
+                list.object_list
+                    .drain(..)
+                    .filter(|o| o.path_string() != prefix),
+            );
+            dirs.append(&mut list.common_prefixes);
+
+            match list.next_query() {
+                Some(query) => {
+                    list.set_search_query(query);
+                    list = list.get_object_list()?;
+                }
+                None => break,
+            }
+        }
+
+        Ok((files, dirs))
+    }
+
     /// 可将 object 列表导出到外部 struct
     #[inline]
     pub fn base_object_list<
@@ -890,6 +1192,61 @@ impl PartialEq<ObjectBase<ArcPointer>> for Object<ArcPointer> {
     }
 }
 
+/// # Object 的类型
+/// 对应列表接口中的 `Type` 字段
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ObjectType {
+    /// 通过简单上传生成的 object
+    Normal,
+    /// 通过追加上传生成的 object
+    Appendable,
+    /// 通过分片上传生成的 object
+    Multipart,
+    /// 软链接
+    Symlink,
+}
+
+const TYPE_NORMAL: &str = "Normal";
+const TYPE_APPENDABLE: &str = "Appendable";
+const TYPE_MULTIPART: &str = "Multipart";
+const TYPE_SYMLINK: &str = "Symlink";
+
+impl AsRef<str> for ObjectType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Normal => TYPE_NORMAL,
+            Self::Appendable => TYPE_APPENDABLE,
+            Self::Multipart => TYPE_MULTIPART,
+            Self::Symlink => TYPE_SYMLINK,
+        }
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for ObjectType {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::ObjectType;
+    /// assert_eq!("Symlink".parse::<ObjectType>().unwrap(), ObjectType::Symlink);
+    /// assert!("foo".parse::<ObjectType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            TYPE_NORMAL => Ok(Self::Normal),
+            TYPE_APPENDABLE => Ok(Self::Appendable),
+            TYPE_MULTIPART => Ok(Self::Multipart),
+            TYPE_SYMLINK => Ok(Self::Symlink),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
 /// 未来计划支持的功能
 #[derive(Default)]
 pub struct PutObject<'a> {
@@ -902,8 +1259,8 @@ pub struct PutObject<'a> {
     pub tagging: Option<&'a str>,
 }
 
-/// 未来计划支持的功能
-#[derive(Default)]
+/// # 服务端加密算法
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub enum Encryption {
     #[default]
     Aes256,
@@ -911,118 +1268,1129 @@ pub enum Encryption {
     Sm4,
 }
 
-/// 未来计划支持的功能
-#[derive(Default)]
-pub enum ObjectAcl {
-    #[default]
-    Default,
-    Private,
-    PublicRead,
-    PublicReadWrite,
-}
+const ENCRYPTION_AES256: &str = "AES256";
+const ENCRYPTION_KMS: &str = "KMS";
+const ENCRYPTION_SM4: &str = "SM4";
 
-/// 未来计划支持的功能
-#[derive(Default)]
-pub enum StorageClass {
-    #[default]
-    Standard,
-    IA,
-    Archive,
-    ColdArchive,
+impl AsRef<str> for Encryption {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Aes256 => ENCRYPTION_AES256,
+            Self::Kms => ENCRYPTION_KMS,
+            Self::Sm4 => ENCRYPTION_SM4,
+        }
+    }
 }
 
-/// 未来计划支持的功能
-#[derive(Default)]
-pub struct CopyObject<'a> {
-    pub forbid_overwrite: bool,
-    pub copy_source: &'a str,
-    pub copy_source_if_match: Option<&'a str>,
-    pub copy_source_if_none_match: Option<&'a str>,
-    pub copy_source_if_unmodified_since: Option<&'a str>,
-    pub copy_source_if_modified_since: Option<&'a str>,
-    pub metadata_directive: CopyDirective,
-    pub server_side_encryption: Option<Encryption>,
-    pub server_side_encryption_key_id: Option<&'a str>,
-    pub object_acl: ObjectAcl,
-    pub storage_class: StorageClass,
-    pub tagging: Option<&'a str>,
-    pub tagging_directive: CopyDirective,
+impl fmt::Display for Encryption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
 }
 
-/// 未来计划支持的功能
-#[derive(Default)]
-pub enum CopyDirective {
-    #[default]
-    Copy,
-    Replace,
+impl FromStr for Encryption {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::Encryption;
+    /// assert_eq!("KMS".parse::<Encryption>().unwrap(), Encryption::Kms);
+    /// assert!("foo".parse::<Encryption>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ENCRYPTION_AES256 => Ok(Self::Aes256),
+            ENCRYPTION_KMS => Ok(Self::Kms),
+            ENCRYPTION_SM4 => Ok(Self::Sm4),
+            _ => Err(InvalidObjectValue),
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::ObjectList;
-    use crate::{
-        builder::ArcPointer,
-        config::BucketBase,
-        object::{Object, ObjectBuilder},
-        types::QueryValue,
-        Client,
-    };
-    use chrono::{DateTime, NaiveDateTime, Utc};
-    use std::sync::Arc;
+const SERVER_SIDE_ENCRYPTION: &str = "x-oss-server-side-encryption";
+const SERVER_SIDE_DATA_ENCRYPTION: &str = "x-oss-server-side-data-encryption";
+const SERVER_SIDE_ENCRYPTION_KEY_ID: &str = "x-oss-server-side-encryption-key-id";
+const SSE_ALGORITHM: &[u8] = b"SSEAlgorithm";
+const KMS_DATA_ENCRYPTION: &[u8] = b"KMSDataEncryption";
+const KMS_MASTER_KEY_ID: &[u8] = b"KMSMasterKeyID";
+
+/// # 服务端加密设置
+/// 用于上传、复制文件，以及 bucket 的默认加密规则
+///
+/// `data_encryption`（只能为 SM4）和 `key_id` 仅在使用 KMS 加密时有效
+///
+/// ```
+/// # use aliyun_oss_client::object::{Encryption, ServerSideEncryption};
+/// let sse = ServerSideEncryption::new(Encryption::Kms)
+///     .data_encryption(Encryption::Sm4)
+///     .key_id("9468da86-3509-4f8d-a61e-6eab1eac****");
+/// assert_eq!(sse.key_id.as_deref(), Some("9468da86-3509-4f8d-a61e-6eab1eac****"));
+/// ```
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct ServerSideEncryption {
+    pub encryption: Encryption,
+    pub data_encryption: Option<Encryption>,
+    pub key_id: Option<String>,
+}
 
-    fn init_object_list(token: Option<String>, list: Vec<Object>) -> ObjectList {
-        let client = Client::new(
-            "foo1".into(),
-            "foo2".into(),
-            "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
-            "foo4".parse().unwrap(),
-        );
+impl ServerSideEncryption {
+    pub fn new(encryption: Encryption) -> Self {
+        Self {
+            encryption,
+            ..Self::default()
+        }
+    }
 
-        let mut object_list = ObjectList::<ArcPointer>::new(
-            "abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
-            String::from("foo2"),
-            100,
-            200,
-            list,
-            token,
-            Arc::new(client),
-            vec![("key1".into(), "value1".into())],
-        );
+    /// 设置 KMS 加密时数据的加密算法
+    pub fn data_encryption(mut self, data_encryption: Encryption) -> Self {
+        self.data_encryption = Some(data_encryption);
+        self
+    }
 
-        object_list.test_set_prefix("foo2/next");
+    /// 设置 KMS 加密时使用的用户主密钥
+    pub fn key_id<K: Into<String>>(mut self, key_id: K) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
 
-        object_list
+    fn validate(&self) -> OssResult<()> {
+        if self.encryption != Encryption::Kms
+            && (self.data_encryption.is_some() || self.key_id.is_some())
+        {
+            return Err(OssError::Input(
+                "data_encryption and key_id only work with KMS".to_string(),
+            ));
+        }
+        if matches!(&self.data_encryption, Some(data) if *data != Encryption::Sm4) {
+            return Err(OssError::Input(
+                "data_encryption only support SM4".to_string(),
+            ));
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_get_bucket() {
-        let object_list = init_object_list(Some(String::from("foo3")), vec![]);
+    /// 转化为上传、复制请求的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        self.validate()?;
 
-        let bucket = object_list.bucket();
+        let mut headers = vec![(
+            HeaderName::from_static(SERVER_SIDE_ENCRYPTION),
+            self.encryption.as_ref().parse().map_err(OssError::from)?,
+        )];
+        if let Some(data) = &self.data_encryption {
+            headers.push((
+                HeaderName::from_static(SERVER_SIDE_DATA_ENCRYPTION),
+                data.as_ref().parse().map_err(OssError::from)?,
+            ));
+        }
+        if let Some(key_id) = &self.key_id {
+            headers.push((
+                HeaderName::from_static(SERVER_SIDE_ENCRYPTION_KEY_ID),
+                key_id.parse().map_err(OssError::from)?,
+            ));
+        }
+        Ok(headers)
+    }
 
-        assert_eq!(bucket.name(), "abc");
+    /// # 从 HEAD、GET 等接口返回的 header 中读取加密信息
+    /// 文件未加密时返回 `None`
+    pub fn from_headers(headers: &HeaderMap) -> OssResult<Option<Self>> {
+        let get = |name: &str| -> OssResult<Option<&str>> {
+            headers
+                .get(name)
+                .map(|value| value.to_str().map_err(OssError::from))
+                .transpose()
+        };
 
-        assert_eq!(object_list.prefix(), "foo2,foo2/next");
+        let encryption = match get(SERVER_SIDE_ENCRYPTION)? {
+            Some(value) => value.parse()?,
+            None => return Ok(None),
+        };
+        let data_encryption = get(SERVER_SIDE_DATA_ENCRYPTION)?
+            .map(str::parse)
+            .transpose()?;
+        let key_id = get(SERVER_SIDE_ENCRYPTION_KEY_ID)?.map(ToOwned::to_owned);
 
-        assert!(object_list.max_keys() == &100u32);
-        assert_eq!(object_list.max_keys().to_owned(), 100u32);
+        Ok(Some(Self {
+            encryption,
+            data_encryption,
+            key_id,
+        }))
+    }
 
-        match &object_list.next_continuation_token() {
-            Some(a) => {
-                assert!(a == "foo3");
-                assert_eq!(a, "foo3");
-            }
-            None => {
-                panic!("token is valid value");
+    /// 转化为 bucket 默认加密规则的 xml
+    pub(crate) fn to_xml(&self) -> OssResult<String> {
+        self.validate()?;
+
+        let mut xml = format!(
+            "<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>{}</SSEAlgorithm>",
+            self.encryption
+        );
+        if let Some(data) = &self.data_encryption {
+            xml.push_str(&format!("<KMSDataEncryption>{}</KMSDataEncryption>", data));
+        }
+        if let Some(key_id) = &self.key_id {
+            xml.push_str(&format!("<KMSMasterKeyID>{}</KMSMasterKeyID>", key_id));
+        }
+        xml.push_str("</ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>");
+        Ok(xml)
+    }
+
+    /// 解析 GetBucketEncryption 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut encryption = None;
+        let mut data_encryption = None;
+        let mut key_id = None;
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    SSE_ALGORITHM => {
+                        encryption = Some(reader.read_text(e.to_end().name())?.parse()?);
+                    }
+                    KMS_DATA_ENCRYPTION => {
+                        let text = reader.read_text(e.to_end().name())?;
+                        if !text.is_empty() {
+                            data_encryption = Some(text.parse()?);
+                        }
+                    }
+                    KMS_MASTER_KEY_ID => {
+                        let text = reader.read_text(e.to_end().name())?;
+                        if !text.is_empty() {
+                            key_id = Some(text.into_owned());
+                        }
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
             }
+            buf.clear();
         }
-    }
 
-    #[test]
-    fn test_next_query() {
-        let object_list = init_object_list(Some(String::from("foo3")), vec![]);
+        Ok(Self {
+            encryption: encryption.ok_or(InvalidObjectValue)?,
+            data_encryption,
+            key_id,
+        })
+    }
+}
 
-        let query = object_list.next_query();
+/// # Object 的访问权限（ACL）
+/// `Default` 表示继承所在 bucket 的访问权限
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum ObjectAcl {
+    #[default]
+    Default,
+    Private,
+    PublicRead,
+    PublicReadWrite,
+}
+
+const ACL_DEFAULT: &str = "default";
+pub(crate) const ACL_PRIVATE: &str = "private";
+pub(crate) const ACL_PUBLIC_READ: &str = "public-read";
+pub(crate) const ACL_PUBLIC_READ_WRITE: &str = "public-read-write";
+const GRANT: &[u8] = b"Grant";
+
+impl AsRef<str> for ObjectAcl {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Default => ACL_DEFAULT,
+            Self::Private => ACL_PRIVATE,
+            Self::PublicRead => ACL_PUBLIC_READ,
+            Self::PublicReadWrite => ACL_PUBLIC_READ_WRITE,
+        }
+    }
+}
+
+impl fmt::Display for ObjectAcl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for ObjectAcl {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::ObjectAcl;
+    /// assert_eq!("public-read".parse::<ObjectAcl>().unwrap(), ObjectAcl::PublicRead);
+    /// assert!("foo".parse::<ObjectAcl>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ACL_DEFAULT => Ok(Self::Default),
+            ACL_PRIVATE => Ok(Self::Private),
+            ACL_PUBLIC_READ => Ok(Self::PublicRead),
+            ACL_PUBLIC_READ_WRITE => Ok(Self::PublicReadWrite),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
+impl ObjectAcl {
+    /// 解析 GetObjectACL 接口返回的 AccessControlPolicy xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() == GRANT => {
+                    return Ok(reader.read_text(e.to_end().name())?.parse()?);
+                }
+                Event::Eof => return Err(InvalidObjectValue.into()),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidObjectValue;
+
+impl fmt::Display for InvalidObjectValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "faild parse to object value")
+    }
+}
+
+impl Error for InvalidObjectValue {}
+
+/// 自定义元信息的 header 前缀
+pub const USER_META_PREFIX: &str = "x-oss-meta-";
+
+/// 自定义元信息的总大小上限，包括 header 名（含前缀）及值
+pub const USER_META_MAX_SIZE: usize = 8 * 1024;
+
+/// # Object 的自定义元信息
+/// 对应 `x-oss-meta-*` header，key 不区分大小写，统一转为小写保存
+///
+/// ```
+/// # use aliyun_oss_client::object::UserMeta;
+/// let mut meta = UserMeta::new();
+/// meta.insert("X-Oss-Meta-Author", "foo").unwrap();
+/// meta.insert("Version", "1").unwrap();
+///
+/// assert_eq!(meta.get("author"), Some("foo"));
+/// assert_eq!(meta.get("x-oss-meta-version"), Some("1"));
+/// assert!(meta.insert("my key", "bar").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserMeta {
+    inner: BTreeMap<String, String>,
+}
+
+impl UserMeta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 去掉前缀并转为小写
+    fn normalize_key(key: &str) -> String {
+        let key = key.trim().to_ascii_lowercase();
+        match key.strip_prefix(USER_META_PREFIX) {
+            Some(key) => key.to_owned(),
+            None => key,
+        }
+    }
+
+    fn entry_size(key: &str, value: &str) -> usize {
+        USER_META_PREFIX.len() + key.len() + value.len()
+    }
+
+    /// 插入一项元信息，key 只能包含字母、数字、`-` 和 `_`，value 只能包含可见 ASCII 字符
+    ///
+    /// 插入后总大小超出 [`USER_META_MAX_SIZE`] 时返回 Err
+    pub fn insert<K: AsRef<str>, V: Into<String>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<Option<String>, InvalidUserMeta> {
+        let key = Self::normalize_key(key.as_ref());
+        let value = value.into();
+
+        if key.is_empty()
+            || !key
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            return Err(InvalidUserMeta::Key(key));
+        }
+        if !value
+            .bytes()
+            .all(|b| b == b'\t' || (b' '..=b'~').contains(&b))
+        {
+            return Err(InvalidUserMeta::Value(key));
+        }
+
+        let old_size = self
+            .inner
+            .get(&key)
+            .map(|old| Self::entry_size(&key, old))
+            .unwrap_or(0);
+        let size = self.size() - old_size + Self::entry_size(&key, &value);
+        if size > USER_META_MAX_SIZE {
+            return Err(InvalidUserMeta::TooLarge(size));
+        }
+
+        Ok(self.inner.insert(key, value))
+    }
+
+    pub fn get<K: AsRef<str>>(&self, key: K) -> Option<&str> {
+        self.inner
+            .get(&Self::normalize_key(key.as_ref()))
+            .map(String::as_str)
+    }
+
+    pub fn remove<K: AsRef<str>>(&mut self, key: K) -> Option<String> {
+        self.inner.remove(&Self::normalize_key(key.as_ref()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// 遍历元信息，key 不含前缀
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.inner.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// 当前的总大小
+    pub fn size(&self) -> usize {
+        self.inner.iter().map(|(k, v)| Self::entry_size(k, v)).sum()
+    }
+
+    /// 从响应的 header 中提取自定义元信息
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let inner = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let key = name.as_str().strip_prefix(USER_META_PREFIX)?;
+                Some((key.to_owned(), value.to_str().ok()?.to_owned()))
+            })
+            .collect();
+
+        Self { inner }
+    }
+
+    /// 转化为请求的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        self.inner
+            .iter()
+            .map(|(k, v)| {
+                let name = HeaderName::from_bytes(format!("{}{}", USER_META_PREFIX, k).as_bytes())
+                    .map_err(|_| InvalidUserMeta::Key(k.to_owned()))?;
+                Ok((name, HeaderValue::from_str(v)?))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidUserMeta {
+    Key(String),
+    Value(String),
+    TooLarge(usize),
+}
+
+impl fmt::Display for InvalidUserMeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, "invalid user meta key: {}", key),
+            Self::Value(key) => write!(f, "invalid user meta value, key: {}", key),
+            Self::TooLarge(size) => write!(
+                f,
+                "user meta size {} exceeds the limit of {} bytes",
+                size, USER_META_MAX_SIZE
+            ),
+        }
+    }
+}
+
+impl Error for InvalidUserMeta {}
+
+const FORBID_OVERWRITE: &str = "x-oss-forbid-overwrite";
+pub(crate) const METADATA_DIRECTIVE: &str = "x-oss-metadata-directive";
+const COPY_SOURCE_IF_MATCH: &str = "x-oss-copy-source-if-match";
+const COPY_SOURCE_IF_NONE_MATCH: &str = "x-oss-copy-source-if-none-match";
+const COPY_SOURCE_IF_MODIFIED_SINCE: &str = "x-oss-copy-source-if-modified-since";
+const COPY_SOURCE_IF_UNMODIFIED_SINCE: &str = "x-oss-copy-source-if-unmodified-since";
+
+/// # 条件请求的参数
+/// 用于 get、head 时对应 `If-Match` 等 header，用于 copy 时对应源文件的 `x-oss-copy-source-if-match` 等 header，
+/// 用于 put、copy 时可设置禁止覆盖同名文件
+///
+/// 还可通过 [`Conditions::request_payer`] 为单次请求声明由请求者付费
+///
+/// 条件不满足时返回 [`OssError::NotModified`] 或 [`OssError::PreconditionFailed`]
+///
+/// ```
+/// # use aliyun_oss_client::object::Conditions;
+/// let conditions = Conditions::new()
+///     .if_none_match("5B3C1A2E053D763E1B002CC607C5A0FE")
+///     .forbid_overwrite(true);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Conditions {
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<DateTime<Utc>>,
+    if_unmodified_since: Option<DateTime<Utc>>,
+    forbid_overwrite: bool,
+    pub(crate) version_id: Option<String>,
+    request_payer: bool,
+}
+
+impl Conditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// etag 不带引号时会自动加上，`*` 及弱校验的 `W/"..."` 保持原样
+    fn quote_etag(etag: String) -> String {
+        if etag == "*" || etag.starts_with('"') || etag.starts_with("W/") {
+            etag
+        } else {
+            format!("\"{}\"", etag)
+        }
+    }
+
+    pub fn if_match<E: Into<String>>(mut self, etag: E) -> Self {
+        self.if_match = Some(Self::quote_etag(etag.into()));
+        self
+    }
+
+    pub fn if_none_match<E: Into<String>>(mut self, etag: E) -> Self {
+        self.if_none_match = Some(Self::quote_etag(etag.into()));
+        self
+    }
+
+    pub fn if_modified_since(mut self, date: DateTime<Utc>) -> Self {
+        self.if_modified_since = Some(date);
+        self
+    }
+
+    pub fn if_unmodified_since(mut self, date: DateTime<Utc>) -> Self {
+        self.if_unmodified_since = Some(date);
+        self
+    }
+
+    /// 存在同名文件时，不进行覆盖，仅 put、copy 时有效
+    pub fn forbid_overwrite(mut self, forbid: bool) -> Self {
+        self.forbid_overwrite = forbid;
+        self
+    }
+
+    /// 操作文件的指定版本，用于开启了版本控制的 bucket，复制时请使用 [`CopyOptions::source_version_id`]
+    pub fn version_id<V: Into<String>>(mut self, version_id: V) -> Self {
+        self.version_id = Some(version_id.into());
+        self
+    }
+
+    /// 以请求者身份为本次请求付费，只作用于使用该条件的请求，
+    /// 对 client 的所有请求生效请使用 [`Client::request_payer`]
+    ///
+    /// [`Client::request_payer`]: crate::client::Client::request_payer
+    pub fn request_payer(mut self, requester: bool) -> Self {
+        self.request_payer = requester;
+        self
+    }
+
+    /// 转化为只包含请求者付费的 header，用于不支持条件的请求
+    pub(crate) fn to_payer_headers(&self) -> Vec<(HeaderName, HeaderValue)> {
+        if self.request_payer {
+            vec![(
+                HeaderName::from_static(OSS_REQUEST_PAYER),
+                HeaderValue::from_static(PAYER_REQUESTER),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn build_headers(&self, names: [HeaderName; 4]) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let [if_match, if_none_match, if_modified_since, if_unmodified_since] = names;
+        let mut headers = Vec::new();
+
+        if let Some(etag) = &self.if_match {
+            headers.push((if_match, etag.parse()?));
+        }
+        if let Some(etag) = &self.if_none_match {
+            headers.push((if_none_match, etag.parse()?));
+        }
+        if let Some(date) = self.if_modified_since {
+            headers.push((if_modified_since, Date::from(date).try_into()?));
+        }
+        if let Some(date) = self.if_unmodified_since {
+            headers.push((if_unmodified_since, Date::from(date).try_into()?));
+        }
+        headers.extend(self.to_payer_headers());
+
+        Ok(headers)
+    }
+
+    fn push_forbid_overwrite(&self, headers: &mut Vec<(HeaderName, HeaderValue)>) {
+        if self.forbid_overwrite {
+            headers.push((
+                HeaderName::from_static(FORBID_OVERWRITE),
+                HeaderValue::from_static("true"),
+            ));
+        }
+    }
+
+    /// 转化为 get、head 请求的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        self.build_headers([
+            IF_MATCH,
+            IF_NONE_MATCH,
+            IF_MODIFIED_SINCE,
+            IF_UNMODIFIED_SINCE,
+        ])
+    }
+
+    /// 转化为 put 请求的 header，包括禁止覆盖
+    pub(crate) fn to_put_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let mut headers = self.to_headers()?;
+        self.push_forbid_overwrite(&mut headers);
+        Ok(headers)
+    }
+
+    /// 转化为 copy 请求的 header，条件作用于源文件，禁止覆盖作用于目标文件
+    pub(crate) fn to_copy_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let mut headers = self.build_headers([
+            HeaderName::from_static(COPY_SOURCE_IF_MATCH),
+            HeaderName::from_static(COPY_SOURCE_IF_NONE_MATCH),
+            HeaderName::from_static(COPY_SOURCE_IF_MODIFIED_SINCE),
+            HeaderName::from_static(COPY_SOURCE_IF_UNMODIFIED_SINCE),
+        ])?;
+        self.push_forbid_overwrite(&mut headers);
+        Ok(headers)
+    }
+}
+
+/// # 上传文件时的可选参数
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PutOptions {
+    pub meta: UserMeta,
+    pub conditions: Conditions,
+    pub encryption: Option<ServerSideEncryption>,
+}
+
+impl PutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn meta(mut self, meta: UserMeta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn conditions(mut self, conditions: Conditions) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    pub fn encryption(mut self, encryption: ServerSideEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// 转化为上传请求的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let mut headers = self.meta.to_headers()?;
+        headers.extend(self.conditions.to_put_headers()?);
+        if let Some(encryption) = &self.encryption {
+            headers.extend(encryption.to_headers()?);
+        }
+        Ok(headers)
+    }
+}
+
+/// # 复制文件时的可选参数
+/// 条件作用于源文件，自定义元信息、加密设置作用于目标文件
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopyOptions {
+    /// 为 `None` 时沿用源文件的元信息
+    pub meta: Option<UserMeta>,
+    pub conditions: Conditions,
+    pub encryption: Option<ServerSideEncryption>,
+    /// 复制源文件的指定版本
+    pub source_version_id: Option<String>,
+}
+
+impl CopyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 使用新的自定义元信息，源文件的自定义元信息及 Content-Type 等标准 header 都不再沿用
+    pub fn meta(mut self, meta: UserMeta) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: Conditions) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    pub fn encryption(mut self, encryption: ServerSideEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// 复制源文件的指定版本，用于开启了版本控制的 bucket
+    pub fn source_version_id<V: Into<String>>(mut self, version_id: V) -> Self {
+        self.source_version_id = Some(version_id.into());
+        self
+    }
+
+    /// 转化为复制请求的 header
+    pub(crate) fn to_headers(&self) -> OssResult<Vec<(HeaderName, HeaderValue)>> {
+        let mut headers = self.conditions.to_copy_headers()?;
+        if let Some(meta) = &self.meta {
+            headers.push((
+                HeaderName::from_static(METADATA_DIRECTIVE),
+                CopyDirective::Replace
+                    .as_ref()
+                    .parse()
+                    .map_err(OssError::from)?,
+            ));
+            headers.extend(meta.to_headers()?);
+        }
+        if let Some(encryption) = &self.encryption {
+            headers.extend(encryption.to_headers()?);
+        }
+        Ok(headers)
+    }
+}
+
+/// # 存储类型
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum StorageClass {
+    #[default]
+    Standard,
+    IA,
+    Archive,
+    ColdArchive,
+}
+
+const STORAGE_STANDARD: &str = "Standard";
+const STORAGE_IA: &str = "IA";
+const STORAGE_ARCHIVE: &str = "Archive";
+const STORAGE_COLD_ARCHIVE: &str = "ColdArchive";
+
+impl AsRef<str> for StorageClass {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Standard => STORAGE_STANDARD,
+            Self::IA => STORAGE_IA,
+            Self::Archive => STORAGE_ARCHIVE,
+            Self::ColdArchive => STORAGE_COLD_ARCHIVE,
+        }
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for StorageClass {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::StorageClass;
+    /// assert_eq!("IA".parse::<StorageClass>().unwrap(), StorageClass::IA);
+    /// assert!("foo".parse::<StorageClass>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            STORAGE_STANDARD => Ok(Self::Standard),
+            STORAGE_IA => Ok(Self::IA),
+            STORAGE_ARCHIVE => Ok(Self::Archive),
+            STORAGE_COLD_ARCHIVE => Ok(Self::ColdArchive),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
+/// # 解冻归档、冷归档类型 object 时的优先级
+/// 仅冷归档类型的 object 支持设置，归档类型的 object 会忽略此项
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum RestoreTier {
+    /// 高优先级，1 小时内完成解冻
+    Expedited,
+    /// 标准，2~5 小时内完成解冻
+    #[default]
+    Standard,
+    /// 批量，5~12 小时内完成解冻
+    Bulk,
+}
+
+const TIER_EXPEDITED: &str = "Expedited";
+const TIER_STANDARD: &str = "Standard";
+const TIER_BULK: &str = "Bulk";
+
+impl AsRef<str> for RestoreTier {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Expedited => TIER_EXPEDITED,
+            Self::Standard => TIER_STANDARD,
+            Self::Bulk => TIER_BULK,
+        }
+    }
+}
+
+impl fmt::Display for RestoreTier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for RestoreTier {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::RestoreTier;
+    /// assert_eq!("Bulk".parse::<RestoreTier>().unwrap(), RestoreTier::Bulk);
+    /// assert!("foo".parse::<RestoreTier>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            TIER_EXPEDITED => Ok(Self::Expedited),
+            TIER_STANDARD => Ok(Self::Standard),
+            TIER_BULK => Ok(Self::Bulk),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
+/// # 解冻 object 的请求参数
+/// `days` 为解冻后的可读时长（天），`tier` 为解冻优先级
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreRequest {
+    pub days: u32,
+    pub tier: Option<RestoreTier>,
+}
+
+impl Default for RestoreRequest {
+    fn default() -> Self {
+        Self {
+            days: 1,
+            tier: None,
+        }
+    }
+}
+
+impl RestoreRequest {
+    pub fn new(days: u32) -> Self {
+        Self { days, tier: None }
+    }
+
+    pub fn tier(mut self, tier: RestoreTier) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// 转化为 RestoreObject 接口的请求体
+    /// ```
+    /// # use aliyun_oss_client::object::{RestoreRequest, RestoreTier};
+    /// assert_eq!(
+    ///     RestoreRequest::new(2).tier(RestoreTier::Bulk).to_xml(),
+    ///     "<RestoreRequest><Days>2</Days><JobParameters><Tier>Bulk</Tier></JobParameters></RestoreRequest>"
+    /// );
+    /// assert_eq!(
+    ///     RestoreRequest::new(2).to_xml(),
+    ///     "<RestoreRequest><Days>2</Days></RestoreRequest>"
+    /// );
+    /// ```
+    pub fn to_xml(&self) -> String {
+        let tier = match &self.tier {
+            Some(tier) => format!("<JobParameters><Tier>{}</Tier></JobParameters>", tier),
+            None => String::new(),
+        };
+        format!(
+            "<RestoreRequest><Days>{}</Days>{}</RestoreRequest>",
+            self.days, tier
+        )
+    }
+}
+
+/// # 归档类型 object 的解冻状态
+/// 对应 `x-oss-restore` 响应头，以及列表接口中的 `RestoreInfo` 字段
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreInfo {
+    ongoing_request: bool,
+    expiry_date: Option<DateTime<Utc>>,
+}
+
+const ONGOING_REQUEST: &str = "ongoing-request";
+const EXPIRY_DATE: &str = "expiry-date";
+
+impl RestoreInfo {
+    /// 是否正在解冻中
+    pub fn ongoing_request(&self) -> bool {
+        self.ongoing_request
+    }
+
+    /// 解冻状态的过期时间，解冻完成后才有值
+    pub fn expiry_date(&self) -> Option<&DateTime<Utc>> {
+        self.expiry_date.as_ref()
+    }
+
+    /// 是否已完成解冻，可以读取
+    pub fn is_restored(&self) -> bool {
+        !self.ongoing_request && self.expiry_date.is_some()
+    }
+}
+
+impl FromStr for RestoreInfo {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::object::RestoreInfo;
+    /// let info: RestoreInfo = r#"ongoing-request="true""#.parse().unwrap();
+    /// assert!(info.ongoing_request());
+    ///
+    /// let info: RestoreInfo = r#"ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT""#
+    ///     .parse()
+    ///     .unwrap();
+    /// assert!(info.is_restored());
+    /// assert!("foo".parse::<RestoreInfo>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ongoing_request = None;
+        let mut expiry_date = None;
+
+        // 日期中含有逗号，所以需按 `key="value"` 的格式逐项解析
+        let mut rest = s.trim();
+        while !rest.is_empty() {
+            let (key, value) = rest.split_once("=\"").ok_or(InvalidObjectValue)?;
+            let (value, next) = value.split_once('"').ok_or(InvalidObjectValue)?;
+            rest = next.trim_start_matches(',').trim_start();
+
+            match key.trim() {
+                ONGOING_REQUEST => ongoing_request = Some(value == "true"),
+                EXPIRY_DATE => {
+                    let date =
+                        DateTime::parse_from_rfc2822(value).map_err(|_| InvalidObjectValue)?;
+                    expiry_date = Some(date.with_timezone(&Utc));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            ongoing_request: ongoing_request.ok_or(InvalidObjectValue)?,
+            expiry_date,
+        })
+    }
+}
+
+/// 未来计划支持的功能
+#[derive(Default)]
+pub struct CopyObject<'a> {
+    pub forbid_overwrite: bool,
+    pub copy_source: &'a str,
+    pub copy_source_if_match: Option<&'a str>,
+    pub copy_source_if_none_match: Option<&'a str>,
+    pub copy_source_if_unmodified_since: Option<&'a str>,
+    pub copy_source_if_modified_since: Option<&'a str>,
+    pub metadata_directive: CopyDirective,
+    pub server_side_encryption: Option<Encryption>,
+    pub server_side_encryption_key_id: Option<&'a str>,
+    pub object_acl: ObjectAcl,
+    pub storage_class: StorageClass,
+    pub tagging: Option<&'a str>,
+    pub tagging_directive: CopyDirective,
+}
+
+/// # 复制文件时元信息、标签的处理方式
+/// `Copy` 表示沿用源文件的，`Replace` 表示使用请求中指定的
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum CopyDirective {
+    #[default]
+    Copy,
+    Replace,
+}
+
+const DIRECTIVE_COPY: &str = "COPY";
+const DIRECTIVE_REPLACE: &str = "REPLACE";
+
+impl AsRef<str> for CopyDirective {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Copy => DIRECTIVE_COPY,
+            Self::Replace => DIRECTIVE_REPLACE,
+        }
+    }
+}
+
+impl fmt::Display for CopyDirective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+const UPLOAD_ID: &[u8] = b"UploadId";
+
+/// # 分片上传中已上传的分片
+/// 由 [`upload_part`] 返回，全部上传后用于 [`complete_multipart_upload`]
+///
+/// [`upload_part`]: crate::file::File::upload_part
+/// [`complete_multipart_upload`]: crate::file::File::complete_multipart_upload
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadedPart {
+    pub part_number: u32,
+    pub etag: String,
+    /// 分片内容的 CRC64 校验值，开启 CRC64 校验时由 [`upload_part`] 填充
+    ///
+    /// [`upload_part`]: crate::file::File::upload_part
+    pub crc64: Option<u64>,
+    /// 分片内容的长度，用于合并各分片的校验值
+    pub size: u64,
+}
+
+impl UploadedPart {
+    pub fn new<E: Into<String>>(part_number: u32, etag: E) -> Self {
+        Self {
+            part_number,
+            etag: etag.into(),
+            crc64: None,
+            size: 0,
+        }
+    }
+
+    /// 设置分片内容的 CRC64 校验值和长度
+    pub fn crc64(mut self, crc64: u64, size: u64) -> Self {
+        self.crc64 = Some(crc64);
+        self.size = size;
+        self
+    }
+}
+
+/// 按分片编号合并各分片的 CRC64 校验值，得到整个文件的校验值，
+/// 任一分片缺少校验值时返回 `None`
+pub(crate) fn multipart_crc64(parts: &[UploadedPart]) -> Option<u64> {
+    let mut parts: Vec<_> = parts.iter().collect();
+    parts.sort_by_key(|part| part.part_number);
+
+    parts.into_iter().try_fold(0, |crc, part| {
+        Some(Crc64::combine(crc, part.crc64?, part.size))
+    })
+}
+
+/// 生成 CompleteMultipartUpload 接口的请求体，分片按编号排序
+pub(crate) fn complete_multipart_xml(parts: &[UploadedPart]) -> String {
+    let mut parts: Vec<_> = parts.iter().collect();
+    parts.sort_by_key(|part| part.part_number);
+
+    let parts: String = parts
+        .into_iter()
+        .map(|part| {
+            format!(
+                "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                part.part_number, part.etag
+            )
+        })
+        .collect();
+    format!("<CompleteMultipartUpload>{parts}</CompleteMultipartUpload>")
+}
+
+/// 解析 InitiateMultipartUpload 接口返回的 upload id
+pub(crate) fn decode_upload_id(xml: &str) -> OssResult<String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::with_capacity(xml.len());
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) if e.name().as_ref() == UPLOAD_ID => {
+                return Ok(reader.read_text(e.to_end().name())?.into_owned());
+            }
+            Event::Eof => return Err(InvalidObjectValue.into()),
+            _ => (),
+        }
+        buf.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectList;
+    use crate::{
+        builder::ArcPointer,
+        config::BucketBase,
+        object::{Object, ObjectBuilder},
+        types::QueryValue,
+        Client,
+    };
+    use chrono::{DateTime, NaiveDateTime, Utc};
+    use std::sync::Arc;
+
+    fn init_object_list(token: Option<String>, list: Vec<Object>) -> ObjectList {
+        let client = Client::new(
+            "foo1".into(),
+            "foo2".into(),
+            "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+            "foo4".parse().unwrap(),
+        );
+
+        let mut object_list = ObjectList::<ArcPointer>::new(
+            "abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+            String::from("foo2"),
+            100,
+            200,
+            list,
+            token,
+            Arc::new(client),
+            vec![("key1".into(), "value1".into())],
+        );
+
+        object_list.test_set_prefix("foo2/next");
+
+        object_list
+    }
+
+    #[test]
+    fn test_get_bucket() {
+        let object_list = init_object_list(Some(String::from("foo3")), vec![]);
+
+        let bucket = object_list.bucket();
+
+        assert_eq!(bucket.name(), "abc");
+
+        assert_eq!(object_list.prefix(), "foo2,foo2/next");
+
+        assert!(object_list.max_keys() == &100u32);
+        assert_eq!(object_list.max_keys().to_owned(), 100u32);
+
+        match &object_list.next_continuation_token() {
+            Some(a) => {
+                assert!(a == "foo3");
+                assert_eq!(a, "foo3");
+            }
+            None => {
+                panic!("token is valid value");
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_query() {
+        let object_list = init_object_list(Some(String::from("foo3")), vec![]);
+
+        let query = object_list.next_query();
 
         assert!(query.is_some());
         let inner_query = query.unwrap();
@@ -1125,6 +2493,320 @@ mod tests {
         assert_eq!(object.size, 123);
         assert_eq!(object.storage_class, "foo3");
     }
+
+    #[test]
+    fn test_object_type() {
+        use super::ObjectType;
+
+        let bucket = Arc::new("abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap());
+        let mut object = ObjectBuilder::<ArcPointer>::new(bucket, "latest")
+            .set_type("Symlink".to_owned())
+            .build();
+
+        assert_eq!(object.object_type().unwrap(), ObjectType::Symlink);
+        assert!(object.is_symlink());
+
+        object.set_type("Normal".to_owned());
+        assert!(!object.is_symlink());
+
+        object.set_type("foo".to_owned());
+        assert!(object.object_type().is_err());
+    }
+
+    #[test]
+    fn test_server_side_encryption() {
+        use super::{Encryption, ServerSideEncryption};
+        use http::HeaderMap;
+
+        let sse = ServerSideEncryption::new(Encryption::Kms)
+            .data_encryption(Encryption::Sm4)
+            .key_id("foo_key");
+        let headers = sse.to_headers().unwrap();
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers[0].1, "KMS");
+        assert_eq!(headers[1].1, "SM4");
+        assert_eq!(headers[2].1, "foo_key");
+
+        let map = HeaderMap::from_iter(headers);
+        assert_eq!(
+            ServerSideEncryption::from_headers(&map).unwrap(),
+            Some(sse.clone())
+        );
+        assert_eq!(
+            ServerSideEncryption::from_headers(&HeaderMap::new()).unwrap(),
+            None
+        );
+
+        let xml = sse.to_xml().unwrap();
+        assert_eq!(ServerSideEncryption::from_xml(&xml).unwrap(), sse);
+
+        let aes = ServerSideEncryption::from_xml(
+            "<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>AES256</SSEAlgorithm><KMSMasterKeyID></KMSMasterKeyID></ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>",
+        )
+        .unwrap();
+        assert_eq!(aes, ServerSideEncryption::new(Encryption::Aes256));
+
+        assert!(ServerSideEncryption::new(Encryption::Aes256)
+            .key_id("foo_key")
+            .to_headers()
+            .is_err());
+        assert!(ServerSideEncryption::new(Encryption::Kms)
+            .data_encryption(Encryption::Aes256)
+            .to_xml()
+            .is_err());
+    }
+
+    #[test]
+    fn test_user_meta() {
+        use super::{InvalidUserMeta, UserMeta, USER_META_MAX_SIZE};
+        use http::HeaderMap;
+
+        let mut meta = UserMeta::new();
+        assert_eq!(meta.insert("Author", "foo"), Ok(None));
+        assert_eq!(
+            meta.insert("x-oss-meta-author", "bar"),
+            Ok(Some("foo".to_owned()))
+        );
+        assert_eq!(meta.len(), 1);
+        assert_eq!(meta.size(), "x-oss-meta-author".len() + 3);
+
+        assert_eq!(
+            meta.insert("", "bar"),
+            Err(InvalidUserMeta::Key(String::new()))
+        );
+        assert_eq!(
+            meta.insert("foo", "中文"),
+            Err(InvalidUserMeta::Value("foo".to_owned()))
+        );
+
+        let large = "a".repeat(USER_META_MAX_SIZE);
+        assert!(matches!(
+            meta.insert("large", large),
+            Err(InvalidUserMeta::TooLarge(_))
+        ));
+        assert_eq!(meta.get("author"), Some("bar"));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-oss-meta-author", "foo".parse().unwrap());
+        headers.insert("content-type", "text/plain".parse().unwrap());
+        let meta = UserMeta::from_headers(&headers);
+        assert_eq!(meta.iter().collect::<Vec<_>>(), vec![("author", "foo")]);
+    }
+
+    #[test]
+    fn test_conditions_quote_etag() {
+        use super::Conditions;
+
+        let conditions = Conditions::new().if_match("abc").if_none_match("*");
+        assert_eq!(conditions.if_match.as_deref(), Some("\"abc\""));
+        assert_eq!(conditions.if_none_match.as_deref(), Some("*"));
+
+        let conditions = Conditions::new()
+            .if_match("\"abc\"")
+            .if_none_match("W/\"abc\"");
+        assert_eq!(conditions.if_match.as_deref(), Some("\"abc\""));
+        assert_eq!(conditions.if_none_match.as_deref(), Some("W/\"abc\""));
+    }
+
+    #[test]
+    fn test_conditions_forbid_overwrite() {
+        use super::Conditions;
+
+        let conditions = Conditions::new().if_match("abc").forbid_overwrite(true);
+        let names = |headers: Vec<(http::HeaderName, http::HeaderValue)>| {
+            headers
+                .into_iter()
+                .map(|(name, _)| name.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(conditions.to_headers().unwrap()), vec!["if-match"]);
+        assert_eq!(
+            names(conditions.to_put_headers().unwrap()),
+            vec!["if-match", "x-oss-forbid-overwrite"]
+        );
+        assert_eq!(
+            names(conditions.to_copy_headers().unwrap()),
+            vec!["x-oss-copy-source-if-match", "x-oss-forbid-overwrite"]
+        );
+
+        let conditions = Conditions::new().request_payer(true);
+        assert_eq!(
+            names(conditions.to_put_headers().unwrap()),
+            vec!["x-oss-request-payer"]
+        );
+        assert_eq!(
+            names(Conditions::new().to_payer_headers()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_decode_restore_info() {
+        use crate::decode::RefineObjectList;
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListBucketResult>
+          <Name>foo_bucket</Name>
+          <Prefix></Prefix>
+          <MaxKeys>100</MaxKeys>
+          <IsTruncated>false</IsTruncated>
+          <Contents>
+            <Key>archive.zip</Key>
+            <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+            <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+            <Type>Normal</Type>
+            <Size>18027</Size>
+            <StorageClass>Archive</StorageClass>
+            <RestoreInfo>ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT"</RestoreInfo>
+          </Contents>
+          <Contents>
+            <Key>CHANGELOG.md</Key>
+            <LastModified>2022-06-12T06:11:06.000Z</LastModified>
+            <ETag>"09C37AC5B145D368D52D0AAB58B25213"</ETag>
+            <Type>Normal</Type>
+            <Size>40845</Size>
+            <StorageClass>Standard</StorageClass>
+          </Contents>
+          <KeyCount>2</KeyCount>
+        </ListBucketResult>"#;
+
+        let mut list = init_object_list(None, vec![]);
+        list.decode(xml, Object::default).unwrap();
+
+        let info = list.object_list[0].restore_info().unwrap();
+        assert!(info.is_restored());
+        assert_eq!(
+            info.expiry_date().unwrap().to_rfc2822(),
+            "Sun, 16 Apr 2017 08:12:33 +0000"
+        );
+        assert!(list.object_list[1].restore_info().is_none());
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListBucketResult>
+          <Name>foo_bucket</Name>
+          <Prefix></Prefix>
+          <MaxKeys>100</MaxKeys>
+          <IsTruncated>false</IsTruncated>
+          <Contents>
+            <Key>archive.zip</Key>
+            <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+            <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+            <Type>Normal</Type>
+            <Size>18027</Size>
+            <StorageClass>Archive</StorageClass>
+            <RestoreInfo>unknown</RestoreInfo>
+          </Contents>
+          <KeyCount>1</KeyCount>
+        </ListBucketResult>"#;
+
+        let mut list = init_object_list(None, vec![]);
+        list.decode(xml, Object::default).unwrap();
+        assert_eq!(list.object_list.len(), 1);
+        assert!(list.object_list[0].restore_info().is_none());
+    }
+
+    #[test]
+    fn test_decode_common_prefixes() {
+        use crate::decode::RefineObjectList;
+
+        let mut list: ObjectList = ObjectList::default();
+        list.decode(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListBucketResult>
+              <Name>foo4</Name>
+              <Prefix>foo/</Prefix>
+              <Delimiter>/</Delimiter>
+              <CommonPrefixes>
+                <Prefix>foo/bar/</Prefix>
+              </CommonPrefixes>
+              <CommonPrefixes>
+                <Prefix>foo/baz/</Prefix>
+              </CommonPrefixes>
+            </ListBucketResult>"#,
+            Object::default,
+        )
+        .unwrap();
+
+        assert_eq!(list.prefix(), "foo/");
+        assert_eq!(list.common_prefixes(), &vec!["foo/bar/", "foo/baz/"]);
+    }
+
+    #[test]
+    fn test_decode_url_encoded_with_owner() {
+        use super::Owner;
+        use crate::decode::RefineObjectList;
+
+        let mut list: ObjectList = ObjectList::default();
+        list.decode(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListBucketResult>
+              <Name>foo4</Name>
+              <Prefix>foo%2F</Prefix>
+              <MaxKeys>100</MaxKeys>
+              <EncodingType>url</EncodingType>
+              <Delimiter>%2F</Delimiter>
+              <NextContinuationToken>CgJi%2Bc</NextContinuationToken>
+              <Contents>
+                <Key>foo%2Fa+b%01.txt</Key>
+                <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+                <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+                <Type>Normal</Type>
+                <Size>18027</Size>
+                <StorageClass>Standard</StorageClass>
+                <Owner>
+                  <ID>1305433xxx</ID>
+                  <DisplayName>1305433xxx</DisplayName>
+                </Owner>
+              </Contents>
+              <Contents>
+                <Key>foo%2Fc%2B.txt</Key>
+                <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+                <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+                <Type>Normal</Type>
+                <Size>18027</Size>
+                <StorageClass>Standard</StorageClass>
+              </Contents>
+              <CommonPrefixes>
+                <Prefix>foo%2Fbar%2F</Prefix>
+              </CommonPrefixes>
+            </ListBucketResult>"#,
+            Object::default,
+        )
+        .unwrap();
+
+        assert_eq!(list.prefix(), "foo/");
+        assert_eq!(list.next_continuation_token.as_deref(), Some("CgJi+c"));
+        assert_eq!(list.common_prefixes(), &vec!["foo/bar/"]);
+        assert_eq!(list.object_list[0].path_string(), "foo/a b\u{1}.txt");
+        assert_eq!(
+            list.object_list[0].owner(),
+            Some(&Owner {
+                id: "1305433xxx".to_string(),
+                display_name: "1305433xxx".to_string(),
+            })
+        );
+        assert_eq!(list.object_list[1].path_string(), "foo/c+.txt");
+        assert!(list.object_list[1].owner().is_none());
+    }
+
+    #[test]
+    fn test_multipart_crc64() {
+        use super::{multipart_crc64, UploadedPart};
+        use crate::integrity::crc64;
+
+        let parts = [
+            UploadedPart::new(2, "b").crc64(crc64(b"56789"), 5),
+            UploadedPart::new(1, "a").crc64(crc64(b"1234"), 4),
+        ];
+        assert_eq!(multipart_crc64(&parts), Some(crc64(b"123456789")));
+
+        let parts = [
+            UploadedPart::new(1, "a").crc64(crc64(b"1234"), 4),
+            UploadedPart::new(2, "b"),
+        ];
+        assert_eq!(multipart_crc64(&parts), None);
+    }
 }
 
 #[cfg(feature = "blocking")]
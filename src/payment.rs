@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use http::Method;
+use quick_xml::{events::Event, Reader};
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::{Bucket, InvalidBucketValue},
+    client::ClientArc,
+    errors::OssResult,
+    file::AlignBuilder,
+    types::CanonicalizedResource,
+};
+
+const REQUEST_PAYMENT: &str = "requestPayment";
+
+const PAYER: &[u8] = b"Payer";
+
+const PAYER_BUCKET_OWNER: &str = "BucketOwner";
+const PAYER_REQUESTER: &str = "Requester";
+
+/// # 请求费用的支付方
+/// 设置为 `Requester` 后，访问者需通过 [`Client::request_payer`] 声明自己付费，
+/// 或通过 [`Conditions::request_payer`] 仅为单次文件请求声明
+///
+/// [`Client::request_payer`]: crate::client::Client::request_payer
+/// [`Conditions::request_payer`]: crate::object::Conditions::request_payer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Payer {
+    #[default]
+    BucketOwner,
+    Requester,
+}
+
+impl AsRef<str> for Payer {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::BucketOwner => PAYER_BUCKET_OWNER,
+            Self::Requester => PAYER_REQUESTER,
+        }
+    }
+}
+
+impl Display for Payer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for Payer {
+    type Err = InvalidBucketValue;
+    /// ```
+    /// # use aliyun_oss_client::payment::Payer;
+    /// assert_eq!("Requester".parse::<Payer>().unwrap(), Payer::Requester);
+    /// assert!("foo".parse::<Payer>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            PAYER_BUCKET_OWNER => Ok(Self::BucketOwner),
+            PAYER_REQUESTER => Ok(Self::Requester),
+            _ => Err(InvalidBucketValue),
+        }
+    }
+}
+
+impl Payer {
+    /// 转化为 PutBucketRequestPayment 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        format!("<RequestPaymentConfiguration><Payer>{self}</Payer></RequestPaymentConfiguration>")
+    }
+
+    /// 解析 GetBucketRequestPayment 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() == PAYER => {
+                    let text = reader.read_text(e.to_end().name())?;
+                    return Ok(text.parse()?);
+                }
+                Event::Eof => return Err(InvalidBucketValue.into()),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+}
+
+async fn get_payment<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Payer> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    Payer::from_xml(&content)
+}
+
+async fn put_payment<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    payer: Payer,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(payer.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的请求者付费设置
+    pub async fn get_bucket_request_payment(&self) -> OssResult<Payer> {
+        get_payment(self, self.get_bucket_sub_resource(REQUEST_PAYMENT)).await
+    }
+
+    /// # 设置默认 bucket 的请求者付费
+    /// OSS 没有删除接口，设置为 [`Payer::BucketOwner`] 即可恢复默认
+    pub async fn put_bucket_request_payment(&self, payer: Payer) -> OssResult<()> {
+        put_payment(self, self.get_bucket_sub_resource(REQUEST_PAYMENT), payer).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的请求者付费设置
+    pub async fn get_bucket_request_payment(&self) -> OssResult<Payer> {
+        get_payment(self, self.base.get_sub_resource(REQUEST_PAYMENT)).await
+    }
+
+    /// # 设置 bucket 的请求者付费
+    /// OSS 没有删除接口，设置为 [`Payer::BucketOwner`] 即可恢复默认
+    pub async fn put_bucket_request_payment(&self, payer: Payer) -> OssResult<()> {
+        put_payment(self, self.base.get_sub_resource(REQUEST_PAYMENT), payer).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::Payer;
+    use crate::{errors::OssResult, file::blocking::AlignBuilder, types::CanonicalizedResource};
+
+    pub(super) fn get_payment<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Payer> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        Payer::from_xml(&content)
+    }
+
+    pub(super) fn put_payment<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        payer: Payer,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(payer.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的请求者付费设置
+    pub fn get_bucket_request_payment(&self) -> OssResult<Payer> {
+        blocking::get_payment(self, self.get_bucket_sub_resource(REQUEST_PAYMENT))
+    }
+
+    /// # 设置默认 bucket 的请求者付费
+    /// OSS 没有删除接口，设置为 [`Payer::BucketOwner`] 即可恢复默认
+    pub fn put_bucket_request_payment(&self, payer: Payer) -> OssResult<()> {
+        blocking::put_payment(self, self.get_bucket_sub_resource(REQUEST_PAYMENT), payer)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的请求者付费设置
+    pub fn get_bucket_request_payment(&self) -> OssResult<Payer> {
+        blocking::get_payment(self, self.base.get_sub_resource(REQUEST_PAYMENT))
+    }
+
+    /// # 设置 bucket 的请求者付费
+    /// OSS 没有删除接口，设置为 [`Payer::BucketOwner`] 即可恢复默认
+    pub fn put_bucket_request_payment(&self, payer: Payer) -> OssResult<()> {
+        blocking::put_payment(self, self.base.get_sub_resource(REQUEST_PAYMENT), payer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Payer;
+
+    #[test]
+    fn test_payer_xml() {
+        let xml = Payer::Requester.to_xml();
+        assert_eq!(
+            xml,
+            "<RequestPaymentConfiguration><Payer>Requester</Payer></RequestPaymentConfiguration>"
+        );
+        assert_eq!(Payer::from_xml(&xml).unwrap(), Payer::Requester);
+
+        assert!(Payer::from_xml("<RequestPaymentConfiguration/>").is_err());
+    }
+}
@@ -0,0 +1,638 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use http::Method;
+use serde_json::{Map, Value};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket,
+    builder::BuilderError,
+    client::ClientArc,
+    errors::OssResult,
+    file::AlignBuilder,
+    types::{BucketName, CanonicalizedResource},
+};
+
+const POLICY: &str = "policy";
+const NO_SUCH_BUCKET_POLICY: &str = "NoSuchBucketPolicy";
+
+/// 授权策略语法的版本，OSS 目前只支持 `1`
+const POLICY_VERSION: &str = "1";
+/// OSS 资源 ARN 的前缀
+const ARN_PREFIX: &str = "acs:oss:";
+
+const EFFECT_ALLOW: &str = "Allow";
+const EFFECT_DENY: &str = "Deny";
+
+/// # 授权策略的效力
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+impl AsRef<str> for Effect {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Allow => EFFECT_ALLOW,
+            Self::Deny => EFFECT_DENY,
+        }
+    }
+}
+
+impl Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for Effect {
+    type Err = InvalidPolicy;
+    /// ```
+    /// # use aliyun_oss_client::policy::Effect;
+    /// assert_eq!("Deny".parse::<Effect>().unwrap(), Effect::Deny);
+    /// assert!("foo".parse::<Effect>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            EFFECT_ALLOW => Ok(Self::Allow),
+            EFFECT_DENY => Ok(Self::Deny),
+            _ => Err(InvalidPolicy::new("unknown effect")),
+        }
+    }
+}
+
+/// # 授权策略的生效条件
+/// 如 `IpAddress` 运算符下 `acs:SourceIp` 的取值为 `["192.168.0.0/16"]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub operator: String,
+    pub key: String,
+    pub values: Vec<String>,
+}
+
+/// # 授权策略中的一条授权语句
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    pub effect: Effect,
+    /// 如 `oss:GetObject`，支持 `*` 通配符
+    pub actions: Vec<String>,
+    /// 被授权的 RAM 用户或账号 ID，`*` 表示所有人
+    pub principals: Vec<String>,
+    /// 授权的资源，可通过 [`resource_arn`] 生成
+    pub resources: Vec<String>,
+    pub conditions: Vec<Condition>,
+}
+
+impl Statement {
+    pub fn new(effect: Effect) -> Self {
+        Self {
+            effect,
+            actions: Vec::new(),
+            principals: Vec::new(),
+            resources: Vec::new(),
+            conditions: Vec::new(),
+        }
+    }
+
+    pub fn allow() -> Self {
+        Self::new(Effect::Allow)
+    }
+
+    pub fn deny() -> Self {
+        Self::new(Effect::Deny)
+    }
+
+    /// 添加操作，可多次调用
+    pub fn action<A: Into<String>>(mut self, action: A) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    /// 添加被授权者，可多次调用
+    pub fn principal<P: Into<String>>(mut self, principal: P) -> Self {
+        self.principals.push(principal.into());
+        self
+    }
+
+    /// 添加资源，可多次调用
+    pub fn resource<R: Into<String>>(mut self, resource: R) -> Self {
+        self.resources.push(resource.into());
+        self
+    }
+
+    /// 添加生效条件，可多次调用
+    pub fn condition<O, K, V>(mut self, operator: O, key: K, values: V) -> Self
+    where
+        O: Into<String>,
+        K: Into<String>,
+        V: IntoIterator,
+        V::Item: Into<String>,
+    {
+        self.conditions.push(Condition {
+            operator: operator.into(),
+            key: key.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    fn to_value(&self) -> Value {
+        let strings = |items: &[String]| Value::from(items.to_vec());
+
+        let mut json = Map::new();
+        json.insert("Effect".into(), self.effect.as_ref().into());
+        json.insert("Action".into(), strings(&self.actions));
+        if !self.principals.is_empty() {
+            json.insert("Principal".into(), strings(&self.principals));
+        }
+        json.insert("Resource".into(), strings(&self.resources));
+
+        if !self.conditions.is_empty() {
+            // 相同运算符的条件合并到同一个对象中，保持添加时的顺序
+            let mut operators = Map::new();
+            for condition in self.conditions.iter() {
+                if let Value::Object(keys) = operators
+                    .entry(condition.operator.clone())
+                    .or_insert_with(|| Value::Object(Map::new()))
+                {
+                    keys.insert(condition.key.clone(), strings(&condition.values));
+                }
+            }
+            json.insert("Condition".into(), Value::Object(operators));
+        }
+
+        Value::Object(json)
+    }
+
+    fn from_value(value: Value) -> Result<Self, InvalidPolicy> {
+        let mut effect = None;
+        let mut statement = Self::new(Effect::Allow);
+
+        for (name, value) in into_object(value)? {
+            match name.as_str() {
+                "Effect" => effect = Some(into_string(value)?.parse()?),
+                "Action" => statement.actions = into_strings(value)?,
+                "Principal" => statement.principals = into_strings(value)?,
+                "Resource" => statement.resources = into_strings(value)?,
+                "Condition" => {
+                    for (operator, keys) in into_object(value)? {
+                        for (key, values) in into_object(keys)? {
+                            statement.conditions.push(Condition {
+                                operator: operator.clone(),
+                                key,
+                                values: into_strings(values)?,
+                            });
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        statement.effect = effect.ok_or_else(|| InvalidPolicy::new("statement has no effect"))?;
+        Ok(statement)
+    }
+}
+
+/// # Bucket 授权策略
+/// ```
+/// # use aliyun_oss_client::policy::{resource_arn, BucketPolicy, Statement};
+/// let bucket = "tenant-a".parse().unwrap();
+/// let policy = BucketPolicy::new().statement(
+///     Statement::allow()
+///         .action("oss:GetObject")
+///         .principal("20214760404935xxxx")
+///         .resource(resource_arn(&bucket, "shared/*")),
+/// );
+/// assert!(policy.validate(&bucket).is_ok());
+/// assert!(policy.validate(&"tenant-b".parse().unwrap()).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BucketPolicy {
+    pub statements: Vec<Statement>,
+}
+
+impl BucketPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加授权语句，可多次调用
+    pub fn statement(mut self, statement: Statement) -> Self {
+        self.statements.push(statement);
+        self
+    }
+
+    /// 检查授权策略是否完整，且所有资源都属于 `bucket`
+    pub fn validate(&self, bucket: &BucketName) -> Result<(), InvalidPolicy> {
+        if self.statements.is_empty() {
+            return Err(InvalidPolicy::new("policy has no statement"));
+        }
+
+        for statement in self.statements.iter() {
+            if statement.actions.is_empty() {
+                return Err(InvalidPolicy::new("statement has no action"));
+            }
+            if statement.resources.is_empty() {
+                return Err(InvalidPolicy::new("statement has no resource"));
+            }
+            for resource in statement.resources.iter() {
+                if resource_bucket(resource) != Some(bucket.as_ref()) {
+                    return Err(InvalidPolicy::new("resource not belongs to the bucket"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 转化为 PutBucketPolicy 接口的请求体
+    pub fn to_json(&self) -> String {
+        let statements: Vec<Value> = self.statements.iter().map(Statement::to_value).collect();
+        let mut json = Map::new();
+        json.insert("Version".into(), POLICY_VERSION.into());
+        json.insert("Statement".into(), statements.into());
+        Value::Object(json).to_string()
+    }
+
+    /// 解析 GetBucketPolicy 接口返回的 json
+    ///
+    /// `Action`、`Principal`、`Resource` 以及条件的取值既可以是字符串也可以是数组，
+    /// 条件中的数字、布尔值按原文保存为字符串
+    pub fn from_json(json: &str) -> Result<Self, InvalidPolicy> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|_| InvalidPolicy::new("invalid json"))?;
+
+        let mut policy = Self::default();
+        for (name, value) in into_object(value)? {
+            if name == "Statement" {
+                let statements = match value {
+                    Value::Array(list) => list,
+                    value => vec![value],
+                };
+                for statement in statements {
+                    policy.statements.push(Statement::from_value(statement)?);
+                }
+            }
+        }
+
+        Ok(policy)
+    }
+}
+
+impl FromStr for BucketPolicy {
+    type Err = InvalidPolicy;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+/// # 生成 bucket 中 object 的资源 ARN
+/// `object` 为空时表示 bucket 本身，支持 `*` 通配符
+/// ```
+/// # use aliyun_oss_client::policy::resource_arn;
+/// let bucket = "foo4".parse().unwrap();
+/// assert_eq!(resource_arn(&bucket, ""), "acs:oss:*:*:foo4");
+/// assert_eq!(resource_arn(&bucket, "dir/*"), "acs:oss:*:*:foo4/dir/*");
+/// ```
+pub fn resource_arn(bucket: &BucketName, object: &str) -> String {
+    if object.is_empty() {
+        format!("{ARN_PREFIX}*:*:{bucket}")
+    } else {
+        format!("{ARN_PREFIX}*:*:{bucket}/{object}")
+    }
+}
+
+/// 从资源 ARN `acs:oss:<region>:<account>:<bucket>[/<object>]` 中取出 bucket 名称
+fn resource_bucket(resource: &str) -> Option<&str> {
+    let mut parts = resource.strip_prefix(ARN_PREFIX)?.splitn(3, ':');
+    let (_region, _account) = (parts.next()?, parts.next()?);
+    let path = parts.next()?;
+    Some(path.split('/').next().unwrap_or(path))
+}
+
+fn into_object(value: Value) -> Result<Map<String, Value>, InvalidPolicy> {
+    match value {
+        Value::Object(fields) => Ok(fields),
+        _ => Err(InvalidPolicy::new("expect json object")),
+    }
+}
+
+fn into_string(value: Value) -> Result<String, InvalidPolicy> {
+    match value {
+        Value::String(s) => Ok(s),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Number(n) => Ok(n.to_string()),
+        _ => Err(InvalidPolicy::new("expect json string")),
+    }
+}
+
+/// 单个字符串视为只有一项的数组
+fn into_strings(value: Value) -> Result<Vec<String>, InvalidPolicy> {
+    match value {
+        Value::Array(list) => list.into_iter().map(into_string).collect(),
+        Value::Null => Ok(Vec::new()),
+        value => Ok(vec![into_string(value)?]),
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidPolicy {
+    reason: &'static str,
+}
+
+impl InvalidPolicy {
+    fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+}
+
+impl fmt::Display for InvalidPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid bucket policy: {}", self.reason)
+    }
+}
+
+impl Error for InvalidPolicy {}
+
+async fn get_policy<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Option<BucketPolicy>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => Ok(Some(BucketPolicy::from_json(&response.text().await?)?)),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_BUCKET_POLICY => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn put_policy<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    policy: &BucketPolicy,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(policy.to_json())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_policy<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的授权策略
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_policy(&self) -> OssResult<Option<BucketPolicy>> {
+        get_policy(self, self.get_bucket_sub_resource(POLICY)).await
+    }
+
+    /// # 设置默认 bucket 的授权策略
+    /// 提交前会检查所有资源是否都属于默认 bucket
+    pub async fn put_bucket_policy(&self, policy: &BucketPolicy) -> OssResult<()> {
+        policy.validate(self.get_bucket_name())?;
+        put_policy(self, self.get_bucket_sub_resource(POLICY), policy).await
+    }
+
+    /// # 删除默认 bucket 的授权策略
+    pub async fn delete_bucket_policy(&self) -> OssResult<()> {
+        delete_policy(self, self.get_bucket_sub_resource(POLICY)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的授权策略
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_policy(&self) -> OssResult<Option<BucketPolicy>> {
+        get_policy(self, self.base.get_sub_resource(POLICY)).await
+    }
+
+    /// # 设置 bucket 的授权策略
+    /// 提交前会检查所有资源是否都属于该 bucket
+    pub async fn put_bucket_policy(&self, policy: &BucketPolicy) -> OssResult<()> {
+        policy.validate(self.base.get_name())?;
+        put_policy(self, self.base.get_sub_resource(POLICY), policy).await
+    }
+
+    /// # 删除 bucket 的授权策略
+    pub async fn delete_bucket_policy(&self) -> OssResult<()> {
+        delete_policy(self, self.base.get_sub_resource(POLICY)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::{BucketPolicy, NO_SUCH_BUCKET_POLICY};
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        types::CanonicalizedResource,
+    };
+
+    pub(super) fn get_policy<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Option<BucketPolicy>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => Ok(Some(BucketPolicy::from_json(&response.text()?)?)),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_BUCKET_POLICY => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn put_policy<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        policy: &BucketPolicy,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(policy.to_json())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_policy<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的授权策略
+    /// 未设置时返回 `None`
+    pub fn get_bucket_policy(&self) -> OssResult<Option<BucketPolicy>> {
+        blocking::get_policy(self, self.get_bucket_sub_resource(POLICY))
+    }
+
+    /// # 设置默认 bucket 的授权策略
+    /// 提交前会检查所有资源是否都属于默认 bucket
+    pub fn put_bucket_policy(&self, policy: &BucketPolicy) -> OssResult<()> {
+        policy.validate(self.get_bucket_name())?;
+        blocking::put_policy(self, self.get_bucket_sub_resource(POLICY), policy)
+    }
+
+    /// # 删除默认 bucket 的授权策略
+    pub fn delete_bucket_policy(&self) -> OssResult<()> {
+        blocking::delete_policy(self, self.get_bucket_sub_resource(POLICY))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的授权策略
+    /// 未设置时返回 `None`
+    pub fn get_bucket_policy(&self) -> OssResult<Option<BucketPolicy>> {
+        blocking::get_policy(self, self.base.get_sub_resource(POLICY))
+    }
+
+    /// # 设置 bucket 的授权策略
+    /// 提交前会检查所有资源是否都属于该 bucket
+    pub fn put_bucket_policy(&self, policy: &BucketPolicy) -> OssResult<()> {
+        policy.validate(self.base.get_name())?;
+        blocking::put_policy(self, self.base.get_sub_resource(POLICY), policy)
+    }
+
+    /// # 删除 bucket 的授权策略
+    pub fn delete_bucket_policy(&self) -> OssResult<()> {
+        blocking::delete_policy(self, self.base.get_sub_resource(POLICY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resource_arn, resource_bucket, BucketPolicy, Statement};
+
+    #[test]
+    fn test_policy_json() {
+        let bucket = "foo4".parse().unwrap();
+        let policy = BucketPolicy::new()
+            .statement(
+                Statement::allow()
+                    .action("oss:GetObject")
+                    .action("oss:ListObjects")
+                    .principal("*")
+                    .resource(resource_arn(&bucket, ""))
+                    .resource(resource_arn(&bucket, "a\"b/*"))
+                    .condition("IpAddress", "acs:SourceIp", ["192.168.0.0/16"])
+                    .condition("StringLike", "oss:Prefix", ["a/*", "b/*"])
+                    .condition("IpAddress", "acs:SourceVpc", ["vpc-1"]),
+            )
+            .statement(
+                Statement::deny()
+                    .action("oss:DeleteObject")
+                    .resource("acs:oss:*:174649585760xxxx:foo4/*"),
+            );
+
+        assert!(policy.validate(&bucket).is_ok());
+        assert_eq!(
+            policy.to_json(),
+            r#"{"Version":"1","Statement":[{"Effect":"Allow","Action":["oss:GetObject","oss:ListObjects"],"Principal":["*"],"Resource":["acs:oss:*:*:foo4","acs:oss:*:*:foo4/a\"b/*"],"Condition":{"IpAddress":{"acs:SourceIp":["192.168.0.0/16"],"acs:SourceVpc":["vpc-1"]},"StringLike":{"oss:Prefix":["a/*","b/*"]}}},{"Effect":"Deny","Action":["oss:DeleteObject"],"Resource":["acs:oss:*:174649585760xxxx:foo4/*"]}]}"#
+        );
+    }
+
+    #[test]
+    fn test_policy_from_json() {
+        let bucket = "foo4".parse().unwrap();
+        let policy = BucketPolicy::new().statement(
+            Statement::allow()
+                .action("oss:GetObject")
+                .principal("*")
+                .resource(resource_arn(&bucket, "a\"b/*"))
+                .condition("IpAddress", "acs:SourceIp", ["192.168.0.0/16"])
+                .condition("StringLike", "oss:Prefix", ["a/*", "b/*"]),
+        );
+        assert_eq!(BucketPolicy::from_json(&policy.to_json()).unwrap(), policy);
+
+        let json = r#"{
+            "Version": "1",
+            "Statement": [{
+                "Effect": "Deny",
+                "Action": "oss:*",
+                "Principal": ["\u4e2d"],
+                "Resource": "acs:oss:*:*:foo4",
+                "Condition": {"Bool": {"acs:SecureTransport": false}}
+            }]
+        }"#;
+        let policy: BucketPolicy = json.parse().unwrap();
+        assert_eq!(
+            policy,
+            BucketPolicy::new().statement(
+                Statement::deny()
+                    .action("oss:*")
+                    .principal("中")
+                    .resource("acs:oss:*:*:foo4")
+                    .condition("Bool", "acs:SecureTransport", ["false"])
+            )
+        );
+
+        assert!(BucketPolicy::from_json("{\"Statement\":[{\"Action\":\"oss:*\"}]}").is_err());
+        assert!(BucketPolicy::from_json("{\"Statement\":[}").is_err());
+        assert!(BucketPolicy::from_json("[]").is_err());
+        assert!(BucketPolicy::from_json("{\"Statement\":NaN}").is_err());
+    }
+
+    #[test]
+    fn test_validate_policy() {
+        let bucket = "foo4".parse().unwrap();
+        assert!(BucketPolicy::new().validate(&bucket).is_err());
+
+        let no_action =
+            BucketPolicy::new().statement(Statement::allow().resource("acs:oss:*:*:foo4"));
+        assert!(no_action.validate(&bucket).is_err());
+
+        let other = BucketPolicy::new().statement(
+            Statement::allow()
+                .action("oss:*")
+                .resource("acs:oss:*:*:foo4-other/*"),
+        );
+        assert!(other.validate(&bucket).is_err());
+
+        assert_eq!(resource_bucket("acs:oss:*:*:foo4"), Some("foo4"));
+        assert_eq!(
+            resource_bucket("acs:oss:oss-cn-hangzhou:1234:foo4/a/b"),
+            Some("foo4")
+        );
+        assert_eq!(resource_bucket("acs:ecs:*:*:foo4"), None);
+        assert_eq!(resource_bucket("acs:oss:*"), None);
+    }
+}
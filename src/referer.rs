@@ -0,0 +1,308 @@
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket, client::ClientArc, errors::OssResult, file::AlignBuilder,
+    types::CanonicalizedResource,
+};
+
+const REFERER: &str = "referer";
+
+const REFERER_CONFIGURATION: &[u8] = b"RefererConfiguration";
+const ALLOW_EMPTY_REFERER: &[u8] = b"AllowEmptyReferer";
+const ALLOW_TRUNCATE_QUERY_STRING: &[u8] = b"AllowTruncateQueryString";
+const REFERER_LIST: &[u8] = b"RefererList";
+const REFERER_BLACKLIST: &[u8] = b"RefererBlacklist";
+const REFERER_ITEM: &[u8] = b"Referer";
+
+/// # 防盗链（Referer）设置
+/// 白名单、黑名单支持 `*` 和 `?` 通配符，黑名单优先于白名单
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefererConfiguration {
+    /// 是否允许 Referer 为空的请求
+    pub allow_empty_referer: bool,
+    /// 匹配时是否忽略 Referer 中的查询参数
+    pub allow_truncate_query_string: bool,
+    pub referer_list: Vec<String>,
+    pub referer_blacklist: Vec<String>,
+}
+
+impl Default for RefererConfiguration {
+    /// 与 OSS 未设置防盗链时的行为一致
+    fn default() -> Self {
+        Self {
+            allow_empty_referer: true,
+            allow_truncate_query_string: true,
+            referer_list: Vec::new(),
+            referer_blacklist: Vec::new(),
+        }
+    }
+}
+
+impl RefererConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow_empty_referer(mut self, allow: bool) -> Self {
+        self.allow_empty_referer = allow;
+        self
+    }
+
+    pub fn allow_truncate_query_string(mut self, allow: bool) -> Self {
+        self.allow_truncate_query_string = allow;
+        self
+    }
+
+    /// 添加白名单，可多次调用
+    pub fn referer<R: Into<String>>(mut self, referer: R) -> Self {
+        self.referer_list.push(referer.into());
+        self
+    }
+
+    /// 添加黑名单，可多次调用
+    pub fn blacklist<R: Into<String>>(mut self, referer: R) -> Self {
+        self.referer_blacklist.push(referer.into());
+        self
+    }
+
+    /// 转化为 PutBucketReferer 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        let list = |items: &[String]| -> String {
+            items
+                .iter()
+                .map(|item| format!("<Referer>{}</Referer>", escape(item)))
+                .collect()
+        };
+
+        let mut xml = format!(
+            "<RefererConfiguration><AllowEmptyReferer>{}</AllowEmptyReferer>\
+            <AllowTruncateQueryString>{}</AllowTruncateQueryString>\
+            <RefererList>{}</RefererList>",
+            self.allow_empty_referer,
+            self.allow_truncate_query_string,
+            list(&self.referer_list)
+        );
+        if !self.referer_blacklist.is_empty() {
+            xml.push_str(&format!(
+                "<RefererBlacklist>{}</RefererBlacklist>",
+                list(&self.referer_blacklist)
+            ));
+        }
+        xml.push_str("</RefererConfiguration>");
+        xml
+    }
+
+    /// 解析 GetBucketReferer 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut config = Self::default();
+        let mut in_blacklist = false;
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    REFERER_CONFIGURATION | REFERER_LIST => (),
+                    REFERER_BLACKLIST => in_blacklist = true,
+                    name => {
+                        let raw = reader.read_text(e.to_end().name())?;
+                        let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                        match name {
+                            ALLOW_EMPTY_REFERER => config.allow_empty_referer = text == "true",
+                            ALLOW_TRUNCATE_QUERY_STRING => {
+                                config.allow_truncate_query_string = text == "true"
+                            }
+                            REFERER_ITEM if in_blacklist => {
+                                config.referer_blacklist.push(text.into_owned())
+                            }
+                            REFERER_ITEM => config.referer_list.push(text.into_owned()),
+                            _ => (),
+                        }
+                    }
+                },
+                Event::End(e) if e.name().as_ref() == REFERER_BLACKLIST => in_blacklist = false,
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(config)
+    }
+}
+
+async fn get_referer<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<RefererConfiguration> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    RefererConfiguration::from_xml(&content)
+}
+
+async fn put_referer<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    config: RefererConfiguration,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(config.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的防盗链设置
+    pub async fn get_bucket_referer(&self) -> OssResult<RefererConfiguration> {
+        get_referer(self, self.get_bucket_sub_resource(REFERER)).await
+    }
+
+    /// # 设置默认 bucket 的防盗链
+    /// 传入 [`RefererConfiguration::default`] 即可清空设置
+    pub async fn put_bucket_referer(&self, config: RefererConfiguration) -> OssResult<()> {
+        put_referer(self, self.get_bucket_sub_resource(REFERER), config).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的防盗链设置
+    pub async fn get_bucket_referer(&self) -> OssResult<RefererConfiguration> {
+        get_referer(self, self.base.get_sub_resource(REFERER)).await
+    }
+
+    /// # 设置 bucket 的防盗链
+    /// 传入 [`RefererConfiguration::default`] 即可清空设置
+    pub async fn put_bucket_referer(&self, config: RefererConfiguration) -> OssResult<()> {
+        put_referer(self, self.base.get_sub_resource(REFERER), config).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::RefererConfiguration;
+    use crate::{errors::OssResult, file::blocking::AlignBuilder, types::CanonicalizedResource};
+
+    pub(super) fn get_referer<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<RefererConfiguration> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        RefererConfiguration::from_xml(&content)
+    }
+
+    pub(super) fn put_referer<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        config: RefererConfiguration,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(config.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的防盗链设置
+    pub fn get_bucket_referer(&self) -> OssResult<RefererConfiguration> {
+        blocking::get_referer(self, self.get_bucket_sub_resource(REFERER))
+    }
+
+    /// # 设置默认 bucket 的防盗链
+    /// 传入 [`RefererConfiguration::default`] 即可清空设置
+    pub fn put_bucket_referer(&self, config: RefererConfiguration) -> OssResult<()> {
+        blocking::put_referer(self, self.get_bucket_sub_resource(REFERER), config)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的防盗链设置
+    pub fn get_bucket_referer(&self) -> OssResult<RefererConfiguration> {
+        blocking::get_referer(self, self.base.get_sub_resource(REFERER))
+    }
+
+    /// # 设置 bucket 的防盗链
+    /// 传入 [`RefererConfiguration::default`] 即可清空设置
+    pub fn put_bucket_referer(&self, config: RefererConfiguration) -> OssResult<()> {
+        blocking::put_referer(self, self.base.get_sub_resource(REFERER), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefererConfiguration;
+
+    #[test]
+    fn test_referer_xml() {
+        let config = RefererConfiguration::new()
+            .allow_empty_referer(false)
+            .referer("http://www.aliyun.com")
+            .referer("https://*.example.com/?a=1&b=2")
+            .blacklist("http://www.refuse.com");
+
+        let xml = config.to_xml();
+        assert_eq!(
+            xml,
+            "<RefererConfiguration><AllowEmptyReferer>false</AllowEmptyReferer>\
+            <AllowTruncateQueryString>true</AllowTruncateQueryString>\
+            <RefererList><Referer>http://www.aliyun.com</Referer>\
+            <Referer>https://*.example.com/?a=1&amp;b=2</Referer></RefererList>\
+            <RefererBlacklist><Referer>http://www.refuse.com</Referer></RefererBlacklist>\
+            </RefererConfiguration>"
+        );
+        assert_eq!(RefererConfiguration::from_xml(&xml).unwrap(), config);
+    }
+
+    #[test]
+    fn test_default_referer_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <RefererConfiguration>
+          <AllowEmptyReferer>true</AllowEmptyReferer>
+          <AllowTruncateQueryString>true</AllowTruncateQueryString>
+          <RefererList />
+        </RefererConfiguration>"#;
+
+        assert_eq!(
+            RefererConfiguration::from_xml(xml).unwrap(),
+            RefererConfiguration::default()
+        );
+        assert_eq!(
+            RefererConfiguration::default().to_xml(),
+            "<RefererConfiguration><AllowEmptyReferer>true</AllowEmptyReferer>\
+            <AllowTruncateQueryString>true</AllowTruncateQueryString>\
+            <RefererList></RefererList></RefererConfiguration>"
+        );
+    }
+}
@@ -0,0 +1,882 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use chrono::{DateTime, Utc};
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::{Bucket, BucketLocation, InvalidBucketValue},
+    builder::BuilderError,
+    client::ClientArc,
+    config::BucketBase,
+    errors::OssResult,
+    file::AlignBuilder,
+    types::{BucketName, CanonicalizedResource},
+};
+
+const REPLICATION: &str = "replication";
+const REPLICATION_LOCATION: &str = "replicationLocation";
+const REPLICATION_PROGRESS: &str = "replicationProgress";
+const NO_SUCH_REPLICATION_CONFIGURATION: &str = "NoSuchReplicationConfiguration";
+const COMP_ADD: &str = "add";
+const COMP_DELETE: &str = "delete";
+const RULE_ID: &str = "rule-id";
+
+const RULE: &[u8] = b"Rule";
+const ID: &[u8] = b"ID";
+const PREFIX_SET: &[u8] = b"PrefixSet";
+const PREFIX: &[u8] = b"Prefix";
+const ACTION: &[u8] = b"Action";
+const DESTINATION: &[u8] = b"Destination";
+const BUCKET: &[u8] = b"Bucket";
+const LOCATION: &[u8] = b"Location";
+const TRANSFER_TYPE: &[u8] = b"TransferType";
+const STATUS: &[u8] = b"Status";
+const HISTORICAL_OBJECT_REPLICATION: &[u8] = b"HistoricalObjectReplication";
+const SYNC_ROLE: &[u8] = b"SyncRole";
+const SOURCE_SELECTION_CRITERIA: &[u8] = b"SourceSelectionCriteria";
+const SSE_KMS_ENCRYPTED_OBJECTS: &[u8] = b"SseKmsEncryptedObjects";
+const ENCRYPTION_CONFIGURATION: &[u8] = b"EncryptionConfiguration";
+const REPLICA_KMS_KEY_ID: &[u8] = b"ReplicaKmsKeyID";
+const PROGRESS: &[u8] = b"Progress";
+const HISTORICAL_OBJECT: &[u8] = b"HistoricalObject";
+const NEW_OBJECT: &[u8] = b"NewObject";
+const REPLICATION_LOCATION_TAG: &[u8] = b"ReplicationLocation";
+const LOCATION_TRANSFER_TYPE_CONSTRAINT: &[u8] = b"LocationTransferTypeConstraint";
+const LOCATION_TRANSFER_TYPE: &[u8] = b"LocationTransferType";
+const TRANSFER_TYPES: &[u8] = b"TransferTypes";
+const TYPE: &[u8] = b"Type";
+
+const ENABLED: &str = "enabled";
+const DISABLED: &str = "disabled";
+const STATUS_ENABLED: &str = "Enabled";
+
+/// # 需要同步到目标 bucket 的操作
+/// - `All` 同步新增、更新、删除操作
+/// - `Put` 只同步新增、更新操作
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ReplicationAction {
+    #[default]
+    All,
+    Put,
+}
+
+const ACTION_ALL: &str = "ALL";
+const ACTION_PUT: &str = "PUT";
+
+impl AsRef<str> for ReplicationAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::All => ACTION_ALL,
+            Self::Put => ACTION_PUT,
+        }
+    }
+}
+
+impl Display for ReplicationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for ReplicationAction {
+    type Err = InvalidBucketValue;
+    /// ```
+    /// # use aliyun_oss_client::replication::ReplicationAction;
+    /// assert_eq!("PUT".parse::<ReplicationAction>().unwrap(), ReplicationAction::Put);
+    /// assert!("foo".parse::<ReplicationAction>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ACTION_ALL => Ok(Self::All),
+            ACTION_PUT => Ok(Self::Put),
+            _ => Err(InvalidBucketValue),
+        }
+    }
+}
+
+/// # 数据同步使用的传输链路
+/// - `Internal` 默认的 OSS 内部链路
+/// - `OssAcc` 传输加速链路，只在跨境同步时可用
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferType {
+    Internal,
+    OssAcc,
+}
+
+const TRANSFER_INTERNAL: &str = "internal";
+const TRANSFER_OSS_ACC: &str = "oss_acc";
+
+impl AsRef<str> for TransferType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Internal => TRANSFER_INTERNAL,
+            Self::OssAcc => TRANSFER_OSS_ACC,
+        }
+    }
+}
+
+impl Display for TransferType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for TransferType {
+    type Err = InvalidBucketValue;
+    /// ```
+    /// # use aliyun_oss_client::replication::TransferType;
+    /// assert_eq!("oss_acc".parse::<TransferType>().unwrap(), TransferType::OssAcc);
+    /// assert!("foo".parse::<TransferType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            TRANSFER_INTERNAL => Ok(Self::Internal),
+            TRANSFER_OSS_ACC => Ok(Self::OssAcc),
+            _ => Err(InvalidBucketValue),
+        }
+    }
+}
+
+/// # 数据同步的目标 bucket
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplicationDestination {
+    pub bucket: BucketName,
+    /// 目标 bucket 所在的地域，如 `oss-cn-beijing`
+    pub location: BucketLocation,
+    pub transfer_type: Option<TransferType>,
+}
+
+impl ReplicationDestination {
+    pub fn new(bucket: BucketName, location: BucketLocation) -> Self {
+        Self {
+            bucket,
+            location,
+            transfer_type: None,
+        }
+    }
+
+    pub fn transfer_type(mut self, transfer_type: TransferType) -> Self {
+        self.transfer_type = Some(transfer_type);
+        self
+    }
+}
+
+/// # 数据同步规则
+/// `id` 和 `status` 由 OSS 生成，设置规则时无需填写
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReplicationRule {
+    pub id: Option<String>,
+    /// 只同步匹配这些前缀的 object，为空时同步全部
+    pub prefix_set: Vec<String>,
+    pub action: ReplicationAction,
+    pub destination: ReplicationDestination,
+    /// 是否同步规则生效前已存在的 object，默认为 `true`
+    pub historical_object_replication: bool,
+    /// 授权 OSS 进行数据同步的 RAM 角色，同步 KMS 加密的 object 时必须设置
+    pub sync_role: Option<String>,
+    /// 是否同步通过 KMS 加密的 object
+    pub replicate_sse_kms: bool,
+    /// 目标 bucket 加密 object 使用的 KMS 密钥 ID
+    pub replica_kms_key_id: Option<String>,
+    /// 同步状态，为 `starting`、`doing` 或 `closing`
+    pub status: Option<String>,
+}
+
+impl ReplicationRule {
+    pub fn new(destination: ReplicationDestination) -> Self {
+        Self {
+            id: None,
+            prefix_set: Vec::new(),
+            action: ReplicationAction::default(),
+            destination,
+            historical_object_replication: true,
+            sync_role: None,
+            replicate_sse_kms: false,
+            replica_kms_key_id: None,
+            status: None,
+        }
+    }
+
+    /// 添加前缀，可多次调用
+    pub fn prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.prefix_set.push(prefix.into());
+        self
+    }
+
+    pub fn action(mut self, action: ReplicationAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    pub fn historical_object_replication(mut self, enabled: bool) -> Self {
+        self.historical_object_replication = enabled;
+        self
+    }
+
+    pub fn sync_role<R: Into<String>>(mut self, role: R) -> Self {
+        self.sync_role = Some(role.into());
+        self
+    }
+
+    /// 同步 KMS 加密的 object，并在目标 bucket 使用 `key_id` 加密
+    pub fn replicate_sse_kms<K: Into<String>>(mut self, key_id: K) -> Self {
+        self.replicate_sse_kms = true;
+        self.replica_kms_key_id = Some(key_id.into());
+        self
+    }
+
+    /// 转化为 PutBucketReplication 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        let mut xml = String::from("<ReplicationConfiguration><Rule>");
+        if let Some(id) = &self.id {
+            xml.push_str(&format!("<ID>{}</ID>", escape(id)));
+        }
+        if !self.prefix_set.is_empty() {
+            xml.push_str("<PrefixSet>");
+            for prefix in self.prefix_set.iter() {
+                xml.push_str(&format!("<Prefix>{}</Prefix>", escape(prefix)));
+            }
+            xml.push_str("</PrefixSet>");
+        }
+        xml.push_str(&format!(
+            "<Action>{}</Action><Destination><Bucket>{}</Bucket><Location>{}</Location>",
+            self.action,
+            self.destination.bucket,
+            escape(self.destination.location.as_ref())
+        ));
+        if let Some(transfer_type) = &self.destination.transfer_type {
+            xml.push_str(&format!("<TransferType>{transfer_type}</TransferType>"));
+        }
+        xml.push_str(&format!(
+            "</Destination><HistoricalObjectReplication>{}</HistoricalObjectReplication>",
+            if self.historical_object_replication {
+                ENABLED
+            } else {
+                DISABLED
+            }
+        ));
+        if let Some(role) = &self.sync_role {
+            xml.push_str(&format!("<SyncRole>{}</SyncRole>", escape(role)));
+        }
+        if self.replicate_sse_kms {
+            xml.push_str(
+                "<SourceSelectionCriteria><SseKmsEncryptedObjects><Status>Enabled</Status>\
+                </SseKmsEncryptedObjects></SourceSelectionCriteria>",
+            );
+        }
+        if let Some(key_id) = &self.replica_kms_key_id {
+            xml.push_str(&format!(
+                "<EncryptionConfiguration><ReplicaKmsKeyID>{}</ReplicaKmsKeyID></EncryptionConfiguration>",
+                escape(key_id)
+            ));
+        }
+        xml.push_str("</Rule></ReplicationConfiguration>");
+        xml
+    }
+}
+
+/// # 数据同步规则的进度
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplicationProgress {
+    pub rule: ReplicationRule,
+    /// 历史数据的同步进度，取值为 0 到 1，未开启历史数据同步时为 `None`
+    pub historical_object: Option<f64>,
+    /// 此时间点之前写入的新数据都已同步到目标 bucket
+    pub new_object: Option<DateTime<Utc>>,
+}
+
+/// # 可作为同步目标的地域
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplicationLocation {
+    pub locations: Vec<BucketLocation>,
+    /// 支持传输加速等特殊链路的地域
+    pub transfer_types: Vec<(BucketLocation, Vec<TransferType>)>,
+}
+
+impl ReplicationLocation {
+    /// 解析 GetBucketReplicationLocation 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut result = Self::default();
+        let mut in_constraint = false;
+        let mut location = None;
+        let mut types = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    LOCATION_TRANSFER_TYPE_CONSTRAINT => in_constraint = true,
+                    REPLICATION_LOCATION_TAG | LOCATION_TRANSFER_TYPE | TRANSFER_TYPES => (),
+                    name @ (LOCATION | TYPE) => {
+                        let text = reader.read_text(e.to_end().name())?;
+                        match name {
+                            LOCATION if in_constraint => location = Some(text.parse()?),
+                            LOCATION => result.locations.push(text.parse()?),
+                            _ => types.push(text.parse()?),
+                        }
+                    }
+                    // 忽略其他约束，如 LocationRTCConstraint
+                    _ => {
+                        reader.read_to_end(e.to_end().name())?;
+                    }
+                },
+                Event::End(e) => match e.name().as_ref() {
+                    LOCATION_TRANSFER_TYPE_CONSTRAINT => in_constraint = false,
+                    LOCATION_TRANSFER_TYPE => {
+                        let location = location.take().ok_or(InvalidBucketValue)?;
+                        result
+                            .transfer_types
+                            .push((location, std::mem::take(&mut types)));
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(result)
+    }
+}
+
+/// 解析 xml 时暂存规则的各项
+#[derive(Default)]
+struct Fields {
+    id: Option<String>,
+    prefix_set: Vec<String>,
+    action: ReplicationAction,
+    bucket: Option<BucketName>,
+    location: Option<BucketLocation>,
+    transfer_type: Option<TransferType>,
+    historical_object_replication: Option<bool>,
+    sync_role: Option<String>,
+    replicate_sse_kms: bool,
+    replica_kms_key_id: Option<String>,
+    status: Option<String>,
+    historical_object: Option<f64>,
+    new_object: Option<DateTime<Utc>>,
+}
+
+impl Fields {
+    fn into_progress(self) -> OssResult<ReplicationProgress> {
+        let destination = ReplicationDestination {
+            bucket: self.bucket.ok_or(InvalidBucketValue)?,
+            location: self.location.ok_or(InvalidBucketValue)?,
+            transfer_type: self.transfer_type,
+        };
+        let rule = ReplicationRule {
+            id: self.id,
+            prefix_set: self.prefix_set,
+            action: self.action,
+            destination,
+            historical_object_replication: self.historical_object_replication.unwrap_or(true),
+            sync_role: self.sync_role,
+            replicate_sse_kms: self.replicate_sse_kms,
+            replica_kms_key_id: self.replica_kms_key_id,
+            status: self.status,
+        };
+
+        Ok(ReplicationProgress {
+            rule,
+            historical_object: self.historical_object,
+            new_object: self.new_object,
+        })
+    }
+}
+
+/// 解析 GetBucketReplication 和 GetBucketReplicationProgress 接口返回的 xml
+fn rules_from_xml(xml: &str) -> OssResult<Vec<ReplicationProgress>> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::with_capacity(xml.len());
+
+    let mut rules = Vec::new();
+    // 当前所在的父节点，用于区分同名的子节点，如 `Status`
+    let mut parents: Vec<Vec<u8>> = Vec::new();
+    let mut fields = Fields::default();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => match e.name().as_ref() {
+                name @ (RULE
+                | PREFIX_SET
+                | DESTINATION
+                | SOURCE_SELECTION_CRITERIA
+                | SSE_KMS_ENCRYPTED_OBJECTS
+                | ENCRYPTION_CONFIGURATION
+                | PROGRESS) => {
+                    if name == RULE {
+                        fields = Fields::default();
+                    }
+                    parents.push(name.to_vec());
+                }
+                name if parents.is_empty() => parents.push(name.to_vec()),
+                name => {
+                    let raw = reader.read_text(e.to_end().name())?;
+                    let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                    let parent = parents.last().map(Vec::as_slice).unwrap_or_default();
+                    match (parent, name) {
+                        (RULE, ID) => fields.id = Some(text.into_owned()),
+                        (RULE, ACTION) => fields.action = text.parse()?,
+                        (RULE, STATUS) => fields.status = Some(text.into_owned()),
+                        (RULE, HISTORICAL_OBJECT_REPLICATION) => {
+                            fields.historical_object_replication = Some(text == ENABLED)
+                        }
+                        (RULE, SYNC_ROLE) => fields.sync_role = Some(text.into_owned()),
+                        (PREFIX_SET, PREFIX) => fields.prefix_set.push(text.into_owned()),
+                        (DESTINATION, BUCKET) => {
+                            fields.bucket = Some(BucketName::new(text.into_owned())?)
+                        }
+                        (DESTINATION, LOCATION) => fields.location = Some(text.parse()?),
+                        (DESTINATION, TRANSFER_TYPE) => fields.transfer_type = Some(text.parse()?),
+                        (SSE_KMS_ENCRYPTED_OBJECTS, STATUS) => {
+                            fields.replicate_sse_kms = text == STATUS_ENABLED
+                        }
+                        (ENCRYPTION_CONFIGURATION, REPLICA_KMS_KEY_ID) => {
+                            fields.replica_kms_key_id = Some(text.into_owned())
+                        }
+                        (PROGRESS, HISTORICAL_OBJECT) => {
+                            fields.historical_object =
+                                Some(text.parse().map_err(|_| InvalidBucketValue)?)
+                        }
+                        (PROGRESS, NEW_OBJECT) => fields.new_object = Some(text.parse()?),
+                        _ => (),
+                    }
+                }
+            },
+            Event::End(e) => {
+                if e.name().as_ref() == RULE {
+                    rules.push(std::mem::take(&mut fields).into_progress()?);
+                }
+                parents.pop();
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(rules)
+}
+
+/// 解析 GetBucketReplicationProgress 接口返回的 xml，只包含一条规则
+fn progress_from_xml(xml: &str) -> OssResult<ReplicationProgress> {
+    rules_from_xml(xml)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| InvalidBucketValue.into())
+}
+
+/// `?replication&comp=...` 接口的地址及签名参数，签名时子资源按字典序排列
+fn replication_comp(base: &BucketBase, comp: &str) -> (Url, CanonicalizedResource) {
+    let mut url = base.to_url();
+    url.set_query(Some(&format!("{REPLICATION}&comp={comp}")));
+
+    let resource =
+        CanonicalizedResource::from(format!("/{}/?comp={}&{}", base.name(), comp, REPLICATION));
+
+    (url, resource)
+}
+
+/// `rule-id` 不参与签名
+fn replication_progress(base: &BucketBase, rule_id: &str) -> (Url, CanonicalizedResource) {
+    let (mut url, resource) = base.get_sub_resource(REPLICATION_PROGRESS);
+    url.query_pairs_mut().append_pair(RULE_ID, rule_id);
+
+    (url, resource)
+}
+
+fn delete_rule_xml(rule_id: &str) -> String {
+    format!(
+        "<ReplicationRules><ID>{}</ID></ReplicationRules>",
+        escape(rule_id)
+    )
+}
+
+async fn put_replication<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    rule: ReplicationRule,
+) -> OssResult<()> {
+    builder
+        .builder(Method::POST, url, canonicalized)?
+        .body(rule.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn get_replication<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Vec<ReplicationRule>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => Ok(rules_from_xml(&response.text().await?)?
+            .into_iter()
+            .map(|progress| progress.rule)
+            .collect()),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_REPLICATION_CONFIGURATION => {
+            Ok(Vec::new())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn delete_replication<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    rule_id: &str,
+) -> OssResult<()> {
+    builder
+        .builder(Method::POST, url, canonicalized)?
+        .body(delete_rule_xml(rule_id))
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn get_location<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<ReplicationLocation> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    ReplicationLocation::from_xml(&content)
+}
+
+async fn get_progress<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<ReplicationProgress> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    progress_from_xml(&content)
+}
+
+impl ClientArc {
+    /// # 为默认 bucket 添加数据同步规则
+    /// 每次调用只能添加一条规则
+    pub async fn put_bucket_replication(&self, rule: ReplicationRule) -> OssResult<()> {
+        let sub = replication_comp(&self.get_bucket_base(), COMP_ADD);
+        put_replication(self, sub, rule).await
+    }
+
+    /// # 获取默认 bucket 的数据同步规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_replication(&self) -> OssResult<Vec<ReplicationRule>> {
+        get_replication(self, self.get_bucket_sub_resource(REPLICATION)).await
+    }
+
+    /// # 停止默认 bucket 的某条数据同步规则
+    /// 已同步到目标 bucket 的数据会保留
+    pub async fn delete_bucket_replication(&self, rule_id: &str) -> OssResult<()> {
+        let sub = replication_comp(&self.get_bucket_base(), COMP_DELETE);
+        delete_replication(self, sub, rule_id).await
+    }
+
+    /// # 获取默认 bucket 可同步到的目标地域
+    pub async fn get_bucket_replication_location(&self) -> OssResult<ReplicationLocation> {
+        get_location(self, self.get_bucket_sub_resource(REPLICATION_LOCATION)).await
+    }
+
+    /// # 获取默认 bucket 某条数据同步规则的进度
+    pub async fn get_bucket_replication_progress(
+        &self,
+        rule_id: &str,
+    ) -> OssResult<ReplicationProgress> {
+        get_progress(self, replication_progress(&self.get_bucket_base(), rule_id)).await
+    }
+}
+
+impl Bucket {
+    /// # 为 bucket 添加数据同步规则
+    /// 每次调用只能添加一条规则
+    pub async fn put_bucket_replication(&self, rule: ReplicationRule) -> OssResult<()> {
+        put_replication(self, replication_comp(&self.base, COMP_ADD), rule).await
+    }
+
+    /// # 获取 bucket 的数据同步规则
+    /// 未设置时返回空列表
+    pub async fn get_bucket_replication(&self) -> OssResult<Vec<ReplicationRule>> {
+        get_replication(self, self.base.get_sub_resource(REPLICATION)).await
+    }
+
+    /// # 停止 bucket 的某条数据同步规则
+    /// 已同步到目标 bucket 的数据会保留
+    pub async fn delete_bucket_replication(&self, rule_id: &str) -> OssResult<()> {
+        delete_replication(self, replication_comp(&self.base, COMP_DELETE), rule_id).await
+    }
+
+    /// # 获取 bucket 可同步到的目标地域
+    pub async fn get_bucket_replication_location(&self) -> OssResult<ReplicationLocation> {
+        get_location(self, self.base.get_sub_resource(REPLICATION_LOCATION)).await
+    }
+
+    /// # 获取 bucket 某条数据同步规则的进度
+    pub async fn get_bucket_replication_progress(
+        &self,
+        rule_id: &str,
+    ) -> OssResult<ReplicationProgress> {
+        get_progress(self, replication_progress(&self.base, rule_id)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::{
+        delete_rule_xml, progress_from_xml, rules_from_xml, ReplicationLocation,
+        ReplicationProgress, ReplicationRule, NO_SUCH_REPLICATION_CONFIGURATION,
+    };
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        types::CanonicalizedResource,
+    };
+
+    pub(super) fn put_replication<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        rule: ReplicationRule,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::POST, url, canonicalized)?
+            .body(rule.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn get_replication<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Vec<ReplicationRule>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => Ok(rules_from_xml(&response.text()?)?
+                .into_iter()
+                .map(|progress| progress.rule)
+                .collect()),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_REPLICATION_CONFIGURATION => {
+                Ok(Vec::new())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn delete_replication<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        rule_id: &str,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::POST, url, canonicalized)?
+            .body(delete_rule_xml(rule_id))
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn get_location<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<ReplicationLocation> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        ReplicationLocation::from_xml(&content)
+    }
+
+    pub(super) fn get_progress<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<ReplicationProgress> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        progress_from_xml(&content)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 为默认 bucket 添加数据同步规则
+    /// 每次调用只能添加一条规则
+    pub fn put_bucket_replication(&self, rule: ReplicationRule) -> OssResult<()> {
+        let sub = replication_comp(&self.get_bucket_base(), COMP_ADD);
+        blocking::put_replication(self, sub, rule)
+    }
+
+    /// # 获取默认 bucket 的数据同步规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_replication(&self) -> OssResult<Vec<ReplicationRule>> {
+        blocking::get_replication(self, self.get_bucket_sub_resource(REPLICATION))
+    }
+
+    /// # 停止默认 bucket 的某条数据同步规则
+    /// 已同步到目标 bucket 的数据会保留
+    pub fn delete_bucket_replication(&self, rule_id: &str) -> OssResult<()> {
+        let sub = replication_comp(&self.get_bucket_base(), COMP_DELETE);
+        blocking::delete_replication(self, sub, rule_id)
+    }
+
+    /// # 获取默认 bucket 可同步到的目标地域
+    pub fn get_bucket_replication_location(&self) -> OssResult<ReplicationLocation> {
+        blocking::get_location(self, self.get_bucket_sub_resource(REPLICATION_LOCATION))
+    }
+
+    /// # 获取默认 bucket 某条数据同步规则的进度
+    pub fn get_bucket_replication_progress(&self, rule_id: &str) -> OssResult<ReplicationProgress> {
+        blocking::get_progress(self, replication_progress(&self.get_bucket_base(), rule_id))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 为 bucket 添加数据同步规则
+    /// 每次调用只能添加一条规则
+    pub fn put_bucket_replication(&self, rule: ReplicationRule) -> OssResult<()> {
+        blocking::put_replication(self, replication_comp(&self.base, COMP_ADD), rule)
+    }
+
+    /// # 获取 bucket 的数据同步规则
+    /// 未设置时返回空列表
+    pub fn get_bucket_replication(&self) -> OssResult<Vec<ReplicationRule>> {
+        blocking::get_replication(self, self.base.get_sub_resource(REPLICATION))
+    }
+
+    /// # 停止 bucket 的某条数据同步规则
+    /// 已同步到目标 bucket 的数据会保留
+    pub fn delete_bucket_replication(&self, rule_id: &str) -> OssResult<()> {
+        blocking::delete_replication(self, replication_comp(&self.base, COMP_DELETE), rule_id)
+    }
+
+    /// # 获取 bucket 可同步到的目标地域
+    pub fn get_bucket_replication_location(&self) -> OssResult<ReplicationLocation> {
+        blocking::get_location(self, self.base.get_sub_resource(REPLICATION_LOCATION))
+    }
+
+    /// # 获取 bucket 某条数据同步规则的进度
+    pub fn get_bucket_replication_progress(&self, rule_id: &str) -> OssResult<ReplicationProgress> {
+        blocking::get_progress(self, replication_progress(&self.base, rule_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        rules_from_xml, ReplicationAction, ReplicationDestination, ReplicationLocation,
+        ReplicationRule, TransferType,
+    };
+
+    #[test]
+    fn test_replication_xml() {
+        let rule = ReplicationRule::new(
+            ReplicationDestination::new(
+                "dr-bucket".parse().unwrap(),
+                "oss-cn-beijing".parse().unwrap(),
+            )
+            .transfer_type(TransferType::OssAcc),
+        )
+        .prefix("a&b/")
+        .prefix("logs/")
+        .action(ReplicationAction::Put)
+        .historical_object_replication(false)
+        .sync_role("aliyunramrole")
+        .replicate_sse_kms("c4d49f85-ee30-426b-a5ed-95e9139d****");
+
+        let xml = rule.to_xml();
+        assert_eq!(
+            xml,
+            "<ReplicationConfiguration><Rule><PrefixSet><Prefix>a&amp;b/</Prefix><Prefix>logs/</Prefix></PrefixSet>\
+            <Action>PUT</Action><Destination><Bucket>dr-bucket</Bucket><Location>oss-cn-beijing</Location>\
+            <TransferType>oss_acc</TransferType></Destination>\
+            <HistoricalObjectReplication>disabled</HistoricalObjectReplication><SyncRole>aliyunramrole</SyncRole>\
+            <SourceSelectionCriteria><SseKmsEncryptedObjects><Status>Enabled</Status></SseKmsEncryptedObjects></SourceSelectionCriteria>\
+            <EncryptionConfiguration><ReplicaKmsKeyID>c4d49f85-ee30-426b-a5ed-95e9139d****</ReplicaKmsKeyID></EncryptionConfiguration>\
+            </Rule></ReplicationConfiguration>"
+        );
+
+        let rules = rules_from_xml(&xml).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].rule, rule);
+        assert_eq!(rules[0].historical_object, None);
+    }
+
+    #[test]
+    fn test_replication_location_xml() {
+        let xml = r#"<?xml version="1.0" ?>
+        <ReplicationLocation>
+          <Location>oss-cn-beijing</Location>
+          <Location>oss-cn-hongkong</Location>
+          <LocationTransferTypeConstraint>
+            <LocationTransferType>
+              <Location>oss-cn-hongkong</Location>
+              <TransferTypes>
+                <Type>oss_acc</Type>
+              </TransferTypes>
+            </LocationTransferType>
+          </LocationTransferTypeConstraint>
+          <LocationRTCConstraint>
+            <Location>oss-cn-beijing</Location>
+          </LocationRTCConstraint>
+        </ReplicationLocation>"#;
+
+        let location = ReplicationLocation::from_xml(xml).unwrap();
+        assert_eq!(
+            location.locations,
+            vec![
+                "oss-cn-beijing".parse().unwrap(),
+                "oss-cn-hongkong".parse().unwrap()
+            ]
+        );
+        assert_eq!(
+            location.transfer_types,
+            vec![(
+                "oss-cn-hongkong".parse().unwrap(),
+                vec![TransferType::OssAcc]
+            )]
+        );
+    }
+}
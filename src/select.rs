@@ -0,0 +1,830 @@
+use std::fmt;
+
+use async_stream::try_stream;
+use base64::encode;
+use futures_core::stream::Stream;
+use reqwest::Response;
+use thiserror::Error;
+
+use crate::errors::OssResult;
+
+/// # 文件的压缩类型
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum CompressionType {
+    #[default]
+    None,
+    Gzip,
+}
+
+const COMPRESSION_NONE: &str = "None";
+const COMPRESSION_GZIP: &str = "GZIP";
+
+impl AsRef<str> for CompressionType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::None => COMPRESSION_NONE,
+            Self::Gzip => COMPRESSION_GZIP,
+        }
+    }
+}
+
+impl fmt::Display for CompressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// # CSV 文件首行的处理方式
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum FileHeaderInfo {
+    /// 首行不是表头
+    #[default]
+    None,
+    /// 首行是表头，但不在 SQL 中使用
+    Ignore,
+    /// 首行是表头，可在 SQL 中使用列名
+    Use,
+}
+
+const HEADER_NONE: &str = "NONE";
+const HEADER_IGNORE: &str = "IGNORE";
+const HEADER_USE: &str = "USE";
+
+impl AsRef<str> for FileHeaderInfo {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::None => HEADER_NONE,
+            Self::Ignore => HEADER_IGNORE,
+            Self::Use => HEADER_USE,
+        }
+    }
+}
+
+impl fmt::Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// # JSON 文件的格式
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub enum JsonType {
+    /// 整个文件是一个 JSON 对象
+    #[default]
+    Document,
+    /// 每行一个 JSON 对象
+    Lines,
+}
+
+const JSON_DOCUMENT: &str = "DOCUMENT";
+const JSON_LINES: &str = "LINES";
+
+impl AsRef<str> for JsonType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Document => JSON_DOCUMENT,
+            Self::Lines => JSON_LINES,
+        }
+    }
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// # CSV 文件的格式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvInput {
+    pub file_header_info: FileHeaderInfo,
+    pub record_delimiter: String,
+    pub field_delimiter: String,
+    pub quote_character: String,
+    pub comment_character: String,
+    /// 查询范围，如 `line-range=0-99` 或 `split-range=0-9`
+    pub range: Option<String>,
+    pub allow_quoted_record_delimiter: bool,
+}
+
+impl Default for CsvInput {
+    fn default() -> Self {
+        Self {
+            file_header_info: FileHeaderInfo::default(),
+            record_delimiter: "\n".to_owned(),
+            field_delimiter: ",".to_owned(),
+            quote_character: "\"".to_owned(),
+            comment_character: "#".to_owned(),
+            range: None,
+            allow_quoted_record_delimiter: true,
+        }
+    }
+}
+
+/// # JSON 文件的格式
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct JsonInput {
+    pub json_type: JsonType,
+    /// 查询范围，如 `line-range=0-99` 或 `split-range=0-9`，仅 `JsonType::Lines` 支持
+    pub range: Option<String>,
+    pub parse_json_number_as_string: bool,
+}
+
+/// # 被查询文件的格式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSerialization {
+    Csv(CsvInput),
+    Json(JsonInput),
+}
+
+impl Default for InputSerialization {
+    fn default() -> Self {
+        Self::Csv(CsvInput::default())
+    }
+}
+
+/// # 查询结果的输出格式
+/// 输出格式与被查询文件的格式一致，JSON 格式不使用 `field_delimiter`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputSerialization {
+    pub record_delimiter: String,
+    pub field_delimiter: String,
+    pub keep_all_columns: bool,
+    pub output_header: bool,
+    pub enable_payload_crc: bool,
+}
+
+impl Default for OutputSerialization {
+    fn default() -> Self {
+        Self {
+            record_delimiter: "\n".to_owned(),
+            field_delimiter: ",".to_owned(),
+            keep_all_columns: false,
+            output_header: false,
+            enable_payload_crc: true,
+        }
+    }
+}
+
+/// # SelectObject 接口的请求参数
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectRequest {
+    /// SQL 语句，如 `select * from ossobject where _1 > 100`
+    pub expression: String,
+    pub compression: CompressionType,
+    pub input: InputSerialization,
+    pub output: OutputSerialization,
+    pub skip_partial_data_record: bool,
+    pub max_skipped_records_allowed: Option<u64>,
+}
+
+impl SelectRequest {
+    /// 查询 CSV 文件
+    pub fn csv<E: Into<String>>(expression: E, input: CsvInput) -> Self {
+        Self::new(expression, InputSerialization::Csv(input))
+    }
+
+    /// 查询 JSON 文件
+    pub fn json<E: Into<String>>(expression: E, input: JsonInput) -> Self {
+        Self::new(expression, InputSerialization::Json(input))
+    }
+
+    pub fn new<E: Into<String>>(expression: E, input: InputSerialization) -> Self {
+        Self {
+            expression: expression.into(),
+            compression: CompressionType::default(),
+            input,
+            output: OutputSerialization::default(),
+            skip_partial_data_record: false,
+            max_skipped_records_allowed: None,
+        }
+    }
+
+    pub fn compression(mut self, compression: CompressionType) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn output(mut self, output: OutputSerialization) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// 对应 url 中的 `x-oss-process` 参数
+    pub(crate) fn process(&self) -> &'static str {
+        match self.input {
+            InputSerialization::Csv(_) => "csv/select",
+            InputSerialization::Json(_) => "json/select",
+        }
+    }
+
+    /// 转化为 SelectObject 接口的请求体，SQL 语句及分隔符均需 base64 编码
+    /// ```
+    /// # use aliyun_oss_client::select::{JsonInput, JsonType, SelectRequest};
+    /// let input = JsonInput {
+    ///     json_type: JsonType::Lines,
+    ///     ..Default::default()
+    /// };
+    /// let xml = SelectRequest::json("select * from ossobject", input).to_xml();
+    /// assert!(xml.starts_with("<SelectRequest><Expression>c2VsZWN0ICogZnJvbSBvc3NvYmplY3Q=</Expression>"));
+    /// assert!(xml.contains("<JSON><Type>LINES</Type><ParseJsonNumberAsString>false</ParseJsonNumberAsString></JSON>"));
+    /// ```
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from("<SelectRequest>");
+        xml.push_str(&format!(
+            "<Expression>{}</Expression>",
+            encode(&self.expression)
+        ));
+
+        xml.push_str("<InputSerialization>");
+        xml.push_str(&format!(
+            "<CompressionType>{}</CompressionType>",
+            self.compression
+        ));
+        match &self.input {
+            InputSerialization::Csv(csv) => {
+                xml.push_str("<CSV>");
+                xml.push_str(&format!(
+                    "<FileHeaderInfo>{}</FileHeaderInfo>",
+                    csv.file_header_info
+                ));
+                xml.push_str(&format!(
+                    "<RecordDelimiter>{}</RecordDelimiter>",
+                    encode(&csv.record_delimiter)
+                ));
+                xml.push_str(&format!(
+                    "<FieldDelimiter>{}</FieldDelimiter>",
+                    encode(&csv.field_delimiter)
+                ));
+                xml.push_str(&format!(
+                    "<QuoteCharacter>{}</QuoteCharacter>",
+                    encode(&csv.quote_character)
+                ));
+                xml.push_str(&format!(
+                    "<CommentCharacter>{}</CommentCharacter>",
+                    encode(&csv.comment_character)
+                ));
+                if let Some(range) = &csv.range {
+                    xml.push_str(&format!("<Range>{}</Range>", range));
+                }
+                xml.push_str(&format!(
+                    "<AllowQuotedRecordDelimiter>{}</AllowQuotedRecordDelimiter>",
+                    csv.allow_quoted_record_delimiter
+                ));
+                xml.push_str("</CSV>");
+            }
+            InputSerialization::Json(json) => {
+                xml.push_str(&format!("<JSON><Type>{}</Type>", json.json_type));
+                if let Some(range) = &json.range {
+                    xml.push_str(&format!("<Range>{}</Range>", range));
+                }
+                xml.push_str(&format!(
+                    "<ParseJsonNumberAsString>{}</ParseJsonNumberAsString></JSON>",
+                    json.parse_json_number_as_string
+                ));
+            }
+        }
+        xml.push_str("</InputSerialization>");
+
+        let output = &self.output;
+        xml.push_str("<OutputSerialization>");
+        match &self.input {
+            InputSerialization::Csv(_) => xml.push_str(&format!(
+                "<CSV><RecordDelimiter>{}</RecordDelimiter><FieldDelimiter>{}</FieldDelimiter></CSV>",
+                encode(&output.record_delimiter),
+                encode(&output.field_delimiter)
+            )),
+            InputSerialization::Json(_) => xml.push_str(&format!(
+                "<JSON><RecordDelimiter>{}</RecordDelimiter></JSON>",
+                encode(&output.record_delimiter)
+            )),
+        }
+        xml.push_str(&format!(
+            "<KeepAllColumns>{}</KeepAllColumns>",
+            output.keep_all_columns
+        ));
+        // 响应需按帧解析，所以不支持输出原始数据
+        xml.push_str("<OutputRawData>false</OutputRawData>");
+        xml.push_str(&format!(
+            "<EnablePayloadCrc>{}</EnablePayloadCrc>",
+            output.enable_payload_crc
+        ));
+        xml.push_str(&format!(
+            "<OutputHeader>{}</OutputHeader>",
+            output.output_header
+        ));
+        xml.push_str("</OutputSerialization>");
+
+        xml.push_str("<Options>");
+        xml.push_str(&format!(
+            "<SkipPartialDataRecord>{}</SkipPartialDataRecord>",
+            self.skip_partial_data_record
+        ));
+        if let Some(max) = self.max_skipped_records_allowed {
+            xml.push_str(&format!(
+                "<MaxSkippedRecordsAllowed>{}</MaxSkippedRecordsAllowed>",
+                max
+            ));
+        }
+        xml.push_str("</Options>");
+
+        xml.push_str("</SelectRequest>");
+        xml
+    }
+}
+
+/// # CreateSelectObjectMeta 接口的请求参数
+/// JSON 文件仅支持 `JsonType::Lines` 格式
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectMetaRequest {
+    pub compression: CompressionType,
+    pub input: InputSerialization,
+    pub overwrite_if_exists: bool,
+}
+
+impl SelectMetaRequest {
+    pub fn new(input: InputSerialization) -> Self {
+        Self {
+            input,
+            ..Default::default()
+        }
+    }
+
+    /// 对应 url 中的 `x-oss-process` 参数
+    pub(crate) fn process(&self) -> &'static str {
+        match self.input {
+            InputSerialization::Csv(_) => "csv/meta",
+            InputSerialization::Json(_) => "json/meta",
+        }
+    }
+
+    /// 转化为 CreateSelectObjectMeta 接口的请求体
+    /// ```
+    /// # use aliyun_oss_client::select::{CsvInput, InputSerialization, SelectMetaRequest};
+    /// let xml = SelectMetaRequest::new(InputSerialization::Csv(CsvInput::default())).to_xml();
+    /// assert_eq!(
+    ///     xml,
+    ///     "<CsvMetaRequest><InputSerialization><CompressionType>None</CompressionType>\
+    ///     <CSV><RecordDelimiter>Cg==</RecordDelimiter><FieldDelimiter>LA==</FieldDelimiter>\
+    ///     <QuoteCharacter>Ig==</QuoteCharacter></CSV></InputSerialization>\
+    ///     <OverwriteIfExists>false</OverwriteIfExists></CsvMetaRequest>"
+    /// );
+    /// ```
+    pub fn to_xml(&self) -> String {
+        let (root, input) = match &self.input {
+            InputSerialization::Csv(csv) => (
+                "CsvMetaRequest",
+                format!(
+                    "<CSV><RecordDelimiter>{}</RecordDelimiter><FieldDelimiter>{}</FieldDelimiter><QuoteCharacter>{}</QuoteCharacter></CSV>",
+                    encode(&csv.record_delimiter),
+                    encode(&csv.field_delimiter),
+                    encode(&csv.quote_character)
+                ),
+            ),
+            InputSerialization::Json(json) => (
+                "JsonMetaRequest",
+                format!("<JSON><Type>{}</Type></JSON>", json.json_type),
+            ),
+        };
+
+        format!(
+            "<{root}><InputSerialization><CompressionType>{}</CompressionType>{input}</InputSerialization><OverwriteIfExists>{}</OverwriteIfExists></{root}>",
+            self.compression, self.overwrite_if_exists
+        )
+    }
+}
+
+/// # CreateSelectObjectMeta 接口返回的文件元信息
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectObjectMeta {
+    pub total_scanned_bytes: u64,
+    pub status: u32,
+    pub splits_count: u32,
+    pub rows_count: u64,
+    /// 仅 CSV 文件有此项
+    pub columns_count: Option<u32>,
+    pub error_message: String,
+}
+
+/// 数据帧
+const FRAME_DATA: u32 = 8_388_609;
+/// 保持连接的心跳帧
+const FRAME_CONTINUOUS: u32 = 8_388_612;
+/// 结束帧
+const FRAME_END: u32 = 8_388_613;
+/// CSV 元信息结束帧
+const FRAME_META_END_CSV: u32 = 8_388_614;
+/// JSON 元信息结束帧
+const FRAME_META_END_JSON: u32 = 8_388_615;
+
+/// 帧头由 版本号及帧类型、payload 长度、帧头校验值 三部分组成，各占 4 字节
+const FRAME_HEADER_LEN: usize = 12;
+const FRAME_CHECKSUM_LEN: usize = 4;
+/// payload 的前 8 字节为当前扫描的偏移量
+const FRAME_OFFSET_LEN: usize = 8;
+
+/// # 解析 select 响应时的错误
+#[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SelectError {
+    #[error("invalid select frame")]
+    InvalidFrame,
+
+    #[error("unknown select frame type: {0}")]
+    UnknownFrameType(u32),
+
+    #[error("select frame payload checksum mismatch")]
+    Checksum,
+
+    #[error("select response ended without end frame")]
+    UnexpectedEof,
+
+    #[error("select failed, status: {status}, message: {message}")]
+    Service { status: u32, message: String },
+}
+
+/// 结束帧中的查询结果
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct SelectEnd {
+    pub(crate) total_scanned_bytes: u64,
+    pub(crate) status: u32,
+    pub(crate) error_message: String,
+}
+
+impl SelectEnd {
+    fn check(self) -> Result<(), SelectError> {
+        check_status(self.status, self.error_message)
+    }
+}
+
+fn check_status(status: u32, message: String) -> Result<(), SelectError> {
+    if status >= 400 {
+        Err(SelectError::Service { status, message })
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum SelectFrame {
+    Data(Vec<u8>),
+    Continuous,
+    End(SelectEnd),
+    MetaEnd(SelectObjectMeta),
+}
+
+/// # select 响应的帧解析器
+/// 响应内容可能分多次到达，未凑齐一帧时返回 `Ok(None)`
+#[derive(Debug, Default)]
+pub(crate) struct FrameDecoder {
+    buf: Vec<u8>,
+}
+
+impl FrameDecoder {
+    pub(crate) fn extend(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    pub(crate) fn next_frame(&mut self) -> Result<Option<SelectFrame>, SelectError> {
+        if self.buf.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+
+        // 首字节为版本号
+        let frame_type = read_u32(&self.buf[0..4]) & 0x00FF_FFFF;
+        let payload_len = read_u32(&self.buf[4..8]) as usize;
+        let frame_len = FRAME_HEADER_LEN + payload_len + FRAME_CHECKSUM_LEN;
+        if self.buf.len() < frame_len {
+            return Ok(None);
+        }
+
+        let frame: Vec<u8> = self.buf.drain(..frame_len).collect();
+        let payload = &frame[FRAME_HEADER_LEN..FRAME_HEADER_LEN + payload_len];
+        let checksum = read_u32(&frame[FRAME_HEADER_LEN + payload_len..]);
+        // 未开启 EnablePayloadCrc 时校验值为 0
+        if checksum != 0 && checksum != crc32fast::hash(payload) {
+            return Err(SelectError::Checksum);
+        }
+
+        if payload.len() < FRAME_OFFSET_LEN {
+            return Err(SelectError::InvalidFrame);
+        }
+        let payload = &payload[FRAME_OFFSET_LEN..];
+
+        let frame = match frame_type {
+            FRAME_DATA => SelectFrame::Data(payload.to_vec()),
+            FRAME_CONTINUOUS => SelectFrame::Continuous,
+            FRAME_END => {
+                if payload.len() < 12 {
+                    return Err(SelectError::InvalidFrame);
+                }
+                SelectFrame::End(SelectEnd {
+                    total_scanned_bytes: read_u64(&payload[0..8]),
+                    status: read_u32(&payload[8..12]),
+                    error_message: String::from_utf8_lossy(&payload[12..]).into_owned(),
+                })
+            }
+            FRAME_META_END_CSV => {
+                if payload.len() < 28 {
+                    return Err(SelectError::InvalidFrame);
+                }
+                SelectFrame::MetaEnd(SelectObjectMeta {
+                    total_scanned_bytes: read_u64(&payload[0..8]),
+                    status: read_u32(&payload[8..12]),
+                    splits_count: read_u32(&payload[12..16]),
+                    rows_count: read_u64(&payload[16..24]),
+                    columns_count: Some(read_u32(&payload[24..28])),
+                    error_message: String::from_utf8_lossy(&payload[28..]).into_owned(),
+                })
+            }
+            FRAME_META_END_JSON => {
+                if payload.len() < 24 {
+                    return Err(SelectError::InvalidFrame);
+                }
+                SelectFrame::MetaEnd(SelectObjectMeta {
+                    total_scanned_bytes: read_u64(&payload[0..8]),
+                    status: read_u32(&payload[8..12]),
+                    splits_count: read_u32(&payload[12..16]),
+                    rows_count: read_u64(&payload[16..24]),
+                    columns_count: None,
+                    error_message: String::from_utf8_lossy(&payload[24..]).into_owned(),
+                })
+            }
+            other => return Err(SelectError::UnknownFrameType(other)),
+        };
+
+        Ok(Some(frame))
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&bytes[..4]);
+    u32::from_be_bytes(buf)
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(buf)
+}
+
+/// 按 select 响应的格式编码一帧，用于测试
+#[cfg(test)]
+pub(crate) fn encode_frame(frame_type: u32, payload: &[u8]) -> Vec<u8> {
+    let mut frame = (frame_type | 0x0100_0000).to_be_bytes().to_vec();
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&0u32.to_be_bytes());
+    frame.extend_from_slice(payload);
+    frame.extend_from_slice(&crc32fast::hash(payload).to_be_bytes());
+    frame
+}
+
+/// 从 CreateSelectObjectMeta 接口的响应中解析元信息
+pub(crate) fn decode_select_meta(content: &[u8]) -> OssResult<SelectObjectMeta> {
+    let mut decoder = FrameDecoder::default();
+    decoder.extend(content);
+
+    loop {
+        match decoder.next_frame()? {
+            Some(SelectFrame::MetaEnd(meta)) => {
+                check_status(meta.status, meta.error_message.clone())?;
+                return Ok(meta);
+            }
+            Some(_) => (),
+            None => return Err(SelectError::UnexpectedEof.into()),
+        }
+    }
+}
+
+/// # SelectObject 接口的响应
+pub struct SelectObjectResponse {
+    response: Response,
+    decoder: FrameDecoder,
+}
+
+impl fmt::Debug for SelectObjectResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SelectObjectResponse")
+            .field("response", &self.response)
+            .finish()
+    }
+}
+
+impl SelectObjectResponse {
+    pub(crate) fn new(response: Response) -> Self {
+        Self {
+            response,
+            decoder: FrameDecoder::default(),
+        }
+    }
+
+    /// # 将查询结果转化为 stream，每一项为一批记录
+    ///
+    /// ```no_run
+    /// # use aliyun_oss_client::Client;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aliyun_oss_client::file::File;
+    /// use aliyun_oss_client::select::{CsvInput, SelectRequest};
+    /// use futures::{pin_mut, StreamExt};
+    /// # let client = Client::from_env().unwrap();
+    ///
+    /// let request = SelectRequest::csv("select * from ossobject", CsvInput::default());
+    /// let response = client.select_object("data.csv", request).await.unwrap();
+    /// let stream = response.into_stream();
+    /// pin_mut!(stream);
+    ///
+    /// while let Some(records) = stream.next().await {
+    ///     println!("{}", String::from_utf8_lossy(&records.unwrap()));
+    /// }
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> impl Stream<Item = OssResult<Vec<u8>>> {
+        let Self {
+            mut response,
+            mut decoder,
+        } = self;
+
+        try_stream! {
+            loop {
+                match decoder.next_frame()? {
+                    Some(SelectFrame::Data(records)) => yield records,
+                    Some(SelectFrame::End(end)) => {
+                        end.check()?;
+                        break;
+                    }
+                    Some(_) => (),
+                    None => match response.chunk().await? {
+                        Some(chunk) => decoder.extend(&chunk),
+                        None => Err(SelectError::UnexpectedEof)?,
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode_select_meta, encode_frame, FrameDecoder, SelectEnd, SelectError, SelectFrame,
+        FRAME_CONTINUOUS, FRAME_DATA, FRAME_END, FRAME_META_END_CSV,
+    };
+
+    fn data_payload(records: &[u8]) -> Vec<u8> {
+        let mut payload = 10u64.to_be_bytes().to_vec();
+        payload.extend_from_slice(records);
+        payload
+    }
+
+    fn end_payload(status: u32, message: &str) -> Vec<u8> {
+        let mut payload = 20u64.to_be_bytes().to_vec();
+        payload.extend_from_slice(&20u64.to_be_bytes());
+        payload.extend_from_slice(&status.to_be_bytes());
+        payload.extend_from_slice(message.as_bytes());
+        payload
+    }
+
+    #[test]
+    fn test_decode_frames() {
+        let mut content = encode_frame(FRAME_DATA, &data_payload(b"1,foo\n"));
+        content.extend(encode_frame(FRAME_CONTINUOUS, &10u64.to_be_bytes()));
+        content.extend(encode_frame(FRAME_END, &end_payload(200, "")));
+
+        let mut decoder = FrameDecoder::default();
+        // 分两次到达
+        decoder.extend(&content[..10]);
+        assert_eq!(decoder.next_frame(), Ok(None));
+        decoder.extend(&content[10..]);
+
+        assert_eq!(
+            decoder.next_frame(),
+            Ok(Some(SelectFrame::Data(b"1,foo\n".to_vec())))
+        );
+        assert_eq!(decoder.next_frame(), Ok(Some(SelectFrame::Continuous)));
+        assert_eq!(
+            decoder.next_frame(),
+            Ok(Some(SelectFrame::End(SelectEnd {
+                total_scanned_bytes: 20,
+                status: 200,
+                error_message: String::new(),
+            })))
+        );
+        assert_eq!(decoder.next_frame(), Ok(None));
+    }
+
+    #[test]
+    fn test_decode_frame_checksum() {
+        let mut content = encode_frame(FRAME_DATA, &data_payload(b"1,foo\n"));
+        let len = content.len();
+        content[len - 1] ^= 0xff;
+
+        let mut decoder = FrameDecoder::default();
+        decoder.extend(&content);
+        assert_eq!(decoder.next_frame(), Err(SelectError::Checksum));
+
+        let content = encode_frame(0x7f, &data_payload(b""));
+        let mut decoder = FrameDecoder::default();
+        decoder.extend(&content);
+        assert_eq!(
+            decoder.next_frame(),
+            Err(SelectError::UnknownFrameType(0x7f))
+        );
+    }
+
+    #[test]
+    fn test_decode_select_meta() {
+        let mut payload = 20u64.to_be_bytes().to_vec();
+        payload.extend_from_slice(&20u64.to_be_bytes());
+        payload.extend_from_slice(&200u32.to_be_bytes());
+        payload.extend_from_slice(&1u32.to_be_bytes());
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        payload.extend_from_slice(&3u32.to_be_bytes());
+        let content = encode_frame(FRAME_META_END_CSV, &payload);
+
+        let meta = decode_select_meta(&content).unwrap();
+        assert_eq!(meta.splits_count, 1);
+        assert_eq!(meta.rows_count, 100);
+        assert_eq!(meta.columns_count, Some(3));
+
+        let content = encode_frame(FRAME_END, &end_payload(200, ""));
+        assert!(decode_select_meta(&content).is_err());
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::{fmt, io::Read};
+
+    use reqwest::blocking::Response;
+
+    use super::{FrameDecoder, SelectError, SelectFrame};
+    use crate::errors::OssResult;
+
+    /// # SelectObject 接口的响应
+    /// 迭代的每一项为一批记录
+    pub struct SelectObjectResponse {
+        response: Response,
+        decoder: FrameDecoder,
+        finished: bool,
+    }
+
+    impl fmt::Debug for SelectObjectResponse {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("SelectObjectResponse")
+                .field("response", &self.response)
+                .field("finished", &self.finished)
+                .finish()
+        }
+    }
+
+    impl SelectObjectResponse {
+        pub(crate) fn new(response: Response) -> Self {
+            Self {
+                response,
+                decoder: FrameDecoder::default(),
+                finished: false,
+            }
+        }
+    }
+
+    impl Iterator for SelectObjectResponse {
+        type Item = OssResult<Vec<u8>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished {
+                return None;
+            }
+
+            loop {
+                match self.decoder.next_frame() {
+                    Ok(Some(SelectFrame::Data(records))) => return Some(Ok(records)),
+                    Ok(Some(SelectFrame::End(end))) => {
+                        self.finished = true;
+                        return end.check().err().map(|e| Err(e.into()));
+                    }
+                    Ok(Some(_)) => (),
+                    Ok(None) => {
+                        let mut chunk = [0u8; 8192];
+                        match self.response.read(&mut chunk) {
+                            Ok(0) => {
+                                self.finished = true;
+                                return Some(Err(SelectError::UnexpectedEof.into()));
+                            }
+                            Ok(len) => self.decoder.extend(&chunk[..len]),
+                            Err(e) => {
+                                self.finished = true;
+                                return Some(Err(e.into()));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        self.finished = true;
+                        return Some(Err(e.into()));
+                    }
+                }
+            }
+        }
+    }
+}
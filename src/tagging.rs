@@ -0,0 +1,289 @@
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket, client::ClientArc, errors::OssResult, file::AlignBuilder,
+    types::CanonicalizedResource,
+};
+
+pub(crate) const TAGGING: &str = "tagging";
+
+const TAG: &[u8] = b"Tag";
+const KEY: &[u8] = b"Key";
+const VALUE: &[u8] = b"Value";
+
+/// # Bucket 及文件的标签
+/// 一个 bucket 最多设置 20 个标签，一个文件最多设置 10 个标签，key 不可重复
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tagging {
+    pub tags: Vec<(String, String)>,
+}
+
+impl Tagging {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加标签，可多次调用，key 已存在时覆盖原有的值
+    pub fn tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let (key, value) = (key.into(), value.into());
+        match self.tags.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.tags.push((key, value)),
+        }
+        self
+    }
+
+    /// 获取标签的值
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// 转化为 PutBucketTags、PutObjectTagging 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        let tags: String = self
+            .tags
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+                    escape(key),
+                    escape(value)
+                )
+            })
+            .collect();
+        format!("<Tagging><TagSet>{tags}</TagSet></Tagging>")
+    }
+
+    /// 解析 GetBucketTags、GetObjectTagging 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut tagging = Self::default();
+        let mut tag = (String::new(), String::new());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => {
+                    if let name @ (KEY | VALUE) = e.name().as_ref() {
+                        let raw = reader.read_text(e.to_end().name())?;
+                        let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                        if name == KEY {
+                            tag.0 = text.into_owned();
+                        } else {
+                            tag.1 = text.into_owned();
+                        }
+                    }
+                }
+                Event::End(e) if e.name().as_ref() == TAG => {
+                    tagging.tags.push(std::mem::take(&mut tag));
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(tagging)
+    }
+}
+
+pub(crate) async fn get_tagging<B: AlignBuilder + ?Sized>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Tagging> {
+    let content = builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await?;
+
+    Tagging::from_xml(&content)
+}
+
+pub(crate) async fn put_tagging<B: AlignBuilder + ?Sized>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    tagging: Tagging,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(tagging.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete_tagging<B: AlignBuilder + ?Sized>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的标签
+    pub async fn get_bucket_tagging(&self) -> OssResult<Tagging> {
+        get_tagging(self, self.get_bucket_sub_resource(TAGGING)).await
+    }
+
+    /// # 设置默认 bucket 的标签
+    /// 会覆盖已有的全部标签
+    pub async fn put_bucket_tagging(&self, tagging: Tagging) -> OssResult<()> {
+        put_tagging(self, self.get_bucket_sub_resource(TAGGING), tagging).await
+    }
+
+    /// # 删除默认 bucket 的全部标签
+    pub async fn delete_bucket_tagging(&self) -> OssResult<()> {
+        delete_tagging(self, self.get_bucket_sub_resource(TAGGING)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的标签
+    pub async fn get_bucket_tagging(&self) -> OssResult<Tagging> {
+        get_tagging(self, self.base.get_sub_resource(TAGGING)).await
+    }
+
+    /// # 设置 bucket 的标签
+    /// 会覆盖已有的全部标签
+    pub async fn put_bucket_tagging(&self, tagging: Tagging) -> OssResult<()> {
+        put_tagging(self, self.base.get_sub_resource(TAGGING), tagging).await
+    }
+
+    /// # 删除 bucket 的全部标签
+    pub async fn delete_bucket_tagging(&self) -> OssResult<()> {
+        delete_tagging(self, self.base.get_sub_resource(TAGGING)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub(crate) mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::Tagging;
+    use crate::{errors::OssResult, file::blocking::AlignBuilder, types::CanonicalizedResource};
+
+    pub(crate) fn get_tagging<B: AlignBuilder + ?Sized>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Tagging> {
+        let content = builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        Tagging::from_xml(&content)
+    }
+
+    pub(crate) fn put_tagging<B: AlignBuilder + ?Sized>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        tagging: Tagging,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(tagging.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(crate) fn delete_tagging<B: AlignBuilder + ?Sized>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的标签
+    pub fn get_bucket_tagging(&self) -> OssResult<Tagging> {
+        blocking::get_tagging(self, self.get_bucket_sub_resource(TAGGING))
+    }
+
+    /// # 设置默认 bucket 的标签
+    /// 会覆盖已有的全部标签
+    pub fn put_bucket_tagging(&self, tagging: Tagging) -> OssResult<()> {
+        blocking::put_tagging(self, self.get_bucket_sub_resource(TAGGING), tagging)
+    }
+
+    /// # 删除默认 bucket 的全部标签
+    pub fn delete_bucket_tagging(&self) -> OssResult<()> {
+        blocking::delete_tagging(self, self.get_bucket_sub_resource(TAGGING))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的标签
+    pub fn get_bucket_tagging(&self) -> OssResult<Tagging> {
+        blocking::get_tagging(self, self.base.get_sub_resource(TAGGING))
+    }
+
+    /// # 设置 bucket 的标签
+    /// 会覆盖已有的全部标签
+    pub fn put_bucket_tagging(&self, tagging: Tagging) -> OssResult<()> {
+        blocking::put_tagging(self, self.base.get_sub_resource(TAGGING), tagging)
+    }
+
+    /// # 删除 bucket 的全部标签
+    pub fn delete_bucket_tagging(&self) -> OssResult<()> {
+        blocking::delete_tagging(self, self.base.get_sub_resource(TAGGING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tagging;
+
+    #[test]
+    fn test_tagging_xml() {
+        let tagging = Tagging::new()
+            .tag("owner", "tenant-a")
+            .tag("env", "dev")
+            .tag("env", "a&b");
+        assert_eq!(tagging.get("env"), Some("a&b"));
+        assert_eq!(tagging.get("foo"), None);
+
+        let xml = tagging.to_xml();
+        assert_eq!(
+            xml,
+            "<Tagging><TagSet><Tag><Key>owner</Key><Value>tenant-a</Value></Tag>\
+            <Tag><Key>env</Key><Value>a&amp;b</Value></Tag></TagSet></Tagging>"
+        );
+        assert_eq!(Tagging::from_xml(&xml).unwrap(), tagging);
+
+        let empty = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Tagging><TagSet/></Tagging>"#;
+        assert_eq!(Tagging::from_xml(empty).unwrap(), Tagging::default());
+    }
+}
@@ -428,3 +428,1819 @@ fn test_get_blocking_object_list() {
         r##"Ok(ObjectList { bucket: BucketBase { endpoint: CnShanghai, name: BucketName("abc") }, prefix: "", max_keys: 100, key_count: 23, next_continuation_token: None, search_query: Query { inner: {MaxKeys: QueryValue("5")} } })"##
     );
 }
+
+#[tokio::test]
+async fn test_get_bucket_encryption() {
+    use crate::object::{Encryption, ServerSideEncryption};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?encryption"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?encryption").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ServerSideEncryptionRule>
+                  <ApplyServerSideEncryptionByDefault>
+                    <SSEAlgorithm>KMS</SSEAlgorithm>
+                    <KMSDataEncryption>SM4</KMSDataEncryption>
+                    <KMSMasterKeyID>9468da86-3509-4f8d-a61e-6eab1eac****</KMSMasterKeyID>
+                  </ApplyServerSideEncryptionByDefault>
+                </ServerSideEncryptionRule>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client.get_bucket_encryption().await.unwrap();
+    assert_eq!(
+        res,
+        Some(
+            ServerSideEncryption::new(Encryption::Kms)
+                .data_encryption(Encryption::Sm4)
+                .key_id("9468da86-3509-4f8d-a61e-6eab1eac****")
+        )
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_bucket_encryption() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::object::{Encryption, ServerSideEncryption};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?encryption").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<ServerSideEncryptionRule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>AES256</SSEAlgorithm></ApplyServerSideEncryptionByDefault></ServerSideEncryptionRule>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.put_bucket_encryption(ServerSideEncryption::new(Encryption::Aes256));
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_object_versions() {
+    use crate::versioning::KEY_MARKER;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?versions&key-marker=a+b.txt&max-keys=2"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?versions").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListVersionsResult>
+                    <Name>foo4</Name>
+                    <IsTruncated>false</IsTruncated>
+                    <Version>
+                        <Key>c.txt</Key>
+                        <VersionId>CAEQabc</VersionId>
+                        <IsLatest>true</IsLatest>
+                        <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                        <ETag>"250F8A0AE989679A22926A875F0A2****"</ETag>
+                        <Size>10</Size>
+                        <StorageClass>Standard</StorageClass>
+                    </Version>
+                </ListVersionsResult>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client
+        .get_object_versions([
+            (KEY_MARKER.into(), "a b.txt".into()),
+            ("max-keys".into(), 2u8.into()),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(res.versions.len(), 1);
+    assert_eq!(res.versions[0].version_id, "CAEQabc");
+    assert!(res.next_query().is_none());
+}
+
+#[tokio::test]
+async fn test_bucket_version_stream() {
+    use futures::{pin_mut, StreamExt};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/abc/?versions").unwrap())
+            );
+            let (truncated, key) = match request.url().query() {
+                Some("versions&max-keys=1") => (
+                    "<IsTruncated>true</IsTruncated><NextKeyMarker>a.txt</NextKeyMarker><NextVersionIdMarker>CAEQa</NextVersionIdMarker>",
+                    "a.txt",
+                ),
+                Some("versions&key-marker=a.txt&max-keys=1&version-id-marker=CAEQa") => {
+                    ("<IsTruncated>false</IsTruncated>", "b.txt")
+                }
+                q => panic!("unexpected query {:?}", q),
+            };
+            let body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListVersionsResult>
+                    <Name>abc</Name>
+                    {truncated}
+                    <Version>
+                        <Key>{key}</Key>
+                        <VersionId>CAEQa</VersionId>
+                        <IsLatest>true</IsLatest>
+                        <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                        <ETag>"250F8A0AE989679A22926A875F0A2****"</ETag>
+                        <Size>10</Size>
+                        <StorageClass>Standard</StorageClass>
+                    </Version>
+                </ListVersionsResult>"#
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let bucket = Bucket::<ArcPointer>::new(
+        "abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        DateTime::<Utc>::default(),
+        String::from("foo1"),
+        String::from("foo2"),
+        Arc::new(client),
+    );
+
+    let list = bucket
+        .get_object_versions([("max-keys".into(), 1u8.into())])
+        .await
+        .unwrap();
+    let stream = list.into_version_stream();
+    pin_mut!(stream);
+
+    let mut keys = Vec::new();
+    while let Some(version) = stream.next().await {
+        keys.push(version.unwrap().key);
+    }
+    assert_eq!(keys, vec!["a.txt", "b.txt"]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_object_version_iter() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::errors::OssError;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            let (truncated, key) = match request.url().query() {
+                Some("versions&max-keys=1") => (
+                    "<IsTruncated>true</IsTruncated><NextKeyMarker>a.txt</NextKeyMarker>",
+                    "a.txt",
+                ),
+                Some("versions&key-marker=a.txt&max-keys=1") => {
+                    ("<IsTruncated>false</IsTruncated>", "b.txt")
+                }
+                q => panic!("unexpected query {:?}", q),
+            };
+            let body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListVersionsResult>
+                    <Name>foo4</Name>
+                    {truncated}
+                    <DeleteMarker>
+                        <Key>{key}</Key>
+                        <VersionId>CAEQa</VersionId>
+                        <IsLatest>true</IsLatest>
+                        <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                    </DeleteMarker>
+                </ListVersionsResult>"#
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let list = client
+        .get_object_versions([("max-keys".into(), 1u8.into())])
+        .unwrap();
+    let last = list.get_next_list().unwrap();
+    assert!(matches!(last.get_next_list(), Err(OssError::WithoutMore)));
+
+    let keys: Vec<_> = list.into_version_iter().map(|v| v.unwrap().key).collect();
+    assert_eq!(keys, vec!["a.txt", "b.txt"]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_bucket_versioning() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::versioning::VersioningStatus;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?versioning").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<VersioningConfiguration><Status>Enabled</Status></VersioningConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.put_bucket_versioning(VersioningStatus::Enabled);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_bucket_stream() {
+    use crate::bucket::RESOURCE_GROUP_ID;
+    use futures::{pin_mut, StreamExt};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("x-oss-resource-group-id"),
+                Some(&HeaderValue::from_str("rg-abc").unwrap())
+            );
+            let (truncated, name) = match request.url().query() {
+                Some("max-keys=1&prefix=foo") => (
+                    "<IsTruncated>true</IsTruncated><NextMarker>foo1</NextMarker>",
+                    "foo1",
+                ),
+                Some("marker=foo1&max-keys=1&prefix=foo") => {
+                    ("<IsTruncated>false</IsTruncated>", "foo2")
+                }
+                q => panic!("unexpected query {:?}", q),
+            };
+            let body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListAllMyBucketsResult>
+                  <Prefix>foo</Prefix>
+                  <MaxKeys>1</MaxKeys>
+                  {truncated}
+                  <Buckets>
+                    <Bucket>
+                      <CreationDate>2020-09-13T03:14:54.000Z</CreationDate>
+                      <ExtranetEndpoint>oss-cn-shanghai.aliyuncs.com</ExtranetEndpoint>
+                      <IntranetEndpoint>oss-cn-shanghai-internal.aliyuncs.com</IntranetEndpoint>
+                      <Location>oss-cn-shanghai</Location>
+                      <Name>{name}</Name>
+                      <Region>cn-shanghai</Region>
+                      <StorageClass>Standard</StorageClass>
+                    </Bucket>
+                  </Buckets>
+                </ListAllMyBucketsResult>"#
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let stream = client.bucket_stream([
+        ("prefix".into(), "foo".into()),
+        ("max-keys".into(), 1u8.into()),
+        (RESOURCE_GROUP_ID.into(), "rg-abc".into()),
+    ]);
+    pin_mut!(stream);
+
+    let mut names = Vec::new();
+    while let Some(bucket) = stream.next().await {
+        names.push(bucket.unwrap().base.name().to_string());
+    }
+    assert_eq!(names, vec!["foo1", "foo2"]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_bucket_iter() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            let (truncated, name) = match request.url().query() {
+                Some("max-keys=1") => (
+                    "<IsTruncated>true</IsTruncated><NextMarker>foo1</NextMarker>",
+                    "foo1",
+                ),
+                Some("marker=foo1&max-keys=1") => ("<IsTruncated>false</IsTruncated>", "foo2"),
+                q => panic!("unexpected query {:?}", q),
+            };
+            let body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListAllMyBucketsResult>
+                  {truncated}
+                  <Buckets>
+                    <Bucket>
+                      <CreationDate>2020-09-13T03:14:54.000Z</CreationDate>
+                      <ExtranetEndpoint>oss-cn-shanghai.aliyuncs.com</ExtranetEndpoint>
+                      <IntranetEndpoint>oss-cn-shanghai-internal.aliyuncs.com</IntranetEndpoint>
+                      <Location>oss-cn-shanghai</Location>
+                      <Name>{name}</Name>
+                      <Region>cn-shanghai</Region>
+                      <StorageClass>Standard</StorageClass>
+                    </Bucket>
+                  </Buckets>
+                </ListAllMyBucketsResult>"#
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let names: Vec<_> = client
+        .bucket_iter([("max-keys".into(), 1u8.into())])
+        .unwrap()
+        .map(|b| b.unwrap().base.name().to_string())
+        .collect();
+    assert_eq!(names, vec!["foo1", "foo2"]);
+}
+
+#[tokio::test]
+async fn test_put_bucket() {
+    use crate::bucket::{CreateBucketConfiguration, DataRedundancyType, Grant};
+    use crate::object::StorageClass;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                *request.url(),
+                "https://tenant-a.oss-cn-shanghai.aliyuncs.com/"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/tenant-a/").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-acl"),
+                Some(&HeaderValue::from_str("public-read").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-resource-group-id"),
+                Some(&HeaderValue::from_str("rg-abc").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<CreateBucketConfiguration><StorageClass>IA</StorageClass><DataRedundancyType>ZRS</DataRedundancyType></CreateBucketConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let config = CreateBucketConfiguration::new()
+        .acl(Grant::PublicRead)
+        .storage_class(StorageClass::IA)
+        .data_redundancy_type(DataRedundancyType::ZRS)
+        .resource_group_id("rg-abc");
+    let res = client
+        .put_bucket("tenant-a".parse::<crate::BucketName>().unwrap(), config)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_delete_bucket() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "DELETE");
+            assert_eq!(
+                *request.url(),
+                "https://tenant-a.oss-cn-shanghai.aliyuncs.com/"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/tenant-a/").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(204).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.delete_bucket("tenant-a".parse::<crate::BucketName>().unwrap());
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_bucket_stat() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?stat"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?stat").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <BucketStat>
+                  <Storage>1600</Storage>
+                  <ObjectCount>5000000000</ObjectCount>
+                  <MultipartUploadCount>40</MultipartUploadCount>
+                  <LiveChannelCount>4</LiveChannelCount>
+                  <LastModifiedTime>1643341269</LastModifiedTime>
+                  <StandardStorage>430</StandardStorage>
+                  <StandardObjectCount>66</StandardObjectCount>
+                  <InfrequentAccessStorage>2359296</InfrequentAccessStorage>
+                  <InfrequentAccessRealStorage>360</InfrequentAccessRealStorage>
+                  <InfrequentAccessObjectCount>54</InfrequentAccessObjectCount>
+                  <ArchiveStorage>2949120</ArchiveStorage>
+                  <ArchiveRealStorage>450</ArchiveRealStorage>
+                  <ArchiveObjectCount>74</ArchiveObjectCount>
+                  <ColdArchiveStorage>2359296</ColdArchiveStorage>
+                  <ColdArchiveRealStorage>360</ColdArchiveRealStorage>
+                  <ColdArchiveObjectCount>36</ColdArchiveObjectCount>
+                  <DeepColdArchiveStorage>2359296</DeepColdArchiveStorage>
+                </BucketStat>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let stat = client.get_bucket_stat().await.unwrap();
+    assert_eq!(stat.storage, 1600);
+    assert_eq!(stat.object_count, 5_000_000_000);
+    assert_eq!(stat.multipart_upload_count, 40);
+    assert_eq!(stat.live_channel_count, 4);
+    assert_eq!(stat.last_modified_time, 1643341269);
+    assert_eq!(stat.standard_object_count, 66);
+    assert_eq!(stat.infrequent_access_real_storage, 360);
+    assert_eq!(stat.archive_object_count, 74);
+    assert_eq!(stat.cold_archive_storage, 2359296);
+    assert_eq!(stat.cold_archive_object_count, 36);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_stat() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?stat").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body("<BucketStat><Storage>1600</Storage><ObjectCount>foo</ObjectCount></BucketStat>")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.get_bucket_stat();
+    assert!(matches!(
+        res,
+        Err(crate::errors::OssError::ParseIntError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_bucket_acl() {
+    use crate::bucket::Grant;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?acl").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <AccessControlPolicy>
+                    <Owner>
+                        <ID>0022012****</ID>
+                        <DisplayName>user_example</DisplayName>
+                    </Owner>
+                    <AccessControlList>
+                        <Grant>public-read</Grant>
+                    </AccessControlList>
+                </AccessControlPolicy>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let acl = client.get_bucket_acl().await.unwrap();
+    assert_eq!(acl, Grant::PublicRead);
+}
+
+#[tokio::test]
+async fn test_discover_endpoint() {
+    use crate::EndPoint;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?location"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?location").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <LocationConstraint>oss-cn-qingdao</LocationConstraint>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let mut client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let endpoint = client.discover_endpoint().await.unwrap();
+    assert_eq!(endpoint, EndPoint::CnQingdao);
+    assert_eq!(
+        client.get_bucket_url(),
+        "https://foo4.oss-cn-qingdao.aliyuncs.com/".parse().unwrap()
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_bucket_acl() {
+    use crate::blocking::builder::Middleware;
+    use crate::bucket::Grant;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?acl").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-acl"),
+                Some(&HeaderValue::from_str("private").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.put_bucket_acl(Grant::Private);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_bucket_lifecycle() {
+    use crate::lifecycle::{Expiration, LifecycleTime, Transition};
+    use crate::object::StorageClass;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?lifecycle"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?lifecycle").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <LifecycleConfiguration>
+                  <Rule>
+                    <ID>archive-log</ID>
+                    <Prefix>log/</Prefix>
+                    <Status>Enabled</Status>
+                    <Transition>
+                      <Days>30</Days>
+                      <StorageClass>IA</StorageClass>
+                      <IsAccessTime>true</IsAccessTime>
+                      <ReturnToStdWhenVisit>false</ReturnToStdWhenVisit>
+                    </Transition>
+                    <Expiration>
+                      <Days>365</Days>
+                    </Expiration>
+                  </Rule>
+                </LifecycleConfiguration>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let rules = client.get_bucket_lifecycle().await.unwrap();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].id.as_deref(), Some("archive-log"));
+    assert!(rules[0].enabled);
+    assert_eq!(rules[0].expiration, Some(Expiration::Days(365)));
+    assert_eq!(
+        rules[0].transitions,
+        vec![Transition::new(LifecycleTime::Days(30), StorageClass::IA).access_time(false)]
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_bucket_lifecycle() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::lifecycle::{LifecycleRule, LifecycleTime};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?lifecycle").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<LifecycleConfiguration><Rule><Prefix></Prefix><Status>Enabled</Status><AbortMultipartUpload><Days>3</Days></AbortMultipartUpload></Rule></LifecycleConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let rule = LifecycleRule::new("").abort_multipart_upload(LifecycleTime::Days(3));
+    let res = client.put_bucket_lifecycle(&[rule]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_bucket_get_bucket_lifecycle() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/abc/?lifecycle").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(404)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <Error>
+                  <Code>NoSuchLifecycle</Code>
+                  <Message>No Row found in Lifecycle Table.</Message>
+                  <RequestId>5C4A3F2E8E9F3A0B2A5D1234</RequestId>
+                </Error>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let bucket = Bucket::<ArcPointer>::new(
+        "abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        Utc::now(),
+        String::from("foo1"),
+        String::from("foo2"),
+        Arc::new(client),
+    );
+
+    let rules = bucket.get_bucket_lifecycle().await.unwrap();
+    assert!(rules.is_empty());
+}
+
+#[tokio::test]
+async fn test_put_bucket_cors() {
+    use crate::cors::CorsRule;
+    use http::Method;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?cors").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<CORSConfiguration><CORSRule><AllowedOrigin>*</AllowedOrigin><AllowedMethod>PUT</AllowedMethod><AllowedHeader>*</AllowedHeader></CORSRule></CORSConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let rule = CorsRule::new().origin("*").method(Method::PUT).header("*");
+    let res = client.put_bucket_cors(&[rule]).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_cors() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?cors").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <CORSConfiguration>
+                    <CORSRule>
+                      <AllowedOrigin>*</AllowedOrigin>
+                      <AllowedMethod>GET</AllowedMethod>
+                      <AllowedHeader>*</AllowedHeader>
+                      <ExposeHeader>x-oss-test</ExposeHeader>
+                      <MaxAgeSeconds>100</MaxAgeSeconds>
+                    </CORSRule>
+                    <ResponseVary>false</ResponseVary>
+                </CORSConfiguration>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let rules = client.get_bucket_cors().unwrap();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].allowed_methods, vec![http::Method::GET]);
+    assert_eq!(rules[0].expose_headers, vec!["x-oss-test"]);
+    assert_eq!(rules[0].max_age_seconds, Some(100));
+}
+
+#[tokio::test]
+async fn test_put_bucket_referer() {
+    use crate::referer::RefererConfiguration;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?referer").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<RefererConfiguration><AllowEmptyReferer>false</AllowEmptyReferer><AllowTruncateQueryString>true</AllowTruncateQueryString><RefererList><Referer>https://*.example.com</Referer></RefererList></RefererConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let config = RefererConfiguration::new()
+        .allow_empty_referer(false)
+        .referer("https://*.example.com");
+    let res = client.put_bucket_referer(config).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_referer() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?referer").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <RefererConfiguration>
+                  <AllowEmptyReferer>false</AllowEmptyReferer>
+                  <AllowTruncateQueryString>false</AllowTruncateQueryString>
+                  <RefererList>
+                    <Referer>http://www.aliyun.com</Referer>
+                  </RefererList>
+                  <RefererBlacklist>
+                    <Referer>http://www.refuse.com</Referer>
+                  </RefererBlacklist>
+                </RefererConfiguration>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let config = client.get_bucket_referer().unwrap();
+    assert!(!config.allow_empty_referer);
+    assert!(!config.allow_truncate_query_string);
+    assert_eq!(config.referer_list, vec!["http://www.aliyun.com"]);
+    assert_eq!(config.referer_blacklist, vec!["http://www.refuse.com"]);
+}
+
+#[tokio::test]
+async fn test_get_bucket_website() {
+    use crate::website::{IncludeHeader, RedirectType};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?website").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <WebsiteConfiguration>
+                  <IndexDocument>
+                    <Suffix>index.html</Suffix>
+                    <SupportSubDir>true</SupportSubDir>
+                    <Type>0</Type>
+                  </IndexDocument>
+                  <ErrorDocument>
+                    <Key>error.html</Key>
+                    <HttpStatus>404</HttpStatus>
+                  </ErrorDocument>
+                  <RoutingRules>
+                    <RoutingRule>
+                      <RuleNumber>1</RuleNumber>
+                      <Condition>
+                        <KeyPrefixEquals>abc/</KeyPrefixEquals>
+                        <HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals>
+                        <IncludeHeader>
+                          <Key>host</Key>
+                          <Equals>test.oss-cn-beijing-internal.aliyuncs.com</Equals>
+                        </IncludeHeader>
+                      </Condition>
+                      <Redirect>
+                        <RedirectType>Mirror</RedirectType>
+                        <PassQueryString>true</PassQueryString>
+                        <MirrorURL>http://example.com/</MirrorURL>
+                        <MirrorHeaders>
+                          <PassAll>true</PassAll>
+                          <Pass>myheader-key1</Pass>
+                          <Set>
+                            <Key>myheader-key5</Key>
+                            <Value>myheader-value5</Value>
+                          </Set>
+                        </MirrorHeaders>
+                      </Redirect>
+                    </RoutingRule>
+                    <RoutingRule>
+                      <RuleNumber>2</RuleNumber>
+                      <Condition>
+                        <KeySuffixEquals>.jpg</KeySuffixEquals>
+                      </Condition>
+                      <Redirect>
+                        <RedirectType>AliCDN</RedirectType>
+                        <Protocol>http</Protocol>
+                        <HostName>example.com</HostName>
+                        <HttpRedirectCode>301</HttpRedirectCode>
+                      </Redirect>
+                    </RoutingRule>
+                  </RoutingRules>
+                </WebsiteConfiguration>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let config = client.get_bucket_website().await.unwrap().unwrap();
+    let index = config.index_document.unwrap();
+    assert_eq!(index.suffix, "index.html");
+    assert!(index.support_sub_dir);
+    assert_eq!(index.index_type, Some(0));
+    assert_eq!(config.error_document.unwrap().http_status, Some(404));
+
+    assert_eq!(config.routing_rules.len(), 2);
+    let mirror = &config.routing_rules[0];
+    assert_eq!(mirror.rule_number, 1);
+    assert_eq!(
+        mirror.condition.include_headers,
+        vec![IncludeHeader {
+            key: "host".to_owned(),
+            equals: Some("test.oss-cn-beijing-internal.aliyuncs.com".to_owned()),
+            ..IncludeHeader::default()
+        }]
+    );
+    assert_eq!(mirror.redirect.redirect_type, RedirectType::Mirror);
+    let headers = mirror.redirect.mirror_headers.as_ref().unwrap();
+    assert!(headers.pass_all);
+    assert_eq!(headers.pass, vec!["myheader-key1"]);
+    assert_eq!(
+        headers.set,
+        vec![("myheader-key5".to_owned(), "myheader-value5".to_owned())]
+    );
+
+    let cdn = &config.routing_rules[1];
+    assert_eq!(cdn.condition.key_suffix_equals.as_deref(), Some(".jpg"));
+    assert_eq!(cdn.redirect.redirect_type, RedirectType::AliCDN);
+    assert_eq!(cdn.redirect.http_redirect_code, Some(301));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_delete_bucket_website() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "DELETE");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?website").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(204).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.delete_bucket_website();
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_bucket_logging() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?logging").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <BucketLoggingStatus>
+                  <LoggingEnabled>
+                    <TargetBucket>log-bucket</TargetBucket>
+                    <TargetPrefix>foo4/</TargetPrefix>
+                  </LoggingEnabled>
+                </BucketLoggingStatus>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let config = client.get_bucket_logging().await.unwrap().unwrap();
+    assert_eq!(config.target_bucket.as_ref(), "log-bucket");
+    assert_eq!(config.target_prefix, "foo4/");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_bucket_logging() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::logging::LoggingConfiguration;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?logging").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<BucketLoggingStatus><LoggingEnabled><TargetBucket>log-bucket</TargetBucket><TargetPrefix>foo4/</TargetPrefix></LoggingEnabled></BucketLoggingStatus>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let config = LoggingConfiguration::new("log-bucket".parse().unwrap()).target_prefix("foo4/");
+    let res = client.put_bucket_logging(config);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_put_bucket_policy() {
+    use crate::policy::{resource_arn, BucketPolicy, Statement};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?policy").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                br#"{"Version":"1","Statement":[{"Effect":"Allow","Action":["oss:GetObject"],"Principal":["1234"],"Resource":["acs:oss:*:*:foo4/*"]}]}"#
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let bucket = "foo4".parse().unwrap();
+    let policy = BucketPolicy::new().statement(
+        Statement::allow()
+            .action("oss:GetObject")
+            .principal("1234")
+            .resource(resource_arn(&bucket, "*")),
+    );
+    let res = client.put_bucket_policy(&policy).await;
+    assert!(res.is_ok());
+
+    let other = BucketPolicy::new().statement(
+        Statement::allow()
+            .action("oss:GetObject")
+            .resource("acs:oss:*:*:bar/*"),
+    );
+    let res = client.put_bucket_policy(&other).await;
+    assert!(matches!(
+        res,
+        Err(crate::errors::OssError::InvalidPolicy(_))
+    ));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_policy() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?policy").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"{"Version":"1","Statement":[{"Effect":"Allow","Action":"oss:GetObject","Resource":["acs:oss:*:*:foo4/*"]}]}"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let policy = client.get_bucket_policy().unwrap();
+    assert_eq!(
+        policy,
+        Some(
+            crate::policy::BucketPolicy::new().statement(
+                crate::policy::Statement::allow()
+                    .action("oss:GetObject")
+                    .resource("acs:oss:*:*:foo4/*")
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_put_bucket_tagging() {
+    use crate::tagging::Tagging;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                *request.url(),
+                "https://abc.oss-cn-shanghai.aliyuncs.com/?tagging"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/abc/?tagging").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<Tagging><TagSet><Tag><Key>owner</Key><Value>tenant-a</Value></Tag></TagSet></Tagging>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let bucket = Bucket::<ArcPointer>::new(
+        "abc.oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        Utc::now(),
+        String::from("foo1"),
+        String::from("foo2"),
+        Arc::new(client),
+    );
+
+    let res = bucket
+        .put_bucket_tagging(Tagging::new().tag("owner", "tenant-a"))
+        .await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_request_payment() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use crate::payment::Payer;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?requestPayment").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <RequestPaymentConfiguration>
+                  <Payer>Requester</Payer>
+                </RequestPaymentConfiguration>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let payer = client.get_bucket_request_payment().unwrap();
+    assert_eq!(payer, Payer::Requester);
+}
+
+#[tokio::test]
+async fn test_put_bucket_replication() {
+    use crate::replication::{ReplicationDestination, ReplicationRule};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "POST");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?replication&comp=add"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?comp=add&replication").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<ReplicationConfiguration><Rule><PrefixSet><Prefix>logs/</Prefix></PrefixSet>\
+                <Action>ALL</Action><Destination><Bucket>dr-bucket</Bucket><Location>oss-cn-beijing</Location></Destination>\
+                <HistoricalObjectReplication>enabled</HistoricalObjectReplication></Rule></ReplicationConfiguration>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let rule = ReplicationRule::new(ReplicationDestination::new(
+        "dr-bucket".parse().unwrap(),
+        "oss-cn-beijing".parse().unwrap(),
+    ))
+    .prefix("logs/");
+
+    let res = client.put_bucket_replication(rule).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_bucket_replication_progress() {
+    use crate::blocking::builder::Middleware;
+    use crate::client::ClientRc;
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/?replicationProgress&rule-id=test_replication_1"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?replicationProgress").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ReplicationProgress>
+                  <Rule>
+                    <ID>test_replication_1</ID>
+                    <PrefixSet>
+                      <Prefix>source_image</Prefix>
+                    </PrefixSet>
+                    <Action>PUT</Action>
+                    <Destination>
+                      <Bucket>target-bucket</Bucket>
+                      <Location>oss-cn-beijing</Location>
+                      <TransferType>oss_acc</TransferType>
+                    </Destination>
+                    <Status>doing</Status>
+                    <HistoricalObjectReplication>enabled</HistoricalObjectReplication>
+                    <Progress>
+                      <HistoricalObject>0.85</HistoricalObject>
+                      <NewObject>2015-09-24T15:28:14.000Z</NewObject>
+                    </Progress>
+                  </Rule>
+                </ReplicationProgress>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let progress = client
+        .get_bucket_replication_progress("test_replication_1")
+        .unwrap();
+    assert_eq!(progress.rule.id.as_deref(), Some("test_replication_1"));
+    assert_eq!(progress.rule.prefix_set, vec!["source_image".to_string()]);
+    assert_eq!(progress.rule.destination.bucket.as_ref(), "target-bucket");
+    assert_eq!(progress.rule.status.as_deref(), Some("doing"));
+    assert_eq!(progress.historical_object, Some(0.85));
+    assert_eq!(
+        progress.new_object,
+        Some("2015-09-24T15:28:14Z".parse().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_get_bucket_replication_not_found() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/?replication").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(404)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <Error>
+                  <Code>NoSuchReplicationConfiguration</Code>
+                  <Message>The replication configuration does not exist.</Message>
+                  <RequestId>5C4A3F2E8E9F3A0B2A5D1234</RequestId>
+                </Error>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let rules = client.get_bucket_replication().await.unwrap();
+    assert!(rules.is_empty());
+}
@@ -186,6 +186,36 @@ mod handle_error {
         assert_eq!(&ok.text().await.unwrap(), "body_abc");
     }
 
+    #[tokio::test]
+    async fn test_async_conditional_error() {
+        use crate::errors::OssError;
+
+        let http = HttpResponse::builder().status(304).body("").unwrap();
+        let response: Response = http.into();
+
+        let err: OssError = response.handle_error().await.unwrap_err().into();
+        assert!(matches!(err, OssError::NotModified));
+
+        let http = HttpResponse::builder()
+            .status(412)
+            .body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Error>
+                <Code>PreconditionFailed</Code>
+                <Message>At least one of the pre-conditions you specified did not hold.</Message>
+                <RequestId>63145DB90BFD85303279D56B</RequestId>
+            </Error>
+            "#,
+            )
+            .unwrap();
+        let response: Response = http.into();
+
+        let err: OssError = response.handle_error().await.unwrap_err().into();
+        assert!(
+            matches!(err, OssError::PreconditionFailed(OssService{code,..}) if code=="PreconditionFailed")
+        );
+    }
+
     #[cfg(feature = "blocking")]
     #[test]
     fn test_blocking_has_error() {
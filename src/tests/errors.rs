@@ -54,3 +54,61 @@ fn test_oss_service_new() {
 }
 
 //use test::Bencher;
+
+#[test]
+fn test_conditional_status() {
+    let err: OssError = OssService::new("", &StatusCode::NOT_MODIFIED).into();
+    assert!(matches!(err, OssError::NotModified));
+
+    let err: OssError = OssService::new("", &StatusCode::PRECONDITION_FAILED).into();
+    match err {
+        OssError::PreconditionFailed(e) => assert_eq!(e.status, StatusCode::PRECONDITION_FAILED),
+        _ => panic!("expect PreconditionFailed"),
+    }
+
+    let err: OssError = OssService::new("", &StatusCode::NOT_FOUND).into();
+    assert!(matches!(err, OssError::OssService(_)));
+}
+
+#[test]
+fn test_builder_error_path() {
+    use crate::builder::BuilderError;
+
+    let err: OssError =
+        BuilderError::OssService(OssService::new("", &StatusCode::NOT_FOUND)).into();
+    assert!(matches!(
+        err,
+        OssError::BuilderError(BuilderError::OssService(_))
+    ));
+
+    let err: OssError =
+        BuilderError::OssService(OssService::new("", &StatusCode::PRECONDITION_FAILED)).into();
+    assert!(matches!(err, OssError::PreconditionFailed(_)));
+}
+
+#[test]
+fn test_bucket_error_code() {
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+    <Error>
+        <Code>BucketAlreadyExists</Code>
+        <Message>The requested bucket name is not available.</Message>
+        <RequestId>63145DB90BFD85303279D56B</RequestId>
+    </Error>
+    "#;
+    let err: OssError = OssService::new(content, &StatusCode::CONFLICT).into();
+    assert!(matches!(err, OssError::BucketAlreadyExists(_)));
+
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+    <Error>
+        <Code>BucketNotEmpty</Code>
+        <Message>The bucket you tried to delete is not empty.</Message>
+        <RequestId>63145DB90BFD85303279D56B</RequestId>
+    </Error>
+    "#;
+    let err: OssError = OssService::new(content, &StatusCode::CONFLICT).into();
+    assert!(matches!(err, OssError::BucketNotEmpty(_)));
+    assert_eq!(
+        err.message(),
+        "The bucket you tried to delete is not empty."
+    );
+}
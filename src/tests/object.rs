@@ -843,3 +843,2070 @@ fn test_blocking_delete_object() {
     //println!("{:?}", res);
     assert!(res.is_ok());
 }
+
+#[tokio::test]
+async fn test_get_object_acl() {
+    use crate::object::ObjectAcl;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.png?acl"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                //.url(url.clone())
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <AccessControlPolicy>
+                    <Owner>
+                        <ID>0022012****</ID>
+                        <DisplayName>0022012****</DisplayName>
+                    </Owner>
+                    <AccessControlList>
+                        <Grant>public-read</Grant>
+                    </AccessControlList>
+                </AccessControlPolicy>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client.get_object_acl("abc.png").await;
+    assert_eq!(res.unwrap(), ObjectAcl::PublicRead);
+}
+
+#[tokio::test]
+async fn test_put_object_acl() {
+    use crate::object::ObjectAcl;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.png?acl"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-object-acl"),
+                Some(&HeaderValue::from_str("private").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client.put_object_acl("abc.png", ObjectAcl::Private).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_object_acl() {
+    use crate::client::ClientRc;
+    use crate::object::ObjectAcl;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.png?acl"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <AccessControlPolicy>
+                    <AccessControlList>
+                        <Grant>default</Grant>
+                    </AccessControlList>
+                </AccessControlPolicy>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.get_object_acl("abc.png");
+    assert_eq!(res.unwrap(), ObjectAcl::Default);
+}
+
+#[tokio::test]
+async fn test_object_put_object_acl() {
+    use crate::builder::ArcPointer;
+    use crate::object::{ObjectAcl, ObjectBuilder};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-object-acl"),
+                Some(&HeaderValue::from_str("public-read").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let object = ObjectBuilder::<ArcPointer>::new(
+        Arc::new("foo4.oss-cn-shanghai.aliyuncs.com".parse().unwrap()),
+        "abc.png",
+    )
+    .build();
+
+    let res = object.put_object_acl(ObjectAcl::PublicRead, &client).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_object_get_object_acl() {
+    use crate::blocking::builder::Middleware;
+    use crate::builder::RcPointer;
+    use crate::client::ClientRc;
+    use crate::object::{ObjectAcl, ObjectBuilder};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <AccessControlPolicy>
+                    <AccessControlList>
+                        <Grant>private</Grant>
+                    </AccessControlList>
+                </AccessControlPolicy>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let object = ObjectBuilder::<RcPointer>::new(
+        Rc::new("foo4.oss-cn-shanghai.aliyuncs.com".parse().unwrap()),
+        "abc.png",
+    )
+    .build();
+
+    let res = object.get_object_acl(&client);
+    assert_eq!(res.unwrap(), ObjectAcl::Private);
+}
+
+#[tokio::test]
+async fn test_put_symlink() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/latest.zip?symlink"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/latest.zip?symlink").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-symlink-target"),
+                Some(&HeaderValue::from_str("v1.0%2Fapp%20name.zip").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client.put_symlink("latest.zip", "v1.0/app name.zip").await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_symlink() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/latest.zip?symlink"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/latest.zip?symlink").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-symlink-target", "v1.0%2Fapp%20name.zip")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client.get_symlink("latest.zip").await;
+    assert_eq!(res.unwrap(), "v1.0/app name.zip");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_symlink() {
+    use crate::client::ClientRc;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/latest.zip?symlink").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    // 响应中缺少 x-oss-symlink-target
+    let res = client.get_symlink("latest.zip");
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_restore_object() {
+    use crate::object::{RestoreRequest, RestoreTier};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "POST");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/archive.zip?restore"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/archive.zip?restore").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<RestoreRequest><Days>2</Days><JobParameters><Tier>Expedited</Tier></JobParameters></RestoreRequest>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(202).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client
+        .restore_object(
+            "archive.zip",
+            RestoreRequest::new(2).tier(RestoreTier::Expedited),
+        )
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_restore_info() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "HEAD");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/archive.zip").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-restore", "ongoing-request=\"true\"")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let info = client
+        .get_restore_info("archive.zip")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(info.ongoing_request());
+    assert!(!info.is_restored());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_restore_info() {
+    use crate::client::ClientRc;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "HEAD");
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    // 未提交过解冻请求
+    let res = client.get_restore_info("archive.zip");
+    assert!(res.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_select_object() {
+    use crate::select::{encode_frame, CsvInput, SelectRequest};
+    use futures::{pin_mut, StreamExt};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "POST");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/data.csv?x-oss-process=csv/select"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/data.csv?x-oss-process=csv/select").unwrap())
+            );
+
+            let mut data = 0u64.to_be_bytes().to_vec();
+            data.extend_from_slice(b"1,foo\n");
+            let mut end = 6u64.to_be_bytes().to_vec();
+            end.extend_from_slice(&6u64.to_be_bytes());
+            end.extend_from_slice(&200u32.to_be_bytes());
+
+            let mut body = encode_frame(8_388_609, &data);
+            body.extend(encode_frame(8_388_613, &end));
+
+            use http::response::Builder;
+            let response = Builder::new().status(206).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let request = SelectRequest::csv("select * from ossobject", CsvInput::default());
+    let response = client.select_object("data.csv", request).await.unwrap();
+    let stream = response.into_stream();
+    pin_mut!(stream);
+
+    assert_eq!(stream.next().await.unwrap().unwrap(), b"1,foo\n");
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_create_select_object_meta() {
+    use crate::select::{encode_frame, InputSerialization, JsonInput, SelectMetaRequest};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "POST");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/data.json?x-oss-process=json/meta").unwrap())
+            );
+
+            let mut meta = 0u64.to_be_bytes().to_vec();
+            meta.extend_from_slice(&100u64.to_be_bytes());
+            meta.extend_from_slice(&200u32.to_be_bytes());
+            meta.extend_from_slice(&2u32.to_be_bytes());
+            meta.extend_from_slice(&50u64.to_be_bytes());
+
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(encode_frame(8_388_615, &meta))
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let request = SelectMetaRequest::new(InputSerialization::Json(JsonInput::default()));
+    let meta = client
+        .create_select_object_meta("data.json", request)
+        .await
+        .unwrap();
+    assert_eq!(meta.splits_count, 2);
+    assert_eq!(meta.rows_count, 50);
+    assert_eq!(meta.columns_count, None);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_select_object() {
+    use crate::client::ClientRc;
+    use crate::select::{encode_frame, CsvInput, SelectRequest};
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "POST");
+
+            let mut end = 0u64.to_be_bytes().to_vec();
+            end.extend_from_slice(&0u64.to_be_bytes());
+            end.extend_from_slice(&400u32.to_be_bytes());
+            end.extend_from_slice(b"InvalidSql");
+
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(206)
+                .body(encode_frame(8_388_613, &end))
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let request = SelectRequest::csv("select * from", CsvInput::default());
+    let mut response = client.select_object("data.csv", request).unwrap();
+
+    // 结束帧中的状态码表示查询失败
+    assert!(response.next().unwrap().is_err());
+    assert!(response.next().is_none());
+}
+
+#[tokio::test]
+async fn test_put_content_with_options() {
+    use crate::object::{Conditions, PutOptions, UserMeta};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.txt").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-meta-author"),
+                Some(&HeaderValue::from_str("foo").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-forbid-overwrite"),
+                Some(&HeaderValue::from_str("true").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let mut meta = UserMeta::new();
+    meta.insert("Author", "foo").unwrap();
+    let options = PutOptions::new()
+        .meta(meta)
+        .conditions(Conditions::new().forbid_overwrite(true));
+
+    let res = client
+        .put_content_with_options(b"bar".to_vec(), "text/plain", "abc.txt", options)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_put_user_meta() {
+    use crate::object::UserMeta;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            use http::response::Builder;
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/dir/a b.txt").unwrap())
+            );
+
+            if request.method() == "HEAD" {
+                let response = Builder::new()
+                    .status(200)
+                    .header("content-type", "text/plain")
+                    .header("cache-control", "no-cache")
+                    .header("content-disposition", "attachment")
+                    .header("etag", "\"5B3C1A2E053D763E1B002CC607C5A0FE\"")
+                    .header("x-oss-server-side-encryption", "AES256")
+                    .header("x-oss-storage-class", "IA")
+                    .header("x-oss-meta-author", "foo")
+                    .body("")
+                    .unwrap();
+                return Ok(Response::from(response));
+            }
+
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("x-oss-copy-source"),
+                Some(&HeaderValue::from_str("/foo4/dir%2Fa%20b.txt").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-metadata-directive"),
+                Some(&HeaderValue::from_str("REPLACE").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("content-type"),
+                Some(&HeaderValue::from_str("text/plain").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-meta-author"),
+                Some(&HeaderValue::from_str("bar").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-copy-source-if-match"),
+                Some(&HeaderValue::from_str("\"5B3C1A2E053D763E1B002CC607C5A0FE\"").unwrap())
+            );
+            for (name, value) in [
+                ("cache-control", "no-cache"),
+                ("content-disposition", "attachment"),
+                ("x-oss-server-side-encryption", "AES256"),
+                ("x-oss-storage-class", "IA"),
+            ] {
+                assert_eq!(
+                    request.headers().get(name),
+                    Some(&HeaderValue::from_str(value).unwrap())
+                );
+            }
+            assert!(request.headers().get("etag").is_none());
+            let response = Builder::new().status(200).body("").unwrap();
+            Ok(Response::from(response))
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let meta = client.get_user_meta("dir/a b.txt").await.unwrap();
+    assert_eq!(meta.get("author"), Some("foo"));
+
+    let mut meta = UserMeta::new();
+    meta.insert("author", "bar").unwrap();
+    let res = client.put_user_meta("dir/a b.txt", meta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_put_user_meta_keeps_encryption_meta() {
+    use crate::object::UserMeta;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            use http::response::Builder;
+
+            if request.method() == "HEAD" {
+                let response = Builder::new()
+                    .status(200)
+                    .header("etag", "\"5B3C1A2E053D763E1B002CC607C5A0FE\"")
+                    .header("x-oss-meta-author", "foo")
+                    .header("x-oss-meta-client-side-encryption-key", "a2V5")
+                    .header("x-oss-meta-client-side-encryption-start", "c3RhcnQ=")
+                    .header(
+                        "x-oss-meta-client-side-encryption-cek-alg",
+                        "AES/CTR/NoPadding",
+                    )
+                    .body("")
+                    .unwrap();
+                return Ok(Response::from(response));
+            }
+
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("x-oss-meta-author"),
+                Some(&HeaderValue::from_str("bar").unwrap())
+            );
+            for (name, value) in [
+                ("x-oss-meta-client-side-encryption-key", "a2V5"),
+                ("x-oss-meta-client-side-encryption-start", "c3RhcnQ="),
+                (
+                    "x-oss-meta-client-side-encryption-cek-alg",
+                    "AES/CTR/NoPadding",
+                ),
+            ] {
+                let values: Vec<_> = request.headers().get_all(name).iter().collect();
+                assert_eq!(values, vec![&HeaderValue::from_str(value).unwrap()]);
+            }
+            let response = Builder::new().status(200).body("").unwrap();
+            Ok(Response::from(response))
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let mut meta = UserMeta::new();
+    meta.insert("author", "bar").unwrap();
+    meta.insert("client-side-encryption-key", "other").unwrap();
+    let res = client.put_user_meta("a.txt", meta).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_user_meta() {
+    use crate::client::ClientRc;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "HEAD");
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-meta-version", "1")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let meta = client.get_user_meta("abc.txt").unwrap();
+    assert_eq!(meta.get("Version"), Some("1"));
+}
+
+#[tokio::test]
+async fn test_get_object_with_conditions() {
+    use crate::object::Conditions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("if-none-match"),
+                Some(&HeaderValue::from_str("\"5B3C1A2E053D763E1B002CC607C5A0FE\"").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("if-modified-since"),
+                Some(&HeaderValue::from_str("Sat, 01 Jan 2022 18:01:01 GMT").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("bar").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let conditions = Conditions::new()
+        .if_none_match("5B3C1A2E053D763E1B002CC607C5A0FE")
+        .if_modified_since("2022-01-01T18:01:01Z".parse().unwrap());
+    let res = client
+        .get_object_with_conditions("abc.txt", .., conditions)
+        .await;
+    assert_eq!(res.unwrap(), b"bar");
+}
+
+#[tokio::test]
+async fn test_copy_object() {
+    use crate::object::Conditions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/b.txt").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-copy-source"),
+                Some(&HeaderValue::from_str("/foo4/a.txt").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-copy-source-if-match"),
+                Some(&HeaderValue::from_str("\"abc\"").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-forbid-overwrite"),
+                Some(&HeaderValue::from_str("true").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let conditions = Conditions::new().if_match("\"abc\"").forbid_overwrite(true);
+    let res = client.copy_object("b.txt", "a.txt", conditions).await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_head_object() {
+    use crate::client::ClientRc;
+    use crate::object::Conditions;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "HEAD");
+            assert_eq!(
+                request.headers().get("if-match"),
+                Some(&HeaderValue::from_str("\"abc\"").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("etag", "\"abc\"")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let headers = client
+        .head_object("abc.txt", Conditions::new().if_match("abc"))
+        .unwrap();
+    assert_eq!(headers.get("etag").unwrap(), "\"abc\"");
+}
+
+#[tokio::test]
+async fn test_put_content_integrity() {
+    use crate::errors::OssError;
+    use crate::integrity::{Integrity, IntegrityError};
+    use crate::object::PutOptions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(
+                request.headers().get("content-md5"),
+                Some(&HeaderValue::from_str("N7UdGUp1E+RbVvZSTy1R8g==").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-hash-crc64ecma", "1")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let mut client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+    client.integrity(Integrity::new().crc64().content_md5());
+
+    let res = client
+        .put_content_with_options(b"bar".to_vec(), "text/plain", "abc.txt", PutOptions::new())
+        .await;
+    assert!(matches!(
+        res,
+        Err(OssError::Integrity(IntegrityError::Crc64Mismatch {
+            server: 1,
+            ..
+        }))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_object_integrity() {
+    use crate::integrity::{crc64, Integrity};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, _request: Request) -> Result<Response, BuilderError> {
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-hash-crc64ecma", crc64(b"bar").to_string())
+                .body("bar")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let mut client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+    client.integrity(Integrity::new().crc64());
+
+    let res = client.get_object("abc.txt", ..).await;
+    assert_eq!(res.unwrap(), b"bar");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_object_integrity() {
+    use crate::client::ClientRc;
+    use crate::errors::OssError;
+    use crate::integrity::{Integrity, IntegrityError};
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, _request: Request) -> Result<Response, BuilderError> {
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(206)
+                .header("content-range", "bytes 0-3/10")
+                .body("bar")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let mut client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+    client.integrity(Integrity::new().crc64());
+
+    let res = client.get_object("abc.txt", ..4);
+    assert!(matches!(
+        res,
+        Err(OssError::Integrity(IntegrityError::LengthMismatch {
+            expected: 4,
+            actual: 3
+        }))
+    ));
+}
+
+#[tokio::test]
+async fn test_copy_object_with_encryption() {
+    use crate::object::{CopyOptions, Encryption, ServerSideEncryption};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("x-oss-copy-source"),
+                Some(&HeaderValue::from_str("/foo4/a.txt").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-server-side-encryption"),
+                Some(&HeaderValue::from_str("KMS").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-server-side-encryption-key-id"),
+                Some(&HeaderValue::from_str("foo_key").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let options =
+        CopyOptions::new().encryption(ServerSideEncryption::new(Encryption::Kms).key_id("foo_key"));
+    let res = client
+        .copy_object_with_options("b.txt", "a.txt", options)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_copy_object_with_meta() {
+    use crate::object::{CopyOptions, UserMeta};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                request.headers().get("x-oss-metadata-directive"),
+                Some(&HeaderValue::from_str("REPLACE").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("x-oss-meta-author"),
+                Some(&HeaderValue::from_str("bar").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let mut meta = UserMeta::new();
+    meta.insert("author", "bar").unwrap();
+    let res = client
+        .copy_object_with_options("b.txt", "a.txt", CopyOptions::new().meta(meta))
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_object_with_meta() {
+    use crate::object::Conditions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("x-oss-meta-author", "foo")
+                .body("bar")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let (content, meta) = client
+        .get_object_with_meta("abc.txt", .., Conditions::new())
+        .await
+        .unwrap();
+    assert_eq!(content, b"bar");
+    assert_eq!(meta.get("author"), Some("foo"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_multipart_upload() {
+    use crate::client::ClientRc;
+    use crate::object::{PutOptions, UploadedPart, UserMeta};
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            use http::response::Builder;
+            let resource = request
+                .headers()
+                .get("canonicalizedresource")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_owned();
+            let response = match resource.as_str() {
+                "/foo4/big.bin?uploads" => {
+                    assert_eq!(request.method(), "POST");
+                    assert_eq!(
+                        request.headers().get("x-oss-meta-author"),
+                        Some(&HeaderValue::from_str("foo").unwrap())
+                    );
+                    Builder::new()
+                        .status(200)
+                        .body(
+                            r#"<?xml version="1.0" encoding="UTF-8"?>
+                        <InitiateMultipartUploadResult>
+                            <Bucket>foo4</Bucket>
+                            <Key>big.bin</Key>
+                            <UploadId>0004B9894A22E5B1888A1E29F823****</UploadId>
+                        </InitiateMultipartUploadResult>"#,
+                        )
+                        .unwrap()
+                }
+                "/foo4/big.bin?partNumber=1&uploadId=0004B9894A22E5B1888A1E29F823****" => {
+                    assert_eq!(request.method(), "PUT");
+                    Builder::new()
+                        .status(200)
+                        .header("etag", "\"3349DC700140D7F86A0784842780****\"")
+                        .body("")
+                        .unwrap()
+                }
+                "/foo4/big.bin?uploadId=0004B9894A22E5B1888A1E29F823****" => {
+                    assert_eq!(request.method(), "POST");
+                    assert_eq!(
+                        request.body().unwrap().as_bytes().unwrap(),
+                        b"<CompleteMultipartUpload><Part><PartNumber>1</PartNumber>\
+                        <ETag>\"3349DC700140D7F86A0784842780****\"</ETag></Part></CompleteMultipartUpload>"
+                    );
+                    Builder::new().status(200).body("").unwrap()
+                }
+                _ => panic!("unexpected request {}", resource),
+            };
+            Ok(Response::from(response))
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let mut meta = UserMeta::new();
+    meta.insert("author", "foo").unwrap();
+    let upload_id = client
+        .init_multipart_upload(
+            "big.bin",
+            "application/octet-stream",
+            PutOptions::new().meta(meta),
+        )
+        .unwrap();
+    assert_eq!(upload_id, "0004B9894A22E5B1888A1E29F823****");
+
+    let part = client
+        .upload_part("big.bin", &upload_id, 1, b"foo".to_vec())
+        .unwrap();
+    assert_eq!(
+        part,
+        UploadedPart::new(1, "\"3349DC700140D7F86A0784842780****\"")
+    );
+
+    let res = client.complete_multipart_upload("big.bin", &upload_id, &[part]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_multipart_upload_integrity() {
+    use crate::errors::OssError;
+    use crate::integrity::{content_md5, crc64, Integrity, IntegrityError};
+
+    struct MyMiddleware {
+        complete_crc: u64,
+    }
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            use http::response::Builder;
+            let resource = request
+                .headers()
+                .get("canonicalizedresource")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_owned();
+            let content: &[u8] = match resource.as_str() {
+                "/foo4/big.bin?partNumber=1&uploadId=abc" => b"1234",
+                "/foo4/big.bin?partNumber=2&uploadId=abc" => b"56789",
+                "/foo4/big.bin?uploadId=abc" => {
+                    let response = Builder::new()
+                        .status(200)
+                        .header("x-oss-hash-crc64ecma", self.complete_crc.to_string())
+                        .body("")
+                        .unwrap();
+                    return Ok(Response::from(response));
+                }
+                _ => panic!("unexpected request {}", resource),
+            };
+            assert_eq!(
+                request.headers().get("content-md5").unwrap(),
+                content_md5(request.body().unwrap().as_bytes().unwrap()).as_ref()
+            );
+            let response = Builder::new()
+                .status(200)
+                .header("etag", "\"abc\"")
+                .header("x-oss-hash-crc64ecma", crc64(content).to_string())
+                .body("")
+                .unwrap();
+            Ok(Response::from(response))
+        }
+    }
+
+    let init_client = |complete_crc| {
+        let mut client = Client::<ClientWithMiddleware>::new(
+            "foo1".into(),
+            "foo2".into(),
+            "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+            "foo4".parse().unwrap(),
+        )
+        .middleware(Arc::new(MyMiddleware { complete_crc }));
+        client.integrity(Integrity::new().crc64().content_md5());
+        client
+    };
+
+    let client = init_client(crc64(b"123456789"));
+    let part2 = client
+        .upload_part("big.bin", "abc", 2, b"56789".to_vec())
+        .await
+        .unwrap();
+    assert_eq!(part2.crc64, Some(crc64(b"56789")));
+    assert_eq!(part2.size, 5);
+    let part1 = client
+        .upload_part("big.bin", "abc", 1, b"1234".to_vec())
+        .await
+        .unwrap();
+    let parts = [part2, part1];
+
+    let res = client
+        .complete_multipart_upload("big.bin", "abc", &parts)
+        .await;
+    assert!(res.is_ok());
+
+    let client = init_client(1);
+    let res = client
+        .complete_multipart_upload("big.bin", "abc", &parts)
+        .await;
+    assert!(matches!(
+        res,
+        Err(OssError::Integrity(IntegrityError::Crc64Mismatch {
+            server: 1,
+            ..
+        }))
+    ));
+
+    let res = client
+        .upload_part("big.bin", "abc", 1, b"4321".to_vec())
+        .await;
+    assert!(matches!(
+        res,
+        Err(OssError::Integrity(IntegrityError::Crc64Mismatch { .. }))
+    ));
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_encryption_client_get_object() {
+    use crate::crypto::{ContentCipher, EncryptionClient, RsaKeyProvider};
+    use crate::object::UserMeta;
+    use rsa::RsaPrivateKey;
+
+    let private = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+    let provider = RsaKeyProvider::new(private.clone());
+
+    let cipher = ContentCipher::generate();
+    let mut meta = UserMeta::new();
+    cipher.write_meta(&provider, &mut meta, 11).unwrap();
+    let mut encrypted = b"hello world".to_vec();
+    cipher.apply(&mut encrypted, 0);
+
+    struct MyMiddleware {
+        meta: UserMeta,
+        body: Vec<u8>,
+    }
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                request.headers().get("range"),
+                Some(&HeaderValue::from_str("bytes=6-").unwrap())
+            );
+            use http::response::Builder;
+            let mut response = Builder::new().status(206);
+            for (name, value) in self.meta.to_headers().unwrap() {
+                response = response.header(name, value);
+            }
+            let response = response.body(self.body[6..].to_vec()).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {
+        meta,
+        body: encrypted,
+    }));
+    let client = EncryptionClient::new(client, RsaKeyProvider::new(private));
+
+    let res = client.get_object("abc.txt", 6..).await;
+    assert_eq!(res.unwrap(), b"world");
+}
+
+#[tokio::test]
+async fn test_get_object_acl_version() {
+    use crate::object::{Conditions, ObjectAcl};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "GET");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.png?acl&versionId=CAEQ%2Bab%3D"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.png?acl&versionId=CAEQ+ab=").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <AccessControlPolicy>
+                    <AccessControlList>
+                        <Grant>private</Grant>
+                    </AccessControlList>
+                </AccessControlPolicy>"#,
+                )
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client
+        .get_object_acl_with_conditions("abc.png", Conditions::new().version_id("CAEQ+ab="))
+        .await;
+    assert_eq!(res.unwrap(), ObjectAcl::Private);
+}
+
+#[tokio::test]
+async fn test_copy_object_version() {
+    use crate::object::CopyOptions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(
+                request.headers().get("x-oss-copy-source"),
+                Some(&HeaderValue::from_str("/foo4/a.txt?versionId=CAEQabc").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let options = CopyOptions::new().source_version_id("CAEQabc");
+    let res = client
+        .copy_object_with_options("b.txt", "a.txt", options)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_delete_object_version() {
+    use crate::client::ClientRc;
+    use crate::object::Conditions;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "DELETE");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.txt?versionId=CAEQabc"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.txt?versionId=CAEQabc").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(204).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res =
+        client.delete_object_with_conditions("abc.txt", Conditions::new().version_id("CAEQabc"));
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_object_version() {
+    use crate::object::Conditions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.txt?versionId=CAEQabc"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.txt?versionId=CAEQabc").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("if-match"),
+                Some(&HeaderValue::from_str("\"abc\"").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("hello").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let conditions = Conditions::new().if_match("abc").version_id("CAEQabc");
+    let res = client
+        .get_object_with_conditions("abc.txt", .., conditions)
+        .await;
+    assert_eq!(res.unwrap(), b"hello");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_put_object_tagging_version() {
+    use crate::client::ClientRc;
+    use crate::object::Conditions;
+    use crate::tagging::Tagging;
+    use crate::{blocking::builder::Middleware, file::BlockingFile};
+    use reqwest::blocking::{Request, Response};
+    use std::rc::Rc;
+
+    struct MyMiddleware {}
+
+    impl Middleware for MyMiddleware {
+        fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "PUT");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/abc.txt?tagging&versionId=CAEQabc"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("canonicalizedresource"),
+                Some(&HeaderValue::from_str("/foo4/abc.txt?tagging&versionId=CAEQabc").unwrap())
+            );
+            assert_eq!(
+                request.body().unwrap().as_bytes().unwrap(),
+                b"<Tagging><TagSet><Tag><Key>env</Key><Value>dev</Value></Tag></TagSet></Tagging>"
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = ClientRc::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Rc::new(MyMiddleware {}));
+
+    let res = client.put_object_tagging(
+        "abc.txt",
+        Tagging::new().tag("env", "dev"),
+        Conditions::new().version_id("CAEQabc"),
+    );
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_object_list_stream() {
+    use futures::{pin_mut, StreamExt};
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            let query = request.url().query().unwrap();
+            let (key, next) = if query.contains("continuation-token=t3") {
+                ("c.txt", "")
+            } else if query.contains("continuation-token=t2") {
+                ("b.txt", "<NextContinuationToken>t3</NextContinuationToken>")
+            } else {
+                ("a.txt", "<NextContinuationToken>t2</NextContinuationToken>")
+            };
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .body(format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult>
+                  <Name>foo4</Name>
+                  <Prefix></Prefix>
+                  <MaxKeys>1</MaxKeys>
+                  <Contents>
+                    <Key>{}</Key>
+                    <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+                    <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+                    <Type>Normal</Type>
+                    <Size>18027</Size>
+                    <StorageClass>Standard</StorageClass>
+                  </Contents>
+                  {}
+                  <KeyCount>1</KeyCount>
+                </ListBucketResult>"#,
+                    key, next
+                ))
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let query = [("max-keys".into(), 1u8.into())];
+
+    let list = client.clone().get_object_list(query.clone()).await.unwrap();
+    let stream = list.into_stream();
+    pin_mut!(stream);
+    let mut pages = Vec::new();
+    while let Some(page) = stream.next().await {
+        pages.push(page.unwrap().object_list[0].path_string());
+    }
+    assert_eq!(pages, vec!["b.txt", "c.txt"]);
+
+    let list = client.clone().get_object_list(query.clone()).await.unwrap();
+    let stream = list.into_object_stream();
+    pin_mut!(stream);
+    let mut objects = Vec::new();
+    while let Some(object) = stream.next().await {
+        objects.push(object.unwrap().path_string());
+    }
+    assert_eq!(objects, vec!["a.txt", "b.txt", "c.txt"]);
+
+    #[cfg(feature = "prefetch")]
+    {
+        let list = client.clone().get_object_list(query.clone()).await.unwrap();
+        let stream = list.into_prefetch_object_stream();
+        pin_mut!(stream);
+        let mut objects = Vec::new();
+        while let Some(object) = stream.next().await {
+            objects.push(object.unwrap().path_string());
+        }
+        assert_eq!(objects, vec!["a.txt", "b.txt", "c.txt"]);
+
+        let list = client.get_object_list(query).await.unwrap();
+        let stream = list.into_prefetch_stream();
+        pin_mut!(stream);
+        assert_eq!(stream.next().await.unwrap().unwrap().object_list.len(), 1);
+        assert_eq!(stream.next().await.unwrap().unwrap().object_list.len(), 1);
+        assert!(stream.next().await.is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_list_dir() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            let query = request.url().query().unwrap();
+            assert!(query.contains("delimiter=/"));
+            assert!(query.contains("prefix=foo/"));
+            let body = if query.contains("continuation-token=t2") {
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult>
+                  <Name>foo4</Name>
+                  <Prefix>foo/</Prefix>
+                  <MaxKeys>2</MaxKeys>
+                  <Delimiter>/</Delimiter>
+                  <CommonPrefixes>
+                    <Prefix>foo/baz/</Prefix>
+                  </CommonPrefixes>
+                  <KeyCount>1</KeyCount>
+                </ListBucketResult>"#
+            } else {
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult>
+                  <Name>foo4</Name>
+                  <Prefix>foo/</Prefix>
+                  <MaxKeys>2</MaxKeys>
+                  <Delimiter>/</Delimiter>
+                  <NextContinuationToken>t2</NextContinuationToken>
+                  <Contents>
+                    <Key>foo/</Key>
+                    <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+                    <ETag>"D41D8CD98F00B204E9800998ECF8427E"</ETag>
+                    <Type>Normal</Type>
+                    <Size>0</Size>
+                    <StorageClass>Standard</StorageClass>
+                  </Contents>
+                  <Contents>
+                    <Key>foo/a.txt</Key>
+                    <LastModified>2022-06-26T09:53:21.000Z</LastModified>
+                    <ETag>"F75A15996D0857B16FA31A3B16624C26"</ETag>
+                    <Type>Normal</Type>
+                    <Size>18027</Size>
+                    <StorageClass>Standard</StorageClass>
+                  </Contents>
+                  <CommonPrefixes>
+                    <Prefix>foo/bar/</Prefix>
+                  </CommonPrefixes>
+                  <KeyCount>3</KeyCount>
+                </ListBucketResult>"#
+            };
+            use http::response::Builder;
+            let response = Builder::new().status(200).body(body).unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let (files, dirs) = client.list_dir("foo").await.unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path_string(), "foo/a.txt");
+    assert_eq!(dirs, vec!["foo/bar/", "foo/baz/"]);
+}
+
+#[tokio::test]
+async fn test_options_object() {
+    use http::Method;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "OPTIONS");
+            assert_eq!(
+                *request.url(),
+                "https://foo4.oss-cn-shanghai.aliyuncs.com/upload/abc.png"
+                    .parse()
+                    .unwrap()
+            );
+            assert_eq!(
+                request.headers().get("origin"),
+                Some(&HeaderValue::from_str("https://example.com").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("access-control-request-method"),
+                Some(&HeaderValue::from_str("PUT").unwrap())
+            );
+            assert_eq!(
+                request.headers().get("access-control-request-headers"),
+                Some(&HeaderValue::from_str("content-type,x-oss-meta-a").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new()
+                .status(200)
+                .header("Access-Control-Allow-Origin", "https://example.com")
+                .header("Access-Control-Allow-Methods", "PUT, GET")
+                .header("Access-Control-Allow-Headers", "content-type, x-oss-meta-a")
+                .header("Access-Control-Expose-Headers", "ETag")
+                .header("Access-Control-Max-Age", "600")
+                .body("")
+                .unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let preflight = client
+        .options_object(
+            "upload/abc.png",
+            "https://example.com",
+            Method::PUT,
+            &["content-type", "x-oss-meta-a"],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        preflight.allow_origin.as_deref(),
+        Some("https://example.com")
+    );
+    assert_eq!(preflight.allow_methods, vec!["PUT", "GET"]);
+    assert_eq!(
+        preflight.allow_headers,
+        vec!["content-type", "x-oss-meta-a"]
+    );
+    assert_eq!(preflight.expose_headers, vec!["ETag"]);
+    assert_eq!(preflight.max_age, Some(600));
+}
+
+#[tokio::test]
+async fn test_request_payer() {
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(
+                request.headers().get("x-oss-request-payer"),
+                Some(&HeaderValue::from_str("requester").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(200).body("content bar").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let mut client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+    client.request_payer(true);
+
+    let res = client.get_object("foo.png", ..).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_conditions_request_payer() {
+    use crate::object::Conditions;
+
+    struct MyMiddleware {}
+
+    #[async_trait]
+    impl Middleware for MyMiddleware {
+        async fn handle(&self, request: Request) -> Result<Response, BuilderError> {
+            //println!("request {:?}", request);
+            assert_eq!(request.method(), "DELETE");
+            assert_eq!(
+                request.headers().get("x-oss-request-payer"),
+                Some(&HeaderValue::from_str("requester").unwrap())
+            );
+            use http::response::Builder;
+            let response = Builder::new().status(204).body("").unwrap();
+            let response = Response::from(response);
+            Ok(response)
+        }
+    }
+
+    let client = Client::<ClientWithMiddleware>::new(
+        "foo1".into(),
+        "foo2".into(),
+        "https://oss-cn-shanghai.aliyuncs.com".parse().unwrap(),
+        "foo4".parse().unwrap(),
+    )
+    .middleware(Arc::new(MyMiddleware {}));
+
+    let res = client
+        .delete_object_with_conditions("foo.png", Conditions::new().request_payer(true))
+        .await;
+    assert!(res.is_ok());
+}
@@ -94,6 +94,7 @@
 
 use std::borrow::Cow;
 
+use percent_encoding::percent_decode_str;
 use quick_xml::{events::Event, Reader};
 
 pub trait RefineObject {
@@ -124,6 +125,16 @@ pub trait RefineObject {
     fn set_storage_class(&mut self, _storage_class: &str) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    /// 提取解冻状态，仅在 xml 中存在 `RestoreInfo` 时调用
+    fn set_restore_info(&mut self, _restore_info: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// 提取拥有者信息，仅在查询时设置了 `fetch-owner=true` 时调用
+    fn set_owner(&mut self, _id: &str, _display_name: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 const PREFIX: &[u8] = b"Prefix";
@@ -138,6 +149,7 @@ const E_TAG: &[u8] = b"ETag";
 const TYPE: &[u8] = b"Type";
 const SIZE: &[u8] = b"Size";
 const STORAGE_CLASS: &[u8] = b"StorageClass";
+const RESTORE_INFO: &[u8] = b"RestoreInfo";
 const BUCKET: &[u8] = b"Bucket";
 
 const CREATION_DATE: &[u8] = b"CreationDate";
@@ -150,6 +162,20 @@ const NEXT_MARKER: &[u8] = b"NextMarker";
 const ID: &[u8] = b"ID";
 const DISPLAY_NAME: &[u8] = b"DisplayName";
 const CONTENTS: &[u8] = b"Contents";
+const COMMON_PREFIXES: &[u8] = b"CommonPrefixes";
+const START_AFTER: &[u8] = b"StartAfter";
+const DELIMITER: &[u8] = b"Delimiter";
+const ENCODING_TYPE: &[u8] = b"EncodingType";
+const ENCODING_TYPE_URL: &str = "url";
+
+/// 查询时设置了 `encoding-type=url` 的话，对返回的 Key、Prefix 等进行解码
+fn decode_url(value: Cow<'_, str>, url_encoded: bool) -> Cow<'_, str> {
+    if !url_encoded {
+        return value;
+    }
+    let value = value.replace('+', " ");
+    Cow::Owned(percent_decode_str(&value).decode_utf8_lossy().into_owned())
+}
 
 pub trait RefineObjectList<T>
 where
@@ -181,13 +207,30 @@ where
         Ok(())
     }
 
+    /// 提取查询时设置的 `start-after`
+    fn set_start_after(&mut self, _start_after: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// 提取查询时设置的 `delimiter`
+    fn set_delimiter(&mut self, _delimiter: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// 提取 object 列表
     fn set_list(&mut self, _list: Vec<T>) -> Result<(), Self::Error> {
         Ok(())
     }
 
+    /// 提取 `CommonPrefixes`，即使用 `delimiter` 查询时返回的“子目录”列表
+    fn set_common_prefix(&mut self, _list: &[Cow<'_, str>]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// # 由 xml 转 struct 的底层实现
     /// - `init_object` 用于初始化 object 结构体的方法
+    ///
+    /// `EncodingType` 可能出现在 `Prefix` 等元素之后，所以需要解码的值在读取完整个 xml 后再设置
     fn decode<F>(&mut self, xml: &str, mut init_object: F) -> Result<(), Self::Error>
     where
         F: FnMut() -> T,
@@ -204,12 +247,32 @@ where
         let mut etag = Cow::from(String::with_capacity(34));
         let mut size = Cow::from("");
         let mut storage_class = Cow::from(String::with_capacity(11));
+        let mut restore_info = Cow::from("");
+        let mut owner_id = Cow::from("");
+        let mut owner_display_name = Cow::from("");
+        let mut common_prefixes = Vec::new();
+        let mut in_common_prefixes = false;
+        let mut url_encoded = false;
+        let mut prefix = None;
+        let mut start_after = None;
+        let mut delimiter = None;
+        let mut next_continuation_token = None;
+        let mut keys = Vec::new();
 
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(e)) => {
                     match e.name().as_ref() {
-                        PREFIX => self.set_prefix(&reader.read_text(e.to_end().name())?)?,
+                        COMMON_PREFIXES => in_common_prefixes = true,
+                        PREFIX if in_common_prefixes => {
+                            common_prefixes.push(reader.read_text(e.to_end().name())?);
+                        }
+                        PREFIX => prefix = Some(reader.read_text(e.to_end().name())?),
+                        START_AFTER => start_after = Some(reader.read_text(e.to_end().name())?),
+                        DELIMITER => delimiter = Some(reader.read_text(e.to_end().name())?),
+                        ENCODING_TYPE => {
+                            url_encoded = reader.read_text(e.to_end().name())? == ENCODING_TYPE_URL;
+                        }
                         NAME => self.set_name(&reader.read_text(e.to_end().name())?)?,
                         MAX_KEYS => self.set_max_keys(&reader.read_text(e.to_end().name())?)?,
                         KEY_COUNT => self.set_key_count(&reader.read_text(e.to_end().name())?)?,
@@ -217,14 +280,7 @@ where
                             //is_truncated = reader.read_text(e.to_end().name())?.to_string() == "true"
                         }
                         NEXT_CONTINUATION_TOKEN => {
-                            let next_continuation_token = reader.read_text(e.to_end().name())?;
-                            self.set_next_continuation_token(
-                                if next_continuation_token.len() > 0 {
-                                    Some(&next_continuation_token)
-                                } else {
-                                    None
-                                },
-                            )?;
+                            next_continuation_token = Some(reader.read_text(e.to_end().name())?);
                         }
                         // b"Contents" => {
                         //     // key.clear();
@@ -246,21 +302,63 @@ where
                         STORAGE_CLASS => {
                             storage_class = reader.read_text(e.to_end().name())?;
                         }
+                        RESTORE_INFO => {
+                            restore_info = reader.read_text(e.to_end().name())?;
+                        }
+                        ID => owner_id = reader.read_text(e.to_end().name())?,
+                        DISPLAY_NAME => owner_display_name = reader.read_text(e.to_end().name())?,
                         _ => (),
                     }
                 }
                 Ok(Event::End(ref e)) if e.name().as_ref() == CONTENTS => {
                     let mut object = init_object();
-                    object.set_key(&key)?;
                     object.set_last_modified(&last_modified)?;
                     object.set_etag(&etag)?;
                     object.set_type(&_type)?;
                     object.set_size(&size)?;
                     object.set_storage_class(&storage_class)?;
+                    if !restore_info.is_empty() {
+                        object.set_restore_info(&restore_info)?;
+                        restore_info = Cow::from("");
+                    }
+                    if !owner_id.is_empty() {
+                        object.set_owner(&owner_id, &owner_display_name)?;
+                        owner_id = Cow::from("");
+                        owner_display_name = Cow::from("");
+                    }
                     result.push(object);
+                    keys.push(std::mem::take(&mut key));
+                }
+                Ok(Event::End(ref e)) if e.name().as_ref() == COMMON_PREFIXES => {
+                    in_common_prefixes = false;
                 }
                 Ok(Event::Eof) => {
+                    if let Some(prefix) = prefix {
+                        self.set_prefix(&decode_url(prefix, url_encoded))?;
+                    }
+                    if let Some(start_after) = start_after {
+                        self.set_start_after(&decode_url(start_after, url_encoded))?;
+                    }
+                    if let Some(delimiter) = delimiter {
+                        self.set_delimiter(&decode_url(delimiter, url_encoded))?;
+                    }
+                    if let Some(token) = next_continuation_token {
+                        let token = decode_url(token, url_encoded);
+                        self.set_next_continuation_token(if token.is_empty() {
+                            None
+                        } else {
+                            Some(&token)
+                        })?;
+                    }
+                    for (object, key) in result.iter_mut().zip(keys) {
+                        object.set_key(&decode_url(key, url_encoded))?;
+                    }
                     self.set_list(result)?;
+                    let common_prefixes: Vec<_> = common_prefixes
+                        .into_iter()
+                        .map(|prefix| decode_url(prefix, url_encoded))
+                        .collect();
+                    self.set_common_prefix(&common_prefixes)?;
                     break;
                 } // exits the loop when reaching end of file
                 Err(e) => {
@@ -691,7 +691,26 @@ impl Default for CanonicalizedResource {
 pub const CONTINUATION_TOKEN: &str = "continuation-token";
 pub const BUCKET_INFO: &str = "bucketInfo";
 #[cfg(feature = "core")]
-const QUERY_KEYWORD: [&str; 2] = ["acl", BUCKET_INFO];
+const QUERY_KEYWORD: [&str; 18] = [
+    "acl",
+    BUCKET_INFO,
+    "cors",
+    "encryption",
+    "lifecycle",
+    "location",
+    "logging",
+    "policy",
+    "referer",
+    "replication",
+    "replicationLocation",
+    "replicationProgress",
+    "requestPayment",
+    "stat",
+    "tagging",
+    "versioning",
+    "versions",
+    "website",
+];
 
 impl CanonicalizedResource {
     /// Creates a new `CanonicalizedResource` from the given string.
@@ -850,15 +869,25 @@ impl Query {
 
     /// 转化成 url 参数的形式
     /// a=foo&b=bar
-    /// 未进行 urlencode 转码
+    /// 未进行 urlencode 转码，按参数名排序，值为空的参数（如子资源 `acl`）只保留参数名
+    ///
+    /// ```
+    /// # use aliyun_oss_client::types::Query;
+    /// let query = Query::from_iter([("foo", "bar"), ("acl", "")]);
+    /// assert_eq!(query.to_url_query(), "acl&foo=bar");
+    /// ```
     pub fn to_url_query(&self) -> String {
-        self.inner
-            .iter()
+        let mut list: Vec<_> = self.inner.iter().collect();
+        list.sort_by(|(k1, _), (k2, _)| k1.as_ref().cmp(k2.as_ref()));
+
+        list.into_iter()
             .map(|(k, v)| {
                 let mut res = String::with_capacity(k.as_ref().len() + v.as_ref().len() + 1);
                 res.push_str(k.as_ref());
-                res.push('=');
-                res.push_str(v.as_ref());
+                if !v.as_ref().is_empty() {
+                    res.push('=');
+                    res.push_str(v.as_ref());
+                }
                 res
             })
             .collect::<Vec<_>>()
@@ -1088,8 +1117,7 @@ impl AsRef<str> for QueryKey {
             QueryKey::MaxKeys => "max-keys",
             QueryKey::Prefix => "prefix",
             QueryKey::EncodingType => "encoding-type",
-            // TODO
-            QueryKey::FetchOwner => unimplemented!("parse xml not support fetch owner"),
+            QueryKey::FetchOwner => "fetch-owner",
             QueryKey::Custom(ref str) => str,
         }
     }
@@ -1153,11 +1181,9 @@ impl QueryKey {
     ///
     /// let key = QueryKey::new("abc");
     /// assert_matches!(key, QueryKey::Custom(_));
-    /// ```
-    /// `fetch-owner` 功能未实现，特殊说明
-    /// ```should_panic
-    /// # use aliyun_oss_client::QueryKey;
+    ///
     /// let key = QueryKey::new("fetch-owner");
+    /// assert!(key == QueryKey::FetchOwner);
     /// ```
     pub fn new(val: impl Into<Cow<'static, str>>) -> Self {
         let val = val.into();
@@ -1174,7 +1200,7 @@ impl QueryKey {
         } else if val.contains("encoding-type") {
             Self::EncodingType
         } else if val.contains("fetch-owner") {
-            unimplemented!("parse xml not support fetch owner");
+            Self::FetchOwner
         } else {
             Self::Custom(val)
         }
@@ -1189,11 +1215,9 @@ impl QueryKey {
     ///
     /// let key = QueryKey::from_static("abc");
     /// assert_matches!(key, QueryKey::Custom(_));
-    /// ```
-    /// `fetch-owner` 功能未实现，特殊说明
-    /// ```should_panic
-    /// # use aliyun_oss_client::QueryKey;
+    ///
     /// let key = QueryKey::from_static("fetch-owner");
+    /// assert!(key == QueryKey::FetchOwner);
     /// ```
     pub fn from_static(val: &'static str) -> Self {
         if val.contains("delimiter") {
@@ -1209,7 +1233,7 @@ impl QueryKey {
         } else if val.contains("encoding-type") {
             Self::EncodingType
         } else if val.contains("fetch-owner") {
-            unimplemented!("parse xml not support fetch owner");
+            Self::FetchOwner
         } else {
             Self::Custom(Cow::Borrowed(val))
         }
@@ -1355,6 +1379,14 @@ pub struct ContentRange {
     end: Option<u32>,
 }
 
+impl ContentRange {
+    /// 范围的起始位置
+    #[cfg(feature = "encryption")]
+    pub(crate) fn start(&self) -> u32 {
+        self.start.unwrap_or_default()
+    }
+}
+
 impl From<Range<u32>> for ContentRange {
     fn from(r: Range<u32>) -> Self {
         Self {
@@ -0,0 +1,685 @@
+use std::fmt::{self, Display};
+#[cfg(feature = "blocking")]
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_stream::try_stream;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use http::Method;
+use quick_xml::{events::Event, Reader};
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+#[cfg(feature = "blocking")]
+use crate::file::blocking::AlignBuilder as BlockingAlignBuilder;
+use crate::{
+    bucket::Bucket,
+    builder::{ArcPointer, BuilderError, PointerFamily},
+    client::ClientArc,
+    config::BucketBase,
+    errors::{OssError, OssResult},
+    file::AlignBuilder,
+    object::InvalidObjectValue,
+    types::{CanonicalizedResource, Query, QueryKey, QueryValue},
+};
+
+const VERSIONING: &str = "versioning";
+const VERSIONS: &str = "versions";
+
+/// 列举版本时的翻页参数
+pub const KEY_MARKER: &str = "key-marker";
+/// 列举版本时的翻页参数，需与 [`KEY_MARKER`] 同时使用
+pub const VERSION_ID_MARKER: &str = "version-id-marker";
+
+const STATUS: &[u8] = b"Status";
+const LIST_VERSIONS_RESULT: &[u8] = b"ListVersionsResult";
+const VERSION: &[u8] = b"Version";
+const DELETE_MARKER: &[u8] = b"DeleteMarker";
+const COMMON_PREFIXES: &[u8] = b"CommonPrefixes";
+const NAME: &[u8] = b"Name";
+const PREFIX: &[u8] = b"Prefix";
+const KEY: &[u8] = b"Key";
+const VERSION_ID: &[u8] = b"VersionId";
+const IS_LATEST: &[u8] = b"IsLatest";
+const LAST_MODIFIED: &[u8] = b"LastModified";
+const ETAG: &[u8] = b"ETag";
+const SIZE: &[u8] = b"Size";
+const STORAGE_CLASS: &[u8] = b"StorageClass";
+const IS_TRUNCATED: &[u8] = b"IsTruncated";
+const NEXT_KEY_MARKER: &[u8] = b"NextKeyMarker";
+const NEXT_VERSION_ID_MARKER: &[u8] = b"NextVersionIdMarker";
+
+/// # bucket 的版本控制状态
+/// 开启后只能暂停，不能关闭
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersioningStatus {
+    Enabled,
+    Suspended,
+}
+
+const STATUS_ENABLED: &str = "Enabled";
+const STATUS_SUSPENDED: &str = "Suspended";
+
+impl AsRef<str> for VersioningStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Enabled => STATUS_ENABLED,
+            Self::Suspended => STATUS_SUSPENDED,
+        }
+    }
+}
+
+impl Display for VersioningStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for VersioningStatus {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::versioning::VersioningStatus;
+    /// let status: VersioningStatus = "Enabled".parse().unwrap();
+    /// assert_eq!(status, VersioningStatus::Enabled);
+    /// assert!("foo".parse::<VersioningStatus>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            STATUS_ENABLED => Ok(Self::Enabled),
+            STATUS_SUSPENDED => Ok(Self::Suspended),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
+impl VersioningStatus {
+    pub(crate) fn to_xml(&self) -> String {
+        format!(
+            "<VersioningConfiguration><Status>{}</Status></VersioningConfiguration>",
+            self
+        )
+    }
+
+    /// 解析 GetBucketVersioning 接口返回的 xml，从未开启过版本控制时返回 `None`
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Option<Self>> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.name().as_ref() == STATUS => {
+                    let text = reader.read_text(e.to_end().name())?;
+                    if text.is_empty() {
+                        return Ok(None);
+                    }
+                    return Ok(Some(text.parse()?));
+                }
+                Event::Eof => return Ok(None),
+                _ => (),
+            }
+            buf.clear();
+        }
+    }
+}
+
+/// # 文件的一个版本
+/// 删除标记没有内容，其 `etag`、`size`、`storage_class` 均为默认值
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ObjectVersion {
+    pub key: String,
+    pub version_id: String,
+    /// 是否为当前版本
+    pub is_latest: bool,
+    /// 是否为删除标记
+    pub is_delete_marker: bool,
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    pub size: u64,
+    pub storage_class: String,
+}
+
+/// # 列举 bucket 中文件的所有版本
+/// 对应 `GET /?versions` 接口，结果未列举完时可通过 [`get_next_list`] 获取下一页，
+/// 或通过 [`into_stream`]、[`into_version_stream`] 自动翻页
+///
+/// [`get_next_list`]: ObjectVersions::get_next_list
+/// [`into_stream`]: ObjectVersions::into_stream
+/// [`into_version_stream`]: ObjectVersions::into_version_stream
+#[non_exhaustive]
+pub struct ObjectVersions<PointerSel: PointerFamily = ArcPointer> {
+    pub name: String,
+    pub prefix: String,
+    pub is_truncated: bool,
+    pub next_key_marker: Option<String>,
+    pub next_version_id_marker: Option<String>,
+    /// 文件版本及删除标记，按服务端返回的顺序排列
+    pub versions: Vec<ObjectVersion>,
+    pub common_prefixes: Vec<String>,
+    bucket: BucketBase,
+    client: PointerSel::PointerType,
+    search_query: Query,
+}
+
+impl<T: PointerFamily> fmt::Debug for ObjectVersions<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObjectVersions")
+            .field("name", &self.name)
+            .field("prefix", &self.prefix)
+            .field("is_truncated", &self.is_truncated)
+            .field("next_key_marker", &self.next_key_marker)
+            .field("next_version_id_marker", &self.next_version_id_marker)
+            .field("versions", &self.versions)
+            .field("common_prefixes", &self.common_prefixes)
+            .field("search_query", &self.search_query)
+            .finish()
+    }
+}
+
+impl<T: PointerFamily> Clone for ObjectVersions<T>
+where
+    T::PointerType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            prefix: self.prefix.clone(),
+            is_truncated: self.is_truncated,
+            next_key_marker: self.next_key_marker.clone(),
+            next_version_id_marker: self.next_version_id_marker.clone(),
+            versions: self.versions.clone(),
+            common_prefixes: self.common_prefixes.clone(),
+            bucket: self.bucket.clone(),
+            client: self.client.clone(),
+            search_query: self.search_query.clone(),
+        }
+    }
+}
+
+impl<T: PointerFamily> Default for ObjectVersions<T>
+where
+    T::PointerType: Default,
+{
+    fn default() -> Self {
+        Self {
+            name: String::default(),
+            prefix: String::default(),
+            is_truncated: false,
+            next_key_marker: None,
+            next_version_id_marker: None,
+            versions: Vec::new(),
+            common_prefixes: Vec::new(),
+            bucket: BucketBase::default(),
+            client: T::PointerType::default(),
+            search_query: Query::default(),
+        }
+    }
+}
+
+impl<T: PointerFamily> ObjectVersions<T> {
+    /// 获取下一页的查询条件，已列举完时返回 `None`
+    pub fn next_query(&self) -> Option<Query> {
+        if !self.is_truncated {
+            return None;
+        }
+
+        let mut query = self.search_query.clone();
+        query.insert(KEY_MARKER, self.next_key_marker.clone()?);
+        match &self.next_version_id_marker {
+            Some(marker) => query.insert(VERSION_ID_MARKER, marker.clone()),
+            None => {
+                query.remove(VERSION_ID_MARKER);
+            }
+        }
+        Some(query)
+    }
+}
+
+impl<T: PointerFamily> ObjectVersions<T>
+where
+    T::PointerType: Default,
+{
+    /// 解析 ListObjectVersions 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str, search_query: Query) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut list = Self {
+            search_query,
+            ..Self::default()
+        };
+        let mut current: Option<ObjectVersion> = None;
+        let mut in_common_prefixes = false;
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    LIST_VERSIONS_RESULT => (),
+                    VERSION => current = Some(ObjectVersion::default()),
+                    DELETE_MARKER => {
+                        current = Some(ObjectVersion {
+                            is_delete_marker: true,
+                            ..ObjectVersion::default()
+                        })
+                    }
+                    COMMON_PREFIXES => in_common_prefixes = true,
+                    name => {
+                        let text = reader.read_text(e.to_end().name())?;
+                        match (current.as_mut(), name) {
+                            (Some(version), KEY) => version.key = text.into_owned(),
+                            (Some(version), VERSION_ID) => version.version_id = text.into_owned(),
+                            (Some(version), IS_LATEST) => version.is_latest = text == "true",
+                            (Some(version), LAST_MODIFIED) => {
+                                version.last_modified =
+                                    text.parse::<DateTime<Utc>>().map_err(OssError::from)?
+                            }
+                            (Some(version), ETAG) => version.etag = text.into_owned(),
+                            (Some(version), SIZE) => version.size = text.parse()?,
+                            (Some(version), STORAGE_CLASS) => {
+                                version.storage_class = text.into_owned()
+                            }
+                            (Some(_), _) => (),
+                            (None, PREFIX) if in_common_prefixes => {
+                                list.common_prefixes.push(text.into_owned())
+                            }
+                            (None, NAME) => list.name = text.into_owned(),
+                            (None, PREFIX) => list.prefix = text.into_owned(),
+                            (None, IS_TRUNCATED) => list.is_truncated = text == "true",
+                            (None, NEXT_KEY_MARKER) if !text.is_empty() => {
+                                list.next_key_marker = Some(text.into_owned())
+                            }
+                            (None, NEXT_VERSION_ID_MARKER) if !text.is_empty() => {
+                                list.next_version_id_marker = Some(text.into_owned())
+                            }
+                            _ => (),
+                        }
+                    }
+                },
+                Event::End(e) => match e.name().as_ref() {
+                    VERSION | DELETE_MARKER => list.versions.extend(current.take()),
+                    COMMON_PREFIXES => in_common_prefixes = false,
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(list)
+    }
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的版本控制状态
+    /// 从未开启过版本控制时返回 `None`
+    pub async fn get_bucket_versioning(&self) -> OssResult<Option<VersioningStatus>> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(VERSIONING);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+            .await?
+            .text()
+            .await?;
+
+        VersioningStatus::from_xml(&content)
+    }
+
+    /// # 开启或暂停默认 bucket 的版本控制
+    pub async fn put_bucket_versioning(&self, status: VersioningStatus) -> OssResult<()> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(VERSIONING);
+
+        self.builder(Method::PUT, url, canonicalized)?
+            .body(status.to_xml())
+            .send_adjust_error()
+            .await?;
+
+        Ok(())
+    }
+
+    /// # 列举默认 bucket 中文件的所有版本
+    /// 支持 `prefix`、`delimiter`、`max-keys`、[`KEY_MARKER`]、[`VERSION_ID_MARKER`] 等查询条件
+    pub async fn get_object_versions<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        &self,
+        query: Q,
+    ) -> OssResult<ObjectVersions> {
+        get_versions(
+            Arc::new(self.clone()),
+            self.get_bucket_base(),
+            Query::from_iter(query),
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的版本控制状态
+    /// 从未开启过版本控制时返回 `None`
+    pub fn get_bucket_versioning(&self) -> OssResult<Option<VersioningStatus>> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(VERSIONING);
+
+        let content = self
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()?;
+
+        VersioningStatus::from_xml(&content)
+    }
+
+    /// # 开启或暂停默认 bucket 的版本控制
+    pub fn put_bucket_versioning(&self, status: VersioningStatus) -> OssResult<()> {
+        let (url, canonicalized) = self.get_bucket_sub_resource(VERSIONING);
+
+        self.builder(Method::PUT, url, canonicalized)?
+            .body(status.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    /// # 列举默认 bucket 中文件的所有版本
+    /// 支持 `prefix`、`delimiter`、`max-keys`、[`KEY_MARKER`]、[`VERSION_ID_MARKER`] 等查询条件
+    pub fn get_object_versions<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        &self,
+        query: Q,
+    ) -> OssResult<ObjectVersions<RcPointer>> {
+        blocking::get_versions(
+            Rc::new(self.clone()),
+            self.get_bucket_base(),
+            Query::from_iter(query),
+        )
+    }
+}
+
+impl ObjectVersions {
+    /// 获取下一页的版本列表，没有下一页时返回 [`OssError::WithoutMore`]
+    pub async fn get_next_list(&self) -> OssResult<Self> {
+        match self.next_query() {
+            None => Err(OssError::WithoutMore),
+            Some(query) => get_versions(Arc::clone(&self.client), self.bucket.clone(), query).await,
+        }
+    }
+
+    /// # 将版本列表转化为 stream, 返回第二页，第三页... 的内容
+    /// 依次请求下一页，直到没有更多内容时结束
+    pub fn into_stream(self) -> impl Stream<Item = OssResult<Self>> {
+        try_stream! {
+            let mut page = self;
+            while page.next_query().is_some() {
+                page = page.get_next_list().await?;
+                yield page.clone();
+            }
+        }
+    }
+
+    /// # 将版本列表展开为文件版本的 stream
+    /// 先返回当前页的版本，再依次返回后续每一页的版本
+    pub fn into_version_stream(self) -> impl Stream<Item = OssResult<ObjectVersion>> {
+        try_stream! {
+            let mut page = self;
+            loop {
+                for version in std::mem::take(&mut page.versions) {
+                    yield version;
+                }
+                if page.next_query().is_none() {
+                    break;
+                }
+                page = page.get_next_list().await?;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ObjectVersions<RcPointer> {
+    /// 获取下一页的版本列表，没有下一页时返回 [`OssError::WithoutMore`]
+    pub fn get_next_list(&self) -> OssResult<Self> {
+        match self.next_query() {
+            None => Err(OssError::WithoutMore),
+            Some(query) => {
+                blocking::get_versions(Rc::clone(&self.client), self.bucket.clone(), query)
+            }
+        }
+    }
+
+    /// # 将版本列表展开为文件版本的迭代器
+    /// 先返回当前页的版本，再依次返回后续每一页的版本，请求出错后迭代结束
+    pub fn into_version_iter(mut self) -> impl Iterator<Item = OssResult<ObjectVersion>> {
+        let mut versions = std::mem::take(&mut self.versions).into_iter();
+        let mut page = Some(self);
+
+        std::iter::from_fn(move || loop {
+            if let Some(version) = versions.next() {
+                return Some(Ok(version));
+            }
+
+            let current = page.take()?;
+            current.next_query()?;
+            match current.get_next_list() {
+                Ok(mut next) => {
+                    versions = std::mem::take(&mut next.versions).into_iter();
+                    page = Some(next);
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+}
+
+impl Bucket {
+    /// # 列举 bucket 中文件的所有版本
+    /// 支持 `prefix`、`delimiter`、`max-keys`、[`KEY_MARKER`]、[`VERSION_ID_MARKER`] 等查询条件
+    pub async fn get_object_versions<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        &self,
+        query: Q,
+    ) -> OssResult<ObjectVersions> {
+        get_versions(self.client(), self.base.clone(), Query::from_iter(query)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 列举 bucket 中文件的所有版本
+    /// 支持 `prefix`、`delimiter`、`max-keys`、[`KEY_MARKER`]、[`VERSION_ID_MARKER`] 等查询条件
+    pub fn get_object_versions<Q: IntoIterator<Item = (QueryKey, QueryValue)>>(
+        &self,
+        query: Q,
+    ) -> OssResult<ObjectVersions<RcPointer>> {
+        blocking::get_versions(self.client(), self.base.clone(), Query::from_iter(query))
+    }
+}
+
+async fn get_versions(
+    client: Arc<ClientArc>,
+    bucket: BucketBase,
+    query: Query,
+) -> OssResult<ObjectVersions> {
+    let (url, canonicalized) = versions_url(bucket.get_sub_resource(VERSIONS), &query);
+
+    let content = client
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await?
+        .text()
+        .await
+        .map_err(BuilderError::from)?;
+
+    let mut list = ObjectVersions::from_xml(&content, query)?;
+    list.bucket = bucket;
+    list.client = client;
+    Ok(list)
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use std::rc::Rc;
+
+    use http::Method;
+
+    use super::{versions_url, ObjectVersions, VERSIONS};
+    use crate::{
+        builder::{BuilderError, RcPointer},
+        client::ClientRc,
+        config::BucketBase,
+        errors::OssResult,
+        file::blocking::AlignBuilder,
+        types::Query,
+    };
+
+    pub(super) fn get_versions(
+        client: Rc<ClientRc>,
+        bucket: BucketBase,
+        query: Query,
+    ) -> OssResult<ObjectVersions<RcPointer>> {
+        let (url, canonicalized) = versions_url(bucket.get_sub_resource(VERSIONS), &query);
+
+        let content = client
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()?
+            .text()
+            .map_err(BuilderError::from)?;
+
+        let mut list = ObjectVersions::from_xml(&content, query)?;
+        list.bucket = bucket;
+        list.client = client;
+        Ok(list)
+    }
+}
+
+/// 查询条件只附加在 url 上，不参与签名
+fn versions_url(
+    (mut url, canonicalized): (reqwest::Url, CanonicalizedResource),
+    query: &Query,
+) -> (reqwest::Url, CanonicalizedResource) {
+    let mut list: Vec<_> = query.clone().into_iter().collect();
+    list.sort_by(|(k1, _), (k2, _)| k1.as_ref().cmp(k2.as_ref()));
+
+    url.query_pairs_mut()
+        .extend_pairs(list.iter().map(|(k, v)| (k.as_ref(), v.as_ref())));
+
+    (url, canonicalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::{ObjectVersions, VersioningStatus, KEY_MARKER, VERSION_ID_MARKER};
+    use crate::types::Query;
+
+    #[test]
+    fn test_versioning_status_xml() {
+        assert_eq!(
+            VersioningStatus::Suspended.to_xml(),
+            "<VersioningConfiguration><Status>Suspended</Status></VersioningConfiguration>"
+        );
+        assert_eq!(
+            VersioningStatus::from_xml(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <VersioningConfiguration><Status>Enabled</Status></VersioningConfiguration>"#
+            )
+            .unwrap(),
+            Some(VersioningStatus::Enabled)
+        );
+        assert_eq!(
+            VersioningStatus::from_xml(
+                r#"<?xml version="1.0" encoding="UTF-8"?><VersioningConfiguration/>"#
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_object_versions_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListVersionsResult>
+            <Name>foo4</Name>
+            <Prefix>example</Prefix>
+            <KeyMarker></KeyMarker>
+            <VersionIdMarker></VersionIdMarker>
+            <MaxKeys>2</MaxKeys>
+            <Delimiter>/</Delimiter>
+            <IsTruncated>true</IsTruncated>
+            <NextKeyMarker>example.txt</NextKeyMarker>
+            <NextVersionIdMarker>CAEQMxiBgICbof2D0BYiIGRhZjgwMzJiMjA3MjQ0ODE5MWYxZDYwMzJlZjU1****</NextVersionIdMarker>
+            <DeleteMarker>
+                <Key>example</Key>
+                <VersionId>CAEQMxiBgICAof2D0BYiIDJhMGE3N2M1YTI1NDQzOGY5NTkyNTI3MGYyMzJm****</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                <Owner>
+                    <ID>1234512528586****</ID>
+                    <DisplayName>12345125285864390</DisplayName>
+                </Owner>
+            </DeleteMarker>
+            <Version>
+                <Key>example.txt</Key>
+                <VersionId>CAEQMxiBgMDNoP2D0BYiIDE3MWUxNzgxZDQxNTRiODI5OGYwZGMwNGY3MzZj****</VersionId>
+                <IsLatest>false</IsLatest>
+                <LastModified>2019-04-09T07:27:28.000Z</LastModified>
+                <ETag>"250F8A0AE989679A22926A875F0A2****"</ETag>
+                <Type>Normal</Type>
+                <Size>93731</Size>
+                <StorageClass>Standard</StorageClass>
+                <Owner>
+                    <ID>1234512528586****</ID>
+                    <DisplayName>12345125285864390</DisplayName>
+                </Owner>
+            </Version>
+            <CommonPrefixes>
+                <Prefix>example/dir/</Prefix>
+            </CommonPrefixes>
+        </ListVersionsResult>"#;
+
+        let list: ObjectVersions =
+            ObjectVersions::from_xml(xml, Query::from_iter([("prefix", "example")])).unwrap();
+
+        assert_eq!(list.name, "foo4");
+        assert_eq!(list.prefix, "example");
+        assert!(list.is_truncated);
+        assert_eq!(list.common_prefixes, vec!["example/dir/".to_string()]);
+        assert_eq!(list.versions.len(), 2);
+
+        let marker = &list.versions[0];
+        assert!(marker.is_delete_marker);
+        assert!(marker.is_latest);
+        assert_eq!(marker.key, "example");
+        assert_eq!(marker.size, 0);
+
+        let version = &list.versions[1];
+        assert!(!version.is_delete_marker);
+        assert!(!version.is_latest);
+        assert_eq!(version.key, "example.txt");
+        assert_eq!(version.size, 93731);
+        assert_eq!(version.etag, "\"250F8A0AE989679A22926A875F0A2****\"");
+        assert_eq!(version.storage_class, "Standard");
+        assert_eq!(
+            version.last_modified,
+            "2019-04-09T07:27:28.000Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let query = list.next_query().unwrap();
+        assert_eq!(query.get("prefix").unwrap().as_ref(), "example");
+        assert_eq!(query.get(KEY_MARKER).unwrap().as_ref(), "example.txt");
+        assert_eq!(
+            query.get(VERSION_ID_MARKER).unwrap().as_ref(),
+            "CAEQMxiBgICbof2D0BYiIGRhZjgwMzJiMjA3MjQ0ODE5MWYxZDYwMzJlZjU1****"
+        );
+
+        let list: ObjectVersions = ObjectVersions::from_xml(
+            "<ListVersionsResult><IsTruncated>false</IsTruncated></ListVersionsResult>",
+            Query::new(),
+        )
+        .unwrap();
+        assert!(list.next_query().is_none());
+    }
+}
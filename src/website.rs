@@ -0,0 +1,816 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use http::Method;
+use quick_xml::{
+    escape::{escape, unescape},
+    events::Event,
+    Reader,
+};
+
+use reqwest::Url;
+
+#[cfg(feature = "blocking")]
+use crate::builder::RcPointer;
+#[cfg(feature = "blocking")]
+use crate::client::ClientRc;
+use crate::{
+    bucket::Bucket, builder::BuilderError, client::ClientArc, errors::OssResult,
+    file::AlignBuilder, object::InvalidObjectValue, types::CanonicalizedResource,
+};
+
+const WEBSITE: &str = "website";
+const NO_SUCH_WEBSITE_CONFIGURATION: &str = "NoSuchWebsiteConfiguration";
+
+const WEBSITE_CONFIGURATION: &[u8] = b"WebsiteConfiguration";
+const INDEX_DOCUMENT: &[u8] = b"IndexDocument";
+const ERROR_DOCUMENT: &[u8] = b"ErrorDocument";
+const ROUTING_RULES: &[u8] = b"RoutingRules";
+const ROUTING_RULE: &[u8] = b"RoutingRule";
+const CONDITION: &[u8] = b"Condition";
+const INCLUDE_HEADER: &[u8] = b"IncludeHeader";
+const REDIRECT: &[u8] = b"Redirect";
+const MIRROR_HEADERS: &[u8] = b"MirrorHeaders";
+const SET: &[u8] = b"Set";
+
+const SUFFIX: &[u8] = b"Suffix";
+const SUPPORT_SUB_DIR: &[u8] = b"SupportSubDir";
+const TYPE: &[u8] = b"Type";
+const KEY: &[u8] = b"Key";
+const VALUE: &[u8] = b"Value";
+const HTTP_STATUS: &[u8] = b"HttpStatus";
+const RULE_NUMBER: &[u8] = b"RuleNumber";
+const KEY_PREFIX_EQUALS: &[u8] = b"KeyPrefixEquals";
+const KEY_SUFFIX_EQUALS: &[u8] = b"KeySuffixEquals";
+const HTTP_ERROR_CODE_RETURNED_EQUALS: &[u8] = b"HttpErrorCodeReturnedEquals";
+const EQUALS: &[u8] = b"Equals";
+const STARTS_WITH: &[u8] = b"StartsWith";
+const ENDS_WITH: &[u8] = b"EndsWith";
+const REDIRECT_TYPE: &[u8] = b"RedirectType";
+const PASS_QUERY_STRING: &[u8] = b"PassQueryString";
+const REPLACE_KEY_WITH: &[u8] = b"ReplaceKeyWith";
+const REPLACE_KEY_PREFIX_WITH: &[u8] = b"ReplaceKeyPrefixWith";
+const ENABLE_REPLACE_PREFIX: &[u8] = b"EnableReplacePrefix";
+const PROTOCOL: &[u8] = b"Protocol";
+const HOST_NAME: &[u8] = b"HostName";
+const HTTP_REDIRECT_CODE: &[u8] = b"HttpRedirectCode";
+const MIRROR_URL: &[u8] = b"MirrorURL";
+const MIRROR_PASS_QUERY_STRING: &[u8] = b"MirrorPassQueryString";
+const MIRROR_FOLLOW_REDIRECT: &[u8] = b"MirrorFollowRedirect";
+const MIRROR_CHECK_MD5: &[u8] = b"MirrorCheckMd5";
+const PASS_ALL: &[u8] = b"PassAll";
+const PASS: &[u8] = b"Pass";
+const REMOVE: &[u8] = b"Remove";
+
+/// # 静态网站的默认首页
+/// `support_sub_dir` 为 `true` 时，访问子目录也会跳转到子目录下的默认首页
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexDocument {
+    pub suffix: String,
+    pub support_sub_dir: bool,
+    /// 开启子目录首页后，默认首页不存在时的行为，取值为 0、1、2
+    pub index_type: Option<u8>,
+}
+
+impl IndexDocument {
+    pub fn new<S: Into<String>>(suffix: S) -> Self {
+        Self {
+            suffix: suffix.into(),
+            ..Self::default()
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!("<IndexDocument><Suffix>{}</Suffix>", escape(&self.suffix));
+        if self.support_sub_dir {
+            xml.push_str("<SupportSubDir>true</SupportSubDir>");
+        }
+        push_tag(&mut xml, "Type", self.index_type);
+        xml.push_str("</IndexDocument>");
+        xml
+    }
+}
+
+/// # 静态网站的默认 404 页
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorDocument {
+    pub key: String,
+    /// 返回 404 页时的状态码，默认为 404
+    pub http_status: Option<u16>,
+}
+
+impl ErrorDocument {
+    pub fn new<K: Into<String>>(key: K) -> Self {
+        Self {
+            key: key.into(),
+            http_status: None,
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!("<ErrorDocument><Key>{}</Key>", escape(&self.key));
+        push_tag(&mut xml, "HttpStatus", self.http_status);
+        xml.push_str("</ErrorDocument>");
+        xml
+    }
+}
+
+/// # 按请求 header 匹配路由规则
+/// `equals`、`starts_with`、`ends_with` 只能设置其中一项
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IncludeHeader {
+    pub key: String,
+    pub equals: Option<String>,
+    pub starts_with: Option<String>,
+    pub ends_with: Option<String>,
+}
+
+impl IncludeHeader {
+    fn to_xml(&self) -> String {
+        let mut xml = format!("<IncludeHeader><Key>{}</Key>", escape(&self.key));
+        push_text(&mut xml, "Equals", &self.equals);
+        push_text(&mut xml, "StartsWith", &self.starts_with);
+        push_text(&mut xml, "EndsWith", &self.ends_with);
+        xml.push_str("</IncludeHeader>");
+        xml
+    }
+}
+
+/// # 路由规则的匹配条件
+/// 所有设置的条件都满足时才会执行跳转
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoutingCondition {
+    pub key_prefix_equals: Option<String>,
+    pub key_suffix_equals: Option<String>,
+    pub http_error_code_returned_equals: Option<u16>,
+    pub include_headers: Vec<IncludeHeader>,
+}
+
+impl RoutingCondition {
+    fn to_xml(&self) -> String {
+        let mut xml = String::from("<Condition>");
+        push_text(&mut xml, "KeyPrefixEquals", &self.key_prefix_equals);
+        push_text(&mut xml, "KeySuffixEquals", &self.key_suffix_equals);
+        push_tag(
+            &mut xml,
+            "HttpErrorCodeReturnedEquals",
+            self.http_error_code_returned_equals,
+        );
+        for header in self.include_headers.iter() {
+            xml.push_str(&header.to_xml());
+        }
+        xml.push_str("</Condition>");
+        xml
+    }
+}
+
+/// # 路由规则的跳转方式
+/// - `Mirror` 镜像回源
+/// - `External` 外部跳转
+/// - `AliCDN` 阿里云 CDN 跳转
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum RedirectType {
+    Mirror,
+    #[default]
+    External,
+    AliCDN,
+}
+
+const REDIRECT_MIRROR: &str = "Mirror";
+const REDIRECT_EXTERNAL: &str = "External";
+const REDIRECT_ALICDN: &str = "AliCDN";
+
+impl AsRef<str> for RedirectType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Mirror => REDIRECT_MIRROR,
+            Self::External => REDIRECT_EXTERNAL,
+            Self::AliCDN => REDIRECT_ALICDN,
+        }
+    }
+}
+
+impl Display for RedirectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl FromStr for RedirectType {
+    type Err = InvalidObjectValue;
+    /// ```
+    /// # use aliyun_oss_client::website::RedirectType;
+    /// assert_eq!("AliCDN".parse::<RedirectType>().unwrap(), RedirectType::AliCDN);
+    /// assert!("foo".parse::<RedirectType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            REDIRECT_MIRROR => Ok(Self::Mirror),
+            REDIRECT_EXTERNAL => Ok(Self::External),
+            REDIRECT_ALICDN => Ok(Self::AliCDN),
+            _ => Err(InvalidObjectValue),
+        }
+    }
+}
+
+/// # 镜像回源时对请求 header 的处理
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MirrorHeaders {
+    /// 是否透传除特定 header 以外的所有 header 到源站
+    pub pass_all: bool,
+    pub pass: Vec<String>,
+    pub remove: Vec<String>,
+    /// 设置后发送到源站的 header，会覆盖请求中的同名 header
+    pub set: Vec<(String, String)>,
+}
+
+impl MirrorHeaders {
+    fn to_xml(&self) -> String {
+        let mut xml = String::from("<MirrorHeaders>");
+        if self.pass_all {
+            xml.push_str("<PassAll>true</PassAll>");
+        }
+        for key in self.pass.iter() {
+            xml.push_str(&format!("<Pass>{}</Pass>", escape(key)));
+        }
+        for key in self.remove.iter() {
+            xml.push_str(&format!("<Remove>{}</Remove>", escape(key)));
+        }
+        for (key, value) in self.set.iter() {
+            xml.push_str(&format!(
+                "<Set><Key>{}</Key><Value>{}</Value></Set>",
+                escape(key),
+                escape(value)
+            ));
+        }
+        xml.push_str("</MirrorHeaders>");
+        xml
+    }
+}
+
+/// # 路由规则的跳转动作
+/// 以 `mirror_` 开头的项只在镜像回源时生效
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Redirect {
+    pub redirect_type: RedirectType,
+    pub pass_query_string: Option<bool>,
+    pub replace_key_with: Option<String>,
+    pub replace_key_prefix_with: Option<String>,
+    pub enable_replace_prefix: Option<bool>,
+    pub protocol: Option<String>,
+    pub host_name: Option<String>,
+    pub http_redirect_code: Option<u16>,
+    pub mirror_url: Option<String>,
+    pub mirror_pass_query_string: Option<bool>,
+    pub mirror_follow_redirect: Option<bool>,
+    pub mirror_check_md5: Option<bool>,
+    pub mirror_headers: Option<MirrorHeaders>,
+}
+
+impl Redirect {
+    pub fn new(redirect_type: RedirectType) -> Self {
+        Self {
+            redirect_type,
+            ..Self::default()
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!(
+            "<Redirect><RedirectType>{}</RedirectType>",
+            self.redirect_type
+        );
+        push_tag(&mut xml, "PassQueryString", self.pass_query_string);
+        push_text(&mut xml, "MirrorURL", &self.mirror_url);
+        push_tag(
+            &mut xml,
+            "MirrorPassQueryString",
+            self.mirror_pass_query_string,
+        );
+        push_tag(
+            &mut xml,
+            "MirrorFollowRedirect",
+            self.mirror_follow_redirect,
+        );
+        push_tag(&mut xml, "MirrorCheckMd5", self.mirror_check_md5);
+        if let Some(headers) = &self.mirror_headers {
+            xml.push_str(&headers.to_xml());
+        }
+        push_text(&mut xml, "Protocol", &self.protocol);
+        push_text(&mut xml, "HostName", &self.host_name);
+        push_tag(&mut xml, "HttpRedirectCode", self.http_redirect_code);
+        push_text(
+            &mut xml,
+            "ReplaceKeyPrefixWith",
+            &self.replace_key_prefix_with,
+        );
+        push_tag(&mut xml, "EnableReplacePrefix", self.enable_replace_prefix);
+        push_text(&mut xml, "ReplaceKeyWith", &self.replace_key_with);
+        xml.push_str("</Redirect>");
+        xml
+    }
+}
+
+/// # 静态网站的路由规则
+/// 按 `rule_number` 从小到大依次匹配，只执行第一条匹配的规则
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoutingRule {
+    pub rule_number: u32,
+    pub condition: RoutingCondition,
+    pub redirect: Redirect,
+}
+
+impl RoutingRule {
+    pub fn new(rule_number: u32, condition: RoutingCondition, redirect: Redirect) -> Self {
+        Self {
+            rule_number,
+            condition,
+            redirect,
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<RoutingRule><RuleNumber>{}</RuleNumber>{}{}</RoutingRule>",
+            self.rule_number,
+            self.condition.to_xml(),
+            self.redirect.to_xml()
+        )
+    }
+}
+
+/// # 静态网站托管设置
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebsiteConfiguration {
+    pub index_document: Option<IndexDocument>,
+    pub error_document: Option<ErrorDocument>,
+    pub routing_rules: Vec<RoutingRule>,
+}
+
+impl WebsiteConfiguration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn index_document(mut self, index: IndexDocument) -> Self {
+        self.index_document = Some(index);
+        self
+    }
+
+    pub fn error_document(mut self, error: ErrorDocument) -> Self {
+        self.error_document = Some(error);
+        self
+    }
+
+    /// 添加路由规则，可多次调用
+    pub fn routing_rule(mut self, rule: RoutingRule) -> Self {
+        self.routing_rules.push(rule);
+        self
+    }
+
+    /// 转化为 PutBucketWebsite 接口的请求体
+    pub(crate) fn to_xml(&self) -> String {
+        let mut xml = String::from("<WebsiteConfiguration>");
+        if let Some(index) = &self.index_document {
+            xml.push_str(&index.to_xml());
+        }
+        if let Some(error) = &self.error_document {
+            xml.push_str(&error.to_xml());
+        }
+        if !self.routing_rules.is_empty() {
+            xml.push_str("<RoutingRules>");
+            for rule in self.routing_rules.iter() {
+                xml.push_str(&rule.to_xml());
+            }
+            xml.push_str("</RoutingRules>");
+        }
+        xml.push_str("</WebsiteConfiguration>");
+        xml
+    }
+
+    /// 解析 GetBucketWebsite 接口返回的 xml
+    pub(crate) fn from_xml(xml: &str) -> OssResult<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::with_capacity(xml.len());
+
+        let mut config = Self::default();
+        // 当前所在的父节点，用于区分同名的子节点，如 `Key`
+        let mut parents: Vec<Vec<u8>> = Vec::new();
+        let mut rule = RoutingRule::default();
+        let mut header = IncludeHeader::default();
+        let mut set = (String::new(), String::new());
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.name().as_ref() {
+                    name @ (WEBSITE_CONFIGURATION | ROUTING_RULES | CONDITION | REDIRECT) => {
+                        parents.push(name.to_vec())
+                    }
+                    name @ (INDEX_DOCUMENT | ERROR_DOCUMENT | ROUTING_RULE | INCLUDE_HEADER
+                    | MIRROR_HEADERS | SET) => {
+                        match name {
+                            INDEX_DOCUMENT => {
+                                config.index_document = Some(IndexDocument::default())
+                            }
+                            ERROR_DOCUMENT => {
+                                config.error_document = Some(ErrorDocument::default())
+                            }
+                            ROUTING_RULE => rule = RoutingRule::default(),
+                            INCLUDE_HEADER => header = IncludeHeader::default(),
+                            MIRROR_HEADERS => {
+                                rule.redirect.mirror_headers = Some(MirrorHeaders::default())
+                            }
+                            _ => set = (String::new(), String::new()),
+                        }
+                        parents.push(name.to_vec());
+                    }
+                    name => {
+                        let raw = reader.read_text(e.to_end().name())?;
+                        let text = unescape(&raw).map_err(quick_xml::Error::from)?;
+                        let parent = parents.last().map(Vec::as_slice).unwrap_or_default();
+                        match (parent, name) {
+                            (INDEX_DOCUMENT, _) => {
+                                if let Some(index) = config.index_document.as_mut() {
+                                    match name {
+                                        SUFFIX => index.suffix = text.into_owned(),
+                                        SUPPORT_SUB_DIR => index.support_sub_dir = text == "true",
+                                        TYPE => index.index_type = Some(text.parse()?),
+                                        _ => (),
+                                    }
+                                }
+                            }
+                            (ERROR_DOCUMENT, _) => {
+                                if let Some(error) = config.error_document.as_mut() {
+                                    match name {
+                                        KEY => error.key = text.into_owned(),
+                                        HTTP_STATUS => error.http_status = Some(text.parse()?),
+                                        _ => (),
+                                    }
+                                }
+                            }
+                            (ROUTING_RULE, RULE_NUMBER) => rule.rule_number = text.parse()?,
+                            (CONDITION, _) => {
+                                let condition = &mut rule.condition;
+                                match name {
+                                    KEY_PREFIX_EQUALS => {
+                                        condition.key_prefix_equals = Some(text.into_owned())
+                                    }
+                                    KEY_SUFFIX_EQUALS => {
+                                        condition.key_suffix_equals = Some(text.into_owned())
+                                    }
+                                    HTTP_ERROR_CODE_RETURNED_EQUALS => {
+                                        condition.http_error_code_returned_equals =
+                                            Some(text.parse()?)
+                                    }
+                                    _ => (),
+                                }
+                            }
+                            (INCLUDE_HEADER, _) => match name {
+                                KEY => header.key = text.into_owned(),
+                                EQUALS => header.equals = Some(text.into_owned()),
+                                STARTS_WITH => header.starts_with = Some(text.into_owned()),
+                                ENDS_WITH => header.ends_with = Some(text.into_owned()),
+                                _ => (),
+                            },
+                            (REDIRECT, _) => {
+                                let redirect = &mut rule.redirect;
+                                match name {
+                                    REDIRECT_TYPE => redirect.redirect_type = text.parse()?,
+                                    PASS_QUERY_STRING => {
+                                        redirect.pass_query_string = Some(text == "true")
+                                    }
+                                    REPLACE_KEY_WITH => {
+                                        redirect.replace_key_with = Some(text.into_owned())
+                                    }
+                                    REPLACE_KEY_PREFIX_WITH => {
+                                        redirect.replace_key_prefix_with = Some(text.into_owned())
+                                    }
+                                    ENABLE_REPLACE_PREFIX => {
+                                        redirect.enable_replace_prefix = Some(text == "true")
+                                    }
+                                    PROTOCOL => redirect.protocol = Some(text.into_owned()),
+                                    HOST_NAME => redirect.host_name = Some(text.into_owned()),
+                                    HTTP_REDIRECT_CODE => {
+                                        redirect.http_redirect_code = Some(text.parse()?)
+                                    }
+                                    MIRROR_URL => redirect.mirror_url = Some(text.into_owned()),
+                                    MIRROR_PASS_QUERY_STRING => {
+                                        redirect.mirror_pass_query_string = Some(text == "true")
+                                    }
+                                    MIRROR_FOLLOW_REDIRECT => {
+                                        redirect.mirror_follow_redirect = Some(text == "true")
+                                    }
+                                    MIRROR_CHECK_MD5 => {
+                                        redirect.mirror_check_md5 = Some(text == "true")
+                                    }
+                                    _ => (),
+                                }
+                            }
+                            (MIRROR_HEADERS, _) => {
+                                if let Some(headers) = rule.redirect.mirror_headers.as_mut() {
+                                    match name {
+                                        PASS_ALL => headers.pass_all = text == "true",
+                                        PASS => headers.pass.push(text.into_owned()),
+                                        REMOVE => headers.remove.push(text.into_owned()),
+                                        _ => (),
+                                    }
+                                }
+                            }
+                            (SET, KEY) => set.0 = text.into_owned(),
+                            (SET, VALUE) => set.1 = text.into_owned(),
+                            _ => (),
+                        }
+                    }
+                },
+                Event::End(e) => {
+                    match e.name().as_ref() {
+                        ROUTING_RULE => config.routing_rules.push(std::mem::take(&mut rule)),
+                        INCLUDE_HEADER => rule
+                            .condition
+                            .include_headers
+                            .push(std::mem::take(&mut header)),
+                        SET => {
+                            if let Some(headers) = rule.redirect.mirror_headers.as_mut() {
+                                headers.set.push(std::mem::take(&mut set));
+                            }
+                        }
+                        _ => (),
+                    }
+                    parents.pop();
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+
+        Ok(config)
+    }
+}
+
+/// 值存在时，添加一个子节点
+fn push_tag<T: Display>(xml: &mut String, name: &str, value: Option<T>) {
+    if let Some(value) = value {
+        xml.push_str(&format!("<{name}>{value}</{name}>"));
+    }
+}
+
+/// 值存在时，添加一个转义后的文本子节点
+fn push_text(xml: &mut String, name: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        xml.push_str(&format!("<{name}>{}</{name}>", escape(value)));
+    }
+}
+
+async fn get_website<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<Option<WebsiteConfiguration>> {
+    match builder
+        .builder(Method::GET, url, canonicalized)?
+        .send_adjust_error()
+        .await
+    {
+        Ok(response) => WebsiteConfiguration::from_xml(&response.text().await?).map(Some),
+        Err(BuilderError::OssService(e)) if e.code == NO_SUCH_WEBSITE_CONFIGURATION => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn put_website<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+    config: WebsiteConfiguration,
+) -> OssResult<()> {
+    builder
+        .builder(Method::PUT, url, canonicalized)?
+        .body(config.to_xml())
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_website<B: AlignBuilder>(
+    builder: &B,
+    (url, canonicalized): (Url, CanonicalizedResource),
+) -> OssResult<()> {
+    builder
+        .builder(Method::DELETE, url, canonicalized)?
+        .send_adjust_error()
+        .await?;
+
+    Ok(())
+}
+
+impl ClientArc {
+    /// # 获取默认 bucket 的静态网站托管设置
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_website(&self) -> OssResult<Option<WebsiteConfiguration>> {
+        get_website(self, self.get_bucket_sub_resource(WEBSITE)).await
+    }
+
+    /// # 设置默认 bucket 的静态网站托管
+    pub async fn put_bucket_website(&self, config: WebsiteConfiguration) -> OssResult<()> {
+        put_website(self, self.get_bucket_sub_resource(WEBSITE), config).await
+    }
+
+    /// # 关闭默认 bucket 的静态网站托管
+    pub async fn delete_bucket_website(&self) -> OssResult<()> {
+        delete_website(self, self.get_bucket_sub_resource(WEBSITE)).await
+    }
+}
+
+impl Bucket {
+    /// # 获取 bucket 的静态网站托管设置
+    /// 未设置时返回 `None`
+    pub async fn get_bucket_website(&self) -> OssResult<Option<WebsiteConfiguration>> {
+        get_website(self, self.base.get_sub_resource(WEBSITE)).await
+    }
+
+    /// # 设置 bucket 的静态网站托管
+    pub async fn put_bucket_website(&self, config: WebsiteConfiguration) -> OssResult<()> {
+        put_website(self, self.base.get_sub_resource(WEBSITE), config).await
+    }
+
+    /// # 关闭 bucket 的静态网站托管
+    pub async fn delete_bucket_website(&self) -> OssResult<()> {
+        delete_website(self, self.base.get_sub_resource(WEBSITE)).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use http::Method;
+    use reqwest::Url;
+
+    use super::{WebsiteConfiguration, NO_SUCH_WEBSITE_CONFIGURATION};
+    use crate::{
+        builder::BuilderError, errors::OssResult, file::blocking::AlignBuilder,
+        types::CanonicalizedResource,
+    };
+
+    pub(super) fn get_website<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<Option<WebsiteConfiguration>> {
+        match builder
+            .builder(Method::GET, url, canonicalized)?
+            .send_adjust_error()
+        {
+            Ok(response) => WebsiteConfiguration::from_xml(&response.text()?).map(Some),
+            Err(BuilderError::OssService(e)) if e.code == NO_SUCH_WEBSITE_CONFIGURATION => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub(super) fn put_website<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+        config: WebsiteConfiguration,
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::PUT, url, canonicalized)?
+            .body(config.to_xml())
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+
+    pub(super) fn delete_website<B: AlignBuilder>(
+        builder: &B,
+        (url, canonicalized): (Url, CanonicalizedResource),
+    ) -> OssResult<()> {
+        builder
+            .builder(Method::DELETE, url, canonicalized)?
+            .send_adjust_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ClientRc {
+    /// # 获取默认 bucket 的静态网站托管设置
+    /// 未设置时返回 `None`
+    pub fn get_bucket_website(&self) -> OssResult<Option<WebsiteConfiguration>> {
+        blocking::get_website(self, self.get_bucket_sub_resource(WEBSITE))
+    }
+
+    /// # 设置默认 bucket 的静态网站托管
+    pub fn put_bucket_website(&self, config: WebsiteConfiguration) -> OssResult<()> {
+        blocking::put_website(self, self.get_bucket_sub_resource(WEBSITE), config)
+    }
+
+    /// # 关闭默认 bucket 的静态网站托管
+    pub fn delete_bucket_website(&self) -> OssResult<()> {
+        blocking::delete_website(self, self.get_bucket_sub_resource(WEBSITE))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Bucket<RcPointer> {
+    /// # 获取 bucket 的静态网站托管设置
+    /// 未设置时返回 `None`
+    pub fn get_bucket_website(&self) -> OssResult<Option<WebsiteConfiguration>> {
+        blocking::get_website(self, self.base.get_sub_resource(WEBSITE))
+    }
+
+    /// # 设置 bucket 的静态网站托管
+    pub fn put_bucket_website(&self, config: WebsiteConfiguration) -> OssResult<()> {
+        blocking::put_website(self, self.base.get_sub_resource(WEBSITE), config)
+    }
+
+    /// # 关闭 bucket 的静态网站托管
+    pub fn delete_bucket_website(&self) -> OssResult<()> {
+        blocking::delete_website(self, self.base.get_sub_resource(WEBSITE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ErrorDocument, IncludeHeader, IndexDocument, MirrorHeaders, Redirect, RedirectType,
+        RoutingCondition, RoutingRule, WebsiteConfiguration,
+    };
+
+    #[test]
+    fn test_website_xml() {
+        let mirror = Redirect {
+            pass_query_string: Some(true),
+            mirror_url: Some("http://example.com/".to_owned()),
+            mirror_follow_redirect: Some(true),
+            mirror_check_md5: Some(false),
+            mirror_headers: Some(MirrorHeaders {
+                pass_all: true,
+                pass: vec!["myheader-key1".to_owned()],
+                remove: vec!["myheader-key3".to_owned()],
+                set: vec![("myheader-key5".to_owned(), "a&b".to_owned())],
+            }),
+            ..Redirect::new(RedirectType::Mirror)
+        };
+        let external = Redirect {
+            protocol: Some("https".to_owned()),
+            host_name: Some("example.com".to_owned()),
+            http_redirect_code: Some(301),
+            replace_key_prefix_with: Some("new/".to_owned()),
+            enable_replace_prefix: Some(true),
+            ..Redirect::new(RedirectType::External)
+        };
+
+        let config = WebsiteConfiguration::new()
+            .index_document(IndexDocument {
+                support_sub_dir: true,
+                index_type: Some(0),
+                ..IndexDocument::new("index.html")
+            })
+            .error_document(ErrorDocument {
+                http_status: Some(404),
+                ..ErrorDocument::new("error.html")
+            })
+            .routing_rule(RoutingRule::new(
+                1,
+                RoutingCondition {
+                    http_error_code_returned_equals: Some(404),
+                    include_headers: vec![IncludeHeader {
+                        key: "host".to_owned(),
+                        equals: Some("test.example.com".to_owned()),
+                        ..IncludeHeader::default()
+                    }],
+                    ..RoutingCondition::default()
+                },
+                mirror,
+            ))
+            .routing_rule(RoutingRule::new(
+                2,
+                RoutingCondition {
+                    key_prefix_equals: Some("old/".to_owned()),
+                    ..RoutingCondition::default()
+                },
+                external,
+            ));
+
+        let xml = config.to_xml();
+        assert_eq!(
+            xml,
+            "<WebsiteConfiguration>\
+            <IndexDocument><Suffix>index.html</Suffix><SupportSubDir>true</SupportSubDir><Type>0</Type></IndexDocument>\
+            <ErrorDocument><Key>error.html</Key><HttpStatus>404</HttpStatus></ErrorDocument>\
+            <RoutingRules><RoutingRule><RuleNumber>1</RuleNumber>\
+            <Condition><HttpErrorCodeReturnedEquals>404</HttpErrorCodeReturnedEquals>\
+            <IncludeHeader><Key>host</Key><Equals>test.example.com</Equals></IncludeHeader></Condition>\
+            <Redirect><RedirectType>Mirror</RedirectType><PassQueryString>true</PassQueryString>\
+            <MirrorURL>http://example.com/</MirrorURL><MirrorFollowRedirect>true</MirrorFollowRedirect>\
+            <MirrorCheckMd5>false</MirrorCheckMd5><MirrorHeaders><PassAll>true</PassAll>\
+            <Pass>myheader-key1</Pass><Remove>myheader-key3</Remove>\
+            <Set><Key>myheader-key5</Key><Value>a&amp;b</Value></Set></MirrorHeaders></Redirect></RoutingRule>\
+            <RoutingRule><RuleNumber>2</RuleNumber><Condition><KeyPrefixEquals>old/</KeyPrefixEquals></Condition>\
+            <Redirect><RedirectType>External</RedirectType><Protocol>https</Protocol>\
+            <HostName>example.com</HostName><HttpRedirectCode>301</HttpRedirectCode>\
+            <ReplaceKeyPrefixWith>new/</ReplaceKeyPrefixWith><EnableReplacePrefix>true</EnableReplacePrefix>\
+            </Redirect></RoutingRule></RoutingRules></WebsiteConfiguration>"
+        );
+        assert_eq!(WebsiteConfiguration::from_xml(&xml).unwrap(), config);
+    }
+}